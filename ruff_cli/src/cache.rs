@@ -6,7 +6,7 @@ use std::path::Path;
 
 use anyhow::Result;
 use filetime::FileTime;
-use log::error;
+use log::{debug, error};
 use path_absolutize::Absolutize;
 use ruff::message::Message;
 use ruff::settings::{flags, AllSettings, Settings};
@@ -17,6 +17,13 @@ const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[derive(Serialize, Deserialize)]
 struct CacheMetadata {
     mtime: i64,
+    content_hash: u64,
+}
+
+fn content_hash(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
 }
 
 #[derive(Serialize)]
@@ -35,12 +42,18 @@ fn content_dir() -> &'static Path {
     Path::new("content")
 }
 
-fn cache_key<P: AsRef<Path>>(path: P, settings: &Settings, autofix: flags::Autofix) -> u64 {
+fn cache_key<P: AsRef<Path>>(
+    path: P,
+    settings: &Settings,
+    autofix: flags::Autofix,
+    noqa: flags::Noqa,
+) -> u64 {
     let mut hasher = DefaultHasher::new();
     CARGO_PKG_VERSION.hash(&mut hasher);
     path.as_ref().absolutize().unwrap().hash(&mut hasher);
     settings.hash(&mut hasher);
     autofix.hash(&mut hasher);
+    noqa.hash(&mut hasher);
     hasher.finish()
 }
 
@@ -82,45 +95,59 @@ pub fn get<P: AsRef<Path>>(
     metadata: &fs::Metadata,
     settings: &AllSettings,
     autofix: flags::Autofix,
+    noqa: flags::Noqa,
 ) -> Option<Vec<Message>> {
+    let path = path.as_ref();
     let encoded = read_sync(
         &settings.cli.cache_dir,
-        cache_key(path, &settings.lib, autofix),
+        cache_key(path, &settings.lib, autofix, noqa),
     )
     .ok()?;
-    let (mtime, messages) = match bincode::deserialize::<CheckResult>(&encoded[..]) {
-        Ok(CheckResult {
-            metadata: CacheMetadata { mtime },
-            messages,
-        }) => (mtime, messages),
+    let CheckResult {
+        metadata: CacheMetadata { mtime, content_hash },
+        messages,
+    } = match bincode::deserialize::<CheckResult>(&encoded[..]) {
+        Ok(result) => result,
         Err(e) => {
             error!("Failed to deserialize encoded cache entry: {e:?}");
             return None;
         }
     };
-    if FileTime::from_last_modification_time(metadata).unix_seconds() != mtime {
-        return None;
+    if FileTime::from_last_modification_time(metadata).unix_seconds() == mtime {
+        debug!("Cache hit (mtime) for: {}", path.display());
+        return Some(messages);
+    }
+    // The mtime changed (e.g., after a `git checkout` that leaves content untouched); fall
+    // back to a content hash comparison rather than treating this as an unconditional miss.
+    let contents = fs::read_to_string(path).ok()?;
+    if self::content_hash(&contents) == content_hash {
+        debug!("Cache hit (content hash) for: {}", path.display());
+        return Some(messages);
     }
-    Some(messages)
+    debug!("Cache miss for: {}", path.display());
+    None
 }
 
 /// Set a value in the cache.
 pub fn set<P: AsRef<Path>>(
     path: P,
+    contents: &str,
     metadata: &fs::Metadata,
     settings: &AllSettings,
     autofix: flags::Autofix,
+    noqa: flags::Noqa,
     messages: &[Message],
 ) {
     let check_result = CheckResultRef {
         metadata: &CacheMetadata {
             mtime: FileTime::from_last_modification_time(metadata).unix_seconds(),
+            content_hash: content_hash(contents),
         },
         messages,
     };
     if let Err(e) = write_sync(
         &settings.cli.cache_dir,
-        cache_key(path, &settings.lib, autofix),
+        cache_key(path, &settings.lib, autofix, noqa),
         &bincode::serialize(&check_result).unwrap(),
     ) {
         error!("Failed to write to cache: {e:?}");
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use clap::{command, Parser};
 use regex::Regex;
 use ruff::fs;
-use ruff::logging::LogLevel;
+use ruff::logging::{LogFormat, LogLevel};
 use ruff::registry::{Rule, RuleCodePrefix};
 use ruff::resolver::ConfigProcessor;
 use ruff::settings::types::{
@@ -20,15 +20,23 @@ use rustc_hash::FxHashMap;
 #[command(version)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Cli {
-    #[arg(required_unless_present_any = ["clean", "explain", "generate_shell_completion"])]
+    #[arg(required_unless_present_any = ["clean", "explain", "generate_shell_completion", "generate_schema", "files_from"])]
     pub files: Vec<PathBuf>,
+    /// Read a list of paths to lint from a file, one per line, in addition to
+    /// any paths passed on the command-line. Pass `-` to read the list from
+    /// stdin. Useful for piping in the output of `git diff --name-only` (or
+    /// similar) on large repositories, without invoking directory discovery
+    /// or hitting command-line length limits.
+    #[arg(long, value_name = "PATH")]
+    pub files_from: Option<PathBuf>,
     /// Path to the `pyproject.toml` or `ruff.toml` file to use for
     /// configuration.
     #[arg(long, conflicts_with = "isolated")]
     pub config: Option<PathBuf>,
-    /// Enable verbose logging.
-    #[arg(short, long, group = "verbosity")]
-    pub verbose: bool,
+    /// Enable verbose logging. Pass twice (`-vv`) to also include trace-level
+    /// diagnostics from the resolver, cache, and checker dispatch.
+    #[arg(short, long, group = "verbosity", action = clap::ArgAction::Count)]
+    pub verbose: u8,
     /// Print lint violations, but nothing else.
     #[arg(short, long, group = "verbosity")]
     pub quiet: bool,
@@ -36,12 +44,34 @@ pub struct Cli {
     /// lint violations).
     #[arg(short, long, group = "verbosity")]
     pub silent: bool,
+    /// Output format for log messages emitted at `-v`/`-vv`.
+    #[arg(long, value_enum)]
+    pub log_format: Option<LogFormat>,
     /// Exit with status code "0", even upon detecting lint violations.
     #[arg(short, long)]
     pub exit_zero: bool,
+    /// The maximum number of violations to allow before exiting with a
+    /// non-zero status code. Violations at or below this threshold still
+    /// exit "0", to support ratcheting adoption on a codebase with a known
+    /// baseline of pre-existing issues. Ignored if `--exit-zero` is set.
+    #[arg(long, value_name = "MAX_VIOLATIONS")]
+    pub max_violations: Option<usize>,
     /// Run in watch mode by re-running whenever files change.
     #[arg(short, long)]
     pub watch: bool,
+    /// Print a per-file timing report to stderr after linting, to help identify
+    /// pathological files in large repositories.
+    #[arg(long)]
+    pub timing: bool,
+    /// Print a summary of the number of violations per rule code to stderr
+    /// after linting.
+    #[arg(long)]
+    pub statistics: bool,
+    /// Report violations even on lines with a `# noqa` directive, rather than
+    /// suppressing them. Useful for auditing how much is currently being
+    /// suppressed (e.g., alongside `--statistics`).
+    #[arg(long)]
+    pub ignore_noqa: bool,
     /// Attempt to automatically fix lint violations.
     #[arg(long, overrides_with("no_fix"))]
     fix: bool,
@@ -57,6 +87,12 @@ pub struct Cli {
     /// changed file to stdout.
     #[arg(long)]
     pub diff: bool,
+    /// Only report violations on lines added or modified relative to
+    /// `<DIFF_AGAINST>` (e.g., a branch, tag, or commit), as computed via `git
+    /// diff`. Useful for enforcing a "clean on touched lines" policy on
+    /// legacy codebases.
+    #[arg(long, value_name = "DIFF_AGAINST")]
+    pub diff_against: Option<String>,
     /// Disable cache reads.
     #[arg(short, long)]
     pub no_cache: bool,
@@ -65,33 +101,83 @@ pub struct Cli {
     pub isolated: bool,
     /// Comma-separated list of rule codes to enable (or ALL, to enable all
     /// rules).
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_SELECT"
+    )]
     pub select: Option<Vec<RuleCodePrefix>>,
     /// Like --select, but adds additional rule codes on top of the selected
     /// ones.
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_EXTEND_SELECT"
+    )]
     pub extend_select: Option<Vec<RuleCodePrefix>>,
     /// Comma-separated list of rule codes to disable.
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_IGNORE"
+    )]
     pub ignore: Option<Vec<RuleCodePrefix>>,
     /// Like --ignore, but adds additional rule codes on top of the ignored
     /// ones.
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_EXTEND_IGNORE"
+    )]
     pub extend_ignore: Option<Vec<RuleCodePrefix>>,
     /// List of paths, used to omit files and/or directories from analysis.
-    #[arg(long, value_delimiter = ',', value_name = "FILE_PATTERN")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "FILE_PATTERN",
+        env = "RUFF_EXCLUDE"
+    )]
     pub exclude: Option<Vec<FilePattern>>,
     /// Like --exclude, but adds additional files and directories on top of
     /// those already excluded.
-    #[arg(long, value_delimiter = ',', value_name = "FILE_PATTERN")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "FILE_PATTERN",
+        env = "RUFF_EXTEND_EXCLUDE"
+    )]
     pub extend_exclude: Option<Vec<FilePattern>>,
+    /// List of file patterns to lint in addition to the default set of
+    /// `.py`/`.pyi` files.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "FILE_PATTERN",
+        env = "RUFF_EXTEND_INCLUDE"
+    )]
+    pub extend_include: Option<Vec<FilePattern>>,
     /// List of rule codes to treat as eligible for autofix. Only applicable
     /// when autofix itself is enabled (e.g., via `--fix`).
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_FIXABLE"
+    )]
     pub fixable: Option<Vec<RuleCodePrefix>>,
     /// List of rule codes to treat as ineligible for autofix. Only applicable
     /// when autofix itself is enabled (e.g., via `--fix`).
-    #[arg(long, value_delimiter = ',', value_name = "RULE_CODE")]
+    #[arg(
+        long,
+        alias = "no-fixable",
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        env = "RUFF_UNFIXABLE"
+    )]
     pub unfixable: Option<Vec<RuleCodePrefix>>,
     /// List of mappings from file pattern to code to exclude
     #[arg(long, value_delimiter = ',')]
@@ -99,6 +185,11 @@ pub struct Cli {
     /// Output serialization format for violations.
     #[arg(long, value_enum, env = "RUFF_FORMAT")]
     pub format: Option<SerializationFormat>,
+    /// Write the formatted diagnostics (in the format specified via `--format`) to
+    /// the given file, rather than to stdout. The summary is still printed, to
+    /// stderr.
+    #[arg(long, value_name = "PATH")]
+    pub output_file: Option<PathBuf>,
     /// The name of the file when passing it through stdin.
     #[arg(long)]
     pub stdin_filename: Option<PathBuf>,
@@ -128,14 +219,14 @@ pub struct Cli {
     #[clap(long, overrides_with("update_check"), hide = true)]
     no_update_check: bool,
     /// Regular expression matching the name of dummy variables.
-    #[arg(long)]
+    #[arg(long, env = "RUFF_DUMMY_VARIABLE_RGX")]
     pub dummy_variable_rgx: Option<Regex>,
     /// The minimum Python version that should be supported.
-    #[arg(long)]
+    #[arg(long, env = "RUFF_TARGET_VERSION")]
     pub target_version: Option<PythonVersion>,
     /// Set the line-length for length-associated rules and automatic
     /// formatting.
-    #[arg(long)]
+    #[arg(long, env = "RUFF_LINE_LENGTH")]
     pub line_length: Option<usize>,
     /// Enable automatic additions of `noqa` directives to failing lines.
     #[arg(
@@ -144,9 +235,11 @@ pub struct Cli {
         conflicts_with = "clean",
         conflicts_with = "explain",
         conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
@@ -159,9 +252,11 @@ pub struct Cli {
         // conflicts_with = "clean",
         conflicts_with = "explain",
         conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
@@ -175,9 +270,11 @@ pub struct Cli {
         conflicts_with = "clean",
         // conflicts_with = "explain",
         conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
@@ -192,13 +289,33 @@ pub struct Cli {
         conflicts_with = "clean",
         conflicts_with = "explain",
         // conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
     pub generate_shell_completion: Option<clap_complete_command::Shell>,
+    /// Print the JSON Schema for the `pyproject.toml` `[tool.ruff]` section,
+    /// for use in editor integrations.
+    #[arg(
+        long,
+        // Fake subcommands.
+        conflicts_with = "add_noqa",
+        conflicts_with = "clean",
+        conflicts_with = "explain",
+        conflicts_with = "generate_shell_completion",
+        // conflicts_with = "generate_schema",
+        conflicts_with = "show_files",
+        conflicts_with = "show_settings",
+        // Unsupported default-command arguments.
+        conflicts_with = "output_file",
+        conflicts_with = "stdin_filename",
+        conflicts_with = "watch",
+    )]
+    pub generate_schema: bool,
     /// See the files Ruff will be run against with the current settings.
     #[arg(
         long,
@@ -207,9 +324,11 @@ pub struct Cli {
         conflicts_with = "clean",
         conflicts_with = "explain",
         conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         // conflicts_with = "show_files",
         conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
@@ -222,9 +341,11 @@ pub struct Cli {
         conflicts_with = "clean",
         conflicts_with = "explain",
         conflicts_with = "generate_shell_completion",
+        conflicts_with = "generate_schema",
         conflicts_with = "show_files",
         // conflicts_with = "show_settings",
         // Unsupported default-command arguments.
+        conflicts_with = "output_file",
         conflicts_with = "stdin_filename",
         conflicts_with = "watch",
     )]
@@ -241,12 +362,19 @@ impl Cli {
                 clean: self.clean,
                 config: self.config,
                 diff: self.diff,
+                diff_against: self.diff_against,
                 exit_zero: self.exit_zero,
                 explain: self.explain,
                 files: self.files,
+                files_from: self.files_from,
+                generate_schema: self.generate_schema,
                 generate_shell_completion: self.generate_shell_completion,
+                ignore_noqa: self.ignore_noqa,
                 isolated: self.isolated,
+                log_format: self.log_format.unwrap_or_default(),
+                max_violations: self.max_violations,
                 no_cache: self.no_cache,
+                output_file: self.output_file,
                 quiet: self.quiet,
                 show_files: self.show_files,
                 show_settings: self.show_settings,
@@ -254,11 +382,13 @@ impl Cli {
                 stdin_filename: self.stdin_filename,
                 verbose: self.verbose,
                 watch: self.watch,
+                timing: self.timing,
             },
             Overrides {
                 dummy_variable_rgx: self.dummy_variable_rgx,
                 exclude: self.exclude,
                 extend_exclude: self.extend_exclude,
+                extend_include: self.extend_include,
                 extend_ignore: self.extend_ignore,
                 extend_select: self.extend_select,
                 fixable: self.fixable,
@@ -302,19 +432,27 @@ pub struct Arguments {
     pub clean: bool,
     pub config: Option<PathBuf>,
     pub diff: bool,
+    pub diff_against: Option<String>,
     pub exit_zero: bool,
     pub explain: Option<Rule>,
     pub files: Vec<PathBuf>,
+    pub files_from: Option<PathBuf>,
+    pub generate_schema: bool,
     pub generate_shell_completion: Option<clap_complete_command::Shell>,
+    pub ignore_noqa: bool,
     pub isolated: bool,
+    pub log_format: LogFormat,
+    pub max_violations: Option<usize>,
     pub no_cache: bool,
+    pub output_file: Option<PathBuf>,
     pub quiet: bool,
     pub show_files: bool,
     pub show_settings: bool,
     pub silent: bool,
     pub stdin_filename: Option<PathBuf>,
-    pub verbose: bool,
+    pub verbose: u8,
     pub watch: bool,
+    pub timing: bool,
 }
 
 /// CLI settings that function as configuration overrides.
@@ -324,6 +462,7 @@ pub struct Overrides {
     pub dummy_variable_rgx: Option<Regex>,
     pub exclude: Option<Vec<FilePattern>>,
     pub extend_exclude: Option<Vec<FilePattern>>,
+    pub extend_include: Option<Vec<FilePattern>>,
     pub extend_ignore: Option<Vec<RuleCodePrefix>>,
     pub extend_select: Option<Vec<RuleCodePrefix>>,
     pub fixable: Option<Vec<RuleCodePrefix>>,
@@ -358,6 +497,9 @@ impl ConfigProcessor for &Overrides {
         if let Some(extend_exclude) = &self.extend_exclude {
             config.extend_exclude.extend(extend_exclude.clone());
         }
+        if let Some(extend_include) = &self.extend_include {
+            config.extend_include.extend(extend_include.clone());
+        }
         if let Some(fix) = &self.fix {
             config.fix = Some(*fix);
         }
@@ -426,10 +568,8 @@ pub fn extract_log_level(cli: &Arguments) -> LogLevel {
         LogLevel::Silent
     } else if cli.quiet {
         LogLevel::Quiet
-    } else if cli.verbose {
-        LogLevel::Verbose
     } else {
-        LogLevel::Default
+        LogLevel::from_verbosity(cli.verbose)
     }
 }
 
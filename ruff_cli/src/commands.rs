@@ -1,5 +1,5 @@
 use std::fs::remove_dir_all;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
@@ -12,15 +12,19 @@ use path_absolutize::path_dedot;
 #[cfg(not(target_family = "wasm"))]
 use rayon::prelude::*;
 use ruff::cache::CACHE_DIR_NAME;
-use ruff::linter::add_noqa_to_path;
+use ruff::linter::{add_noqa_to_path, lint_fix};
 use ruff::logging::LogLevel;
 use ruff::message::{Location, Message};
 use ruff::registry::Rule;
 use ruff::resolver::{FileDiscovery, PyprojectDiscovery};
-use ruff::settings::flags;
+use ruff::settings::options::Options;
 use ruff::settings::types::SerializationFormat;
-use ruff::{fix, fs, packaging, resolver, warn_user_once, IOError};
+use ruff::settings::{flags, Settings};
+use ruff::{fix, fs, packaging, resolver, warn_user, warn_user_once, IOError};
+use rustc_hash::FxHashMap;
+use schemars::schema_for;
 use serde::Serialize;
+use similar::TextDiff;
 use walkdir::WalkDir;
 
 use crate::cache;
@@ -36,6 +40,8 @@ pub fn run(
     overrides: &Overrides,
     cache: flags::Cache,
     autofix: fix::FixMode,
+    ignore_noqa: bool,
+    timing: bool,
 ) -> Result<Diagnostics> {
     // Collect all the Python files to check.
     let start = Instant::now();
@@ -87,6 +93,8 @@ pub fn run(
         pyproject_strategy,
     );
 
+    let noqa = flags::Noqa::from(!ignore_noqa);
+
     let start = Instant::now();
     let mut diagnostics: Diagnostics = par_iter(&paths)
         .map(|entry| {
@@ -98,8 +106,18 @@ pub fn run(
                         .and_then(|parent| package_roots.get(parent))
                         .and_then(|package| *package);
                     let settings = resolver.resolve_all(path, pyproject_strategy);
-                    lint_path(path, package, settings, cache, autofix)
-                        .map_err(|e| (Some(path.to_owned()), e.to_string()))
+                    if timing {
+                        let start = Instant::now();
+                        let result = lint_path(path, package, settings, cache, autofix, noqa);
+                        let duration = start.elapsed();
+                        result.map(|mut diagnostics| {
+                            diagnostics.timings.push((path.to_owned(), duration));
+                            diagnostics
+                        })
+                    } else {
+                        lint_path(path, package, settings, cache, autofix, noqa)
+                    }
+                    .map_err(|e| (Some(path.to_owned()), e.to_string()))
                 }
                 Err(e) => Err((
                     if let Error::WithPath { path, .. } = e {
@@ -139,12 +157,70 @@ pub fn run(
         });
 
     diagnostics.messages.sort_unstable();
+
+    // Diffs are buffered per-file during the (parallel) lint pass above; sort by path
+    // and print them here so `--diff`'s output is a single, deterministic, git-apply-able
+    // patch regardless of which file finished linting first.
+    if !diagnostics.diffs.is_empty() {
+        diagnostics.diffs.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let mut stdout = io::stdout().lock();
+        for (_, diff) in &diagnostics.diffs {
+            stdout.write_all(diff.as_bytes())?;
+        }
+        stdout.flush()?;
+    }
+
     let duration = start.elapsed();
     debug!("Checked files in: {:?}", duration);
 
+    warn_stale_per_file_ignores(&paths, &resolver, pyproject_strategy);
+
     Ok(diagnostics)
 }
 
+/// Warn about any `per-file-ignores` entries whose pattern didn't match any
+/// of the files checked in this run, which is a strong signal that the entry
+/// is stale (e.g., the referenced path was renamed or removed).
+///
+/// This only checks whether the pattern matched at all, not whether it ever
+/// actually suppressed a violation -- distinguishing "matched a file" from
+/// "matched a file that would otherwise have triggered one of its codes"
+/// would require threading suppression outcomes back out of every `check_path`
+/// call, which isn't worth the plumbing for a best-effort configuration hint.
+fn warn_stale_per_file_ignores(
+    paths: &[Result<ignore::DirEntry, ignore::Error>],
+    resolver: &resolver::Resolver,
+    pyproject_strategy: &PyprojectDiscovery,
+) {
+    let mut groups: FxHashMap<*const Settings, (&Settings, Vec<&Path>)> = FxHashMap::default();
+    for entry in paths.iter().flatten() {
+        let path = entry.path();
+        let settings = resolver.resolve(path, pyproject_strategy);
+        groups
+            .entry(settings as *const Settings)
+            .or_insert_with(|| (settings, Vec::new()))
+            .1
+            .push(path);
+    }
+
+    for (settings, checked_paths) in groups.values() {
+        for (absolute, basename, _) in &settings.per_file_ignores {
+            let matched = checked_paths.iter().any(|path| {
+                fs::extract_path_names(path).map_or(false, |(file_path, file_basename)| {
+                    basename.is_match(file_basename) || absolute.is_match(file_path)
+                })
+            });
+            if !matched {
+                warn_user!(
+                    "The `per-file-ignores` entry for `{}` didn't match any files checked in \
+                     this run; consider removing it.",
+                    basename.glob().glob(),
+                );
+            }
+        }
+    }
+}
+
 /// Read a `String` from `stdin`.
 fn read_from_stdin() -> Result<String> {
     let mut buffer = String::new();
@@ -152,6 +228,22 @@ fn read_from_stdin() -> Result<String> {
     Ok(buffer)
 }
 
+/// Read a newline-delimited list of paths from `path`, treating `-` as
+/// `stdin`. Blank lines are ignored.
+pub fn read_files_from(path: &Path) -> Result<Vec<PathBuf>> {
+    let contents = if path == Path::new("-") {
+        read_from_stdin()?
+    } else {
+        std::fs::read_to_string(path)?
+    };
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
 /// Run the linter over a single file, read from `stdin`.
 pub fn run_stdin(
     filename: Option<&Path>,
@@ -159,6 +251,7 @@ pub fn run_stdin(
     file_strategy: &FileDiscovery,
     overrides: &Overrides,
     autofix: fix::FixMode,
+    ignore_noqa: bool,
 ) -> Result<Diagnostics> {
     if let Some(filename) = filename {
         if !resolver::python_file_at_path(filename, pyproject_strategy, file_strategy, overrides)? {
@@ -173,7 +266,8 @@ pub fn run_stdin(
         .and_then(Path::parent)
         .and_then(|path| packaging::detect_package_root(path, &settings.lib.namespace_packages));
     let stdin = read_from_stdin()?;
-    let mut diagnostics = lint_stdin(filename, package_root, &stdin, &settings.lib, autofix)?;
+    let noqa = flags::Noqa::from(!ignore_noqa);
+    let mut diagnostics = lint_stdin(filename, package_root, &stdin, &settings.lib, autofix, noqa)?;
     diagnostics.messages.sort_unstable();
     Ok(diagnostics)
 }
@@ -287,6 +381,29 @@ struct Explanation<'a> {
     code: &'a str,
     origin: &'a str,
     summary: &'a str,
+    fix_diff: Option<String>,
+}
+
+/// Autofix `rule`'s recorded example with the real fixer and return a unified
+/// diff of the result, so the example can never drift from actual behavior.
+fn example_fix_diff(rule: &Rule) -> Result<Option<String>> {
+    let Some(before) = rule.example() else {
+        return Ok(None);
+    };
+    let settings = Settings {
+        rules: vec![rule.clone()].into(),
+        ..Settings::default()
+    };
+    let (after, fixed, ..) = lint_fix(before, Path::new("<example>.py"), None, &settings)?;
+    if fixed == 0 {
+        return Ok(None);
+    }
+    Ok(Some(
+        TextDiff::from_lines(before, &after)
+            .unified_diff()
+            .header("before", "after")
+            .to_string(),
+    ))
 }
 
 /// Explain a `Rule` to the user.
@@ -299,6 +416,12 @@ pub fn explain(rule: &Rule, format: SerializationFormat) -> Result<()> {
                 rule.origin().name(),
                 rule.kind().summary()
             );
+            if let Some(diff) = example_fix_diff(rule)? {
+                println!();
+                let mut stdout = io::stdout().lock();
+                stdout.write_all(diff.as_bytes())?;
+                stdout.flush()?;
+            }
         }
         SerializationFormat::Json => {
             println!(
@@ -307,6 +430,7 @@ pub fn explain(rule: &Rule, format: SerializationFormat) -> Result<()> {
                     code: rule.code(),
                     origin: rule.origin().name(),
                     summary: &rule.kind().summary(),
+                    fix_diff: example_fix_diff(rule)?,
                 })?
             );
         }
@@ -319,10 +443,22 @@ pub fn explain(rule: &Rule, format: SerializationFormat) -> Result<()> {
         SerializationFormat::Gitlab => {
             bail!("`--explain` does not support GitLab format")
         }
+        SerializationFormat::Rdjson => {
+            bail!("`--explain` does not support RDJSON format")
+        }
     };
     Ok(())
 }
 
+/// Print the JSON Schema for the `pyproject.toml` `[tool.ruff]` section, for use
+/// in editor integrations (e.g., VS Code's `yaml.schemas` / `evenBetterToml`).
+pub fn generate_schema() -> Result<()> {
+    let schema = schema_for!(Options);
+    let output = serde_json::to_string_pretty(&schema)?;
+    println!("{output}");
+    Ok(())
+}
+
 /// Clear any caches in the current directory or any subdirectories.
 pub fn clean(level: &LogLevel) -> Result<()> {
     for entry in WalkDir::new(&*path_dedot::CWD)
@@ -3,14 +3,19 @@ use std::fs::write;
 use std::io;
 use std::io::Write;
 use std::ops::AddAssign;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Result;
 use log::debug;
+use ruff::autofix::SkippedFix;
 use ruff::linter::{lint_fix, lint_only};
-use ruff::message::Message;
+use ruff::message::{Location, Message};
+use ruff::registry::Rule;
 use ruff::settings::{flags, AllSettings, Settings};
+use ruff::violations::IOError;
 use ruff::{fix, fs};
+use rustc_hash::FxHashMap;
 use similar::TextDiff;
 
 use crate::cache;
@@ -19,11 +24,45 @@ use crate::cache;
 pub struct Diagnostics {
     pub messages: Vec<Message>,
     pub fixed: usize,
+    /// Fixes that were dropped because they conflicted with another fix that
+    /// was already applied. Only populated in `--fix`/`--diff` mode.
+    pub skipped_fixes: Vec<SkippedFix>,
+    /// Per-file lint durations, populated only when `--timing` is enabled.
+    pub timings: Vec<(PathBuf, Duration)>,
+    /// The number of files that were checked to produce this summary.
+    pub files_checked: usize,
+    /// Rendered per-file unified diffs, populated only in `--diff` mode. Kept
+    /// here rather than written straight to stdout so that callers linting
+    /// multiple files in parallel can sort by path before printing, giving a
+    /// deterministic, git-apply-able patch regardless of which file finishes
+    /// linting first.
+    pub diffs: Vec<(PathBuf, String)>,
 }
 
 impl Diagnostics {
     pub fn new(messages: Vec<Message>) -> Self {
-        Self { messages, fixed: 0 }
+        Self {
+            messages,
+            fixed: 0,
+            skipped_fixes: Vec::new(),
+            timings: Vec::new(),
+            files_checked: 1,
+            diffs: Vec::new(),
+        }
+    }
+
+    /// Return the number of violations per `Rule`, sorted in descending order
+    /// of frequency, for use by statistics-oriented printer modes.
+    pub fn statistics(&self) -> Vec<(Rule, usize)> {
+        let mut counts: FxHashMap<Rule, usize> = FxHashMap::default();
+        for message in &self.messages {
+            *counts.entry(message.kind.rule()).or_insert(0) += 1;
+        }
+        let mut counts: Vec<(Rule, usize)> = counts.into_iter().collect();
+        counts.sort_unstable_by(|(a_rule, a_count), (b_rule, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_rule.code().cmp(b_rule.code()))
+        });
+        counts
     }
 }
 
@@ -31,6 +70,26 @@ impl AddAssign for Diagnostics {
     fn add_assign(&mut self, other: Self) {
         self.messages.extend(other.messages);
         self.fixed += other.fixed;
+        self.skipped_fixes.extend(other.skipped_fixes);
+        self.timings.extend(other.timings);
+        self.files_checked += other.files_checked;
+        self.diffs.extend(other.diffs);
+    }
+}
+
+/// Print a note to stderr for each fix that was dropped due to a conflict
+/// with another fix, so that `--diff`'s output doesn't silently under-report
+/// what changed.
+fn warn_on_skipped_fixes(path: &Path, skipped_fixes: &[SkippedFix]) {
+    for skipped in skipped_fixes {
+        eprintln!(
+            "{}: fix for `{}` at {}:{} skipped (conflicts with an already-applied `{}` fix)",
+            fs::relativize_path(path),
+            skipped.rule.code(),
+            skipped.location.row(),
+            skipped.location.column(),
+            skipped.conflicts_with.code(),
+        );
     }
 }
 
@@ -41,6 +100,7 @@ pub fn lint_path(
     settings: &AllSettings,
     cache: flags::Cache,
     autofix: fix::FixMode,
+    noqa: flags::Noqa,
 ) -> Result<Diagnostics> {
     // Validate the `Settings` and return any errors.
     settings.lib.validate()?;
@@ -55,7 +115,7 @@ pub fn lint_path(
         && matches!(autofix, fix::FixMode::None | fix::FixMode::Generate)
     {
         let metadata = path.metadata()?;
-        if let Some(messages) = cache::get(path, &metadata, settings, autofix.into()) {
+        if let Some(messages) = cache::get(path, &metadata, settings, autofix.into(), noqa) {
             debug!("Cache hit for: {}", path.to_string_lossy());
             return Ok(Diagnostics::new(messages));
         }
@@ -64,38 +124,91 @@ pub fn lint_path(
         None
     };
 
+    // Skip files that exceed the configured maximum size, if any. This check reads only
+    // filesystem metadata, so an oversized file is never loaded into memory; it applies to
+    // disk-backed paths only, since content passed via stdin has already been read by the
+    // time `lint_stdin` is reached and has no metadata to check up front.
+    if let Some(max_file_size) = settings.lib.max_file_size {
+        let file_size = metadata
+            .as_ref()
+            .map_or_else(|| path.metadata(), |metadata| Ok(metadata.clone()))?
+            .len();
+        if file_size as usize > max_file_size {
+            return Ok(if settings.lib.rules.enabled(&Rule::IOError) {
+                Diagnostics::new(vec![Message {
+                    kind: IOError(format!(
+                        "File size ({file_size} bytes) exceeds `max-file-size` ({max_file_size} bytes); skipping"
+                    ))
+                    .into(),
+                    location: Location::default(),
+                    end_location: Location::default(),
+                    fix: None,
+                    filename: path.to_string_lossy().to_string(),
+                    source: None,
+                }])
+            } else {
+                debug!(
+                    "Ignoring {} (size {} exceeds max-file-size {})",
+                    path.to_string_lossy(),
+                    file_size,
+                    max_file_size
+                );
+                Diagnostics::default()
+            });
+        }
+    }
+
     // Read the file from disk.
     let contents = fs::read_file(path)?;
 
     // Lint the file.
-    let (messages, fixed) = if matches!(autofix, fix::FixMode::Apply | fix::FixMode::Diff) {
-        let (transformed, fixed, messages) = lint_fix(&contents, path, package, &settings.lib)?;
+    let (messages, fixed, skipped_fixes, diffs) = if matches!(
+        autofix,
+        fix::FixMode::Apply | fix::FixMode::Diff
+    ) {
+        let (transformed, fixed, messages, skipped_fixes) =
+            lint_fix(&contents, path, package, &settings.lib)?;
+        let mut diffs = Vec::new();
         if fixed > 0 {
             if matches!(autofix, fix::FixMode::Apply) {
                 write(path, transformed)?;
             } else if matches!(autofix, fix::FixMode::Diff) {
-                let mut stdout = io::stdout().lock();
+                // Buffer the rendered diff rather than writing it to stdout here: when
+                // linting multiple files in parallel, the caller sorts by path before
+                // printing, so the combined patch is deterministic and git-apply-able
+                // regardless of which file finishes linting first.
+                let mut rendered = Vec::new();
                 TextDiff::from_lines(&contents, &transformed)
                     .unified_diff()
                     .header(&fs::relativize_path(path), &fs::relativize_path(path))
-                    .to_writer(&mut stdout)?;
-                stdout.write_all(b"\n")?;
-                stdout.flush()?;
+                    .to_writer(&mut rendered)?;
+                rendered.push(b'\n');
+                diffs.push((path.to_owned(), String::from_utf8_lossy(&rendered).into_owned()));
             }
         }
-        (messages, fixed)
+        if matches!(autofix, fix::FixMode::Diff) {
+            warn_on_skipped_fixes(path, &skipped_fixes);
+        }
+        (messages, fixed, skipped_fixes, diffs)
     } else {
-        let messages = lint_only(&contents, path, package, &settings.lib, autofix.into())?;
+        let messages = lint_only(&contents, path, package, &settings.lib, autofix.into(), noqa)?;
         let fixed = 0;
-        (messages, fixed)
+        (messages, fixed, Vec::new(), Vec::new())
     };
 
     // Re-populate the cache.
     if let Some(metadata) = metadata {
-        cache::set(path, &metadata, settings, autofix.into(), &messages);
+        cache::set(path, &contents, &metadata, settings, autofix.into(), noqa, &messages);
     }
 
-    Ok(Diagnostics { messages, fixed })
+    Ok(Diagnostics {
+        messages,
+        fixed,
+        skipped_fixes,
+        timings: Vec::new(),
+        files_checked: 1,
+        diffs,
+    })
 }
 
 /// Generate `Diagnostic`s from source code content derived from
@@ -106,13 +219,17 @@ pub fn lint_stdin(
     contents: &str,
     settings: &Settings,
     autofix: fix::FixMode,
+    noqa: flags::Noqa,
 ) -> Result<Diagnostics> {
     // Validate the `Settings` and return any errors.
     settings.validate()?;
 
     // Lint the inputs.
-    let (messages, fixed) = if matches!(autofix, fix::FixMode::Apply | fix::FixMode::Diff) {
-        let (transformed, fixed, messages) = lint_fix(
+    let (messages, fixed, skipped_fixes) = if matches!(
+        autofix,
+        fix::FixMode::Apply | fix::FixMode::Diff
+    ) {
+        let (transformed, fixed, messages, skipped_fixes) = lint_fix(
             contents,
             path.unwrap_or_else(|| Path::new("-")),
             package,
@@ -136,9 +253,13 @@ pub fn lint_stdin(
                 stdout.write_all(b"\n")?;
                 stdout.flush()?;
             }
+            warn_on_skipped_fixes(
+                path.unwrap_or_else(|| Path::new("-")),
+                &skipped_fixes,
+            );
         }
 
-        (messages, fixed)
+        (messages, fixed, skipped_fixes)
     } else {
         let messages = lint_only(
             contents,
@@ -146,10 +267,18 @@ pub fn lint_stdin(
             package,
             settings,
             autofix.into(),
+            noqa,
         )?;
         let fixed = 0;
-        (messages, fixed)
+        (messages, fixed, Vec::new())
     };
 
-    Ok(Diagnostics { messages, fixed })
+    Ok(Diagnostics {
+        messages,
+        fixed,
+        skipped_fixes,
+        timings: Vec::new(),
+        files_checked: 1,
+        diffs: Vec::new(),
+    })
 }
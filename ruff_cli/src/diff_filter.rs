@@ -0,0 +1,156 @@
+//! Filter diagnostics down to those that fall on lines touched by a `git
+//! diff`, to support "clean on touched lines" adoption policies for legacy
+//! codebases.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+
+use ruff::fs;
+use ruff::message::Message;
+
+/// The line ranges (inclusive, 1-indexed) added or modified by a diff, keyed
+/// by the absolute path of the file they belong to.
+#[derive(Debug, Default)]
+pub struct ChangedLines(FxHashMap<PathBuf, Vec<(usize, usize)>>);
+
+impl ChangedLines {
+    /// Return `true` if `row` in `path` falls within a hunk of the diff.
+    fn contains(&self, path: &Path, row: usize) -> bool {
+        self.0.get(path).map_or(false, |ranges| {
+            ranges.iter().any(|(start, end)| (*start..=*end).contains(&row))
+        })
+    }
+}
+
+static HUNK_HEADER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@").unwrap());
+
+/// Parse a unified diff (as produced by `git diff -U0`) into a
+/// [`ChangedLines`]. File paths in the diff are resolved to absolute paths
+/// relative to `repo_root`, so they can be compared directly against the
+/// absolute paths Ruff reports diagnostics against.
+pub fn parse_unified_diff(diff: &str, repo_root: &Path) -> ChangedLines {
+    let mut changed = FxHashMap::default();
+    let mut current: Option<PathBuf> = None;
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = if path == "/dev/null" {
+                None
+            } else {
+                // Strip the `b/` prefix that `git diff` adds to the new path by default.
+                let path = path.strip_prefix("b/").unwrap_or(path);
+                Some(fs::normalize_path_to(path, repo_root))
+            };
+            continue;
+        }
+        let Some(captures) = HUNK_HEADER.captures(line) else {
+            continue;
+        };
+        let Some(current) = &current else {
+            continue;
+        };
+        let start: usize = captures[1].parse().unwrap_or(1);
+        let count: usize = captures
+            .get(2)
+            .map_or(1, |m| m.as_str().parse().unwrap_or(1));
+        if count == 0 {
+            // A pure deletion; no new lines were added to this file.
+            continue;
+        }
+        changed
+            .entry(current.clone())
+            .or_insert_with(Vec::new)
+            .push((start, start + count - 1));
+    }
+    ChangedLines(changed)
+}
+
+/// Compute the lines added or modified relative to `git_ref`, for the given
+/// set of Python files.
+pub fn changed_lines_against(git_ref: &str, files: &[PathBuf]) -> Result<ChangedLines> {
+    let toplevel = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()?;
+    if !toplevel.status.success() {
+        bail!(
+            "Failed to resolve the Git repository root: {}",
+            String::from_utf8_lossy(&toplevel.stderr)
+        );
+    }
+    let repo_root = PathBuf::from(String::from_utf8_lossy(&toplevel.stdout).trim());
+
+    let mut command = Command::new("git");
+    command
+        .arg("diff")
+        .arg("--no-color")
+        .arg("--unified=0")
+        .arg(git_ref)
+        .arg("--");
+    command.args(files);
+
+    let output = command.output()?;
+    if !output.status.success() {
+        bail!(
+            "Failed to run `git diff` against {git_ref}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(parse_unified_diff(
+        &String::from_utf8_lossy(&output.stdout),
+        &repo_root,
+    ))
+}
+
+/// Retain only the diagnostics that fall on a changed line.
+pub fn filter_messages(messages: Vec<Message>, changed: &ChangedLines) -> Vec<Message> {
+    messages
+        .into_iter()
+        .filter(|message| changed.contains(Path::new(&message.filename), message.location.row()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_unified_diff;
+
+    #[test]
+    fn parses_added_lines() {
+        let diff = "\
+diff --git a/foo.py b/foo.py
+index 0000000..1111111 100644
+--- a/foo.py
++++ b/foo.py
+@@ -1,0 +2,3 @@
++import os
++import sys
++x = 1
+";
+        let repo_root = std::path::Path::new("/repo");
+        let changed = parse_unified_diff(diff, repo_root);
+        assert!(changed.contains(&repo_root.join("foo.py"), 2));
+        assert!(changed.contains(&repo_root.join("foo.py"), 4));
+        assert!(!changed.contains(&repo_root.join("foo.py"), 5));
+    }
+
+    #[test]
+    fn ignores_pure_deletions() {
+        let diff = "\
+diff --git a/foo.py b/foo.py
+--- a/foo.py
++++ b/foo.py
+@@ -2,3 +1,0 @@
+-import os
+-import sys
+-x = 1
+";
+        let repo_root = std::path::Path::new("/repo");
+        let changed = parse_unified_diff(diff, repo_root);
+        assert!(!changed.contains(&repo_root.join("foo.py"), 1));
+    }
+}
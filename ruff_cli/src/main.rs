@@ -23,6 +23,7 @@ use anyhow::Result;
 use clap::{CommandFactory, Parser};
 use cli::{extract_log_level, Cli, Overrides};
 use colored::Colorize;
+use diagnostics::Diagnostics;
 use notify::{recommended_watcher, RecursiveMode, Watcher};
 use path_absolutize::path_dedot;
 use printer::{Printer, Violations};
@@ -32,6 +33,7 @@ mod cache;
 mod cli;
 mod commands;
 mod diagnostics;
+mod diff_filter;
 mod iterators;
 mod printer;
 #[cfg(all(feature = "update-informer"))]
@@ -88,10 +90,48 @@ fn resolve(
     }
 }
 
+/// Print a table of the slowest files to lint, sorted in descending order by duration.
+fn print_timing_report(timings: &[(PathBuf, std::time::Duration)]) {
+    let mut timings = timings.to_vec();
+    timings.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+
+    eprintln!("\n{}", "Timing report (slowest files first):".bold());
+    for (path, duration) in timings.iter().take(20) {
+        eprintln!("  {:>10.2?}  {}", duration, path.to_string_lossy());
+    }
+}
+
+/// Print a table of the number of violations per rule code, sorted in
+/// descending order of frequency.
+fn print_statistics_report(diagnostics: &Diagnostics) {
+    let counts = diagnostics.statistics();
+
+    eprintln!(
+        "\n{}",
+        format!(
+            "Statistics ({} file(s) checked, {} violation(s)):",
+            diagnostics.files_checked,
+            diagnostics.messages.len()
+        )
+        .bold()
+    );
+    for (rule, count) in counts {
+        eprintln!("  {count:>6}  {}  {}", rule.code(), rule.kind().summary());
+    }
+}
+
 pub fn main() -> Result<ExitCode> {
     // Extract command-line arguments.
     let (cli, overrides) = Cli::parse().partition();
 
+    // Merge in any paths provided via `--files-from`, which bypasses directory
+    // discovery for those paths (they're linted directly, subject to the same
+    // exclusion rules as any other file).
+    let mut files = cli.files;
+    if let Some(files_from) = &cli.files_from {
+        files.extend(commands::read_files_from(files_from)?);
+    }
+
     let default_panic_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         eprintln!(
@@ -108,12 +148,16 @@ quoting the executed command, along with the relevant file contents and `pyproje
     }));
 
     let log_level = extract_log_level(&cli);
-    set_up_logging(&log_level)?;
+    set_up_logging(&log_level, cli.log_format)?;
 
     if let Some(shell) = cli.generate_shell_completion {
         shell.generate(&mut Cli::command(), &mut io::stdout());
         return Ok(ExitCode::SUCCESS);
     }
+    if cli.generate_schema {
+        commands::generate_schema()?;
+        return Ok(ExitCode::SUCCESS);
+    }
     if cli.clean {
         commands::clean(&log_level)?;
         return Ok(ExitCode::SUCCESS);
@@ -162,11 +206,11 @@ quoting the executed command, along with the relevant file contents and `pyproje
         return Ok(ExitCode::SUCCESS);
     }
     if cli.show_settings {
-        commands::show_settings(&cli.files, &pyproject_strategy, &file_strategy, &overrides)?;
+        commands::show_settings(&files, &pyproject_strategy, &file_strategy, &overrides)?;
         return Ok(ExitCode::SUCCESS);
     }
     if cli.show_files {
-        commands::show_files(&cli.files, &pyproject_strategy, &file_strategy, &overrides)?;
+        commands::show_files(&files, &pyproject_strategy, &file_strategy, &overrides)?;
         return Ok(ExitCode::SUCCESS);
     }
 
@@ -203,7 +247,14 @@ quoting the executed command, along with the relevant file contents and `pyproje
         warn_user_once!("debug build without --no-cache.");
     }
 
-    let printer = Printer::new(&format, &log_level, &autofix, &violations);
+    let printer = Printer::new(
+        &format,
+        &log_level,
+        &autofix,
+        &violations,
+        cli.output_file.as_deref(),
+        cli.max_violations,
+    );
     if cli.watch {
         if !matches!(autofix, fix::FixMode::None) {
             warn_user_once!("--fix is not enabled in watch mode.");
@@ -217,19 +268,21 @@ quoting the executed command, along with the relevant file contents and `pyproje
         printer.write_to_user("Starting linter in watch mode...\n");
 
         let messages = commands::run(
-            &cli.files,
+            &files,
             &pyproject_strategy,
             &file_strategy,
             &overrides,
             cache.into(),
             fix::FixMode::None,
+            cli.ignore_noqa,
+            cli.timing,
         )?;
         printer.write_continuously(&messages)?;
 
         // Configure the file watcher.
         let (tx, rx) = channel();
         let mut watcher = recommended_watcher(tx)?;
-        for file in &cli.files {
+        for file in &files {
             watcher.watch(file, RecursiveMode::Recursive)?;
         }
 
@@ -247,12 +300,14 @@ quoting the executed command, along with the relevant file contents and `pyproje
                         printer.write_to_user("File change detected...\n");
 
                         let messages = commands::run(
-                            &cli.files,
+                            &files,
                             &pyproject_strategy,
                             &file_strategy,
                             &overrides,
                             cache.into(),
                             fix::FixMode::None,
+                            cli.ignore_noqa,
+                            cli.timing,
                         )?;
                         printer.write_continuously(&messages)?;
                     }
@@ -262,33 +317,53 @@ quoting the executed command, along with the relevant file contents and `pyproje
         }
     } else if cli.add_noqa {
         let modifications =
-            commands::add_noqa(&cli.files, &pyproject_strategy, &file_strategy, &overrides)?;
+            commands::add_noqa(&files, &pyproject_strategy, &file_strategy, &overrides)?;
         if modifications > 0 && log_level >= LogLevel::Default {
             println!("Added {modifications} noqa directives.");
         }
     } else {
-        let is_stdin = cli.files == vec![PathBuf::from("-")];
+        let is_stdin = files == vec![PathBuf::from("-")];
 
         // Generate lint violations.
-        let diagnostics = if is_stdin {
+        let mut diagnostics = if is_stdin {
             commands::run_stdin(
                 cli.stdin_filename.map(fs::normalize_path).as_deref(),
                 &pyproject_strategy,
                 &file_strategy,
                 &overrides,
                 autofix,
+                cli.ignore_noqa,
             )?
         } else {
             commands::run(
-                &cli.files,
+                &files,
                 &pyproject_strategy,
                 &file_strategy,
                 &overrides,
                 cache.into(),
                 autofix,
+                cli.ignore_noqa,
+                cli.timing,
             )?
         };
 
+        if let Some(diff_against) = &cli.diff_against {
+            if is_stdin {
+                warn_user_once!("--diff-against is not supported when reading from stdin.");
+            } else {
+                let changed = diff_filter::changed_lines_against(diff_against, &files)?;
+                diagnostics.messages = diff_filter::filter_messages(diagnostics.messages, &changed);
+            }
+        }
+
+        if cli.timing {
+            print_timing_report(&diagnostics.timings);
+        }
+
+        if cli.statistics {
+            print_statistics_report(&diagnostics);
+        }
+
         // Always try to print violations (the printer itself may suppress output),
         // unless we're writing fixes via stdin (in which case, the transformed
         // source code goes to stdout).
@@ -311,8 +386,11 @@ quoting the executed command, along with the relevant file contents and `pyproje
                 if diagnostics.fixed > 0 {
                     return Ok(ExitCode::FAILURE);
                 }
-            } else if !diagnostics.messages.is_empty() {
-                return Ok(ExitCode::FAILURE);
+            } else {
+                let max_violations = cli.max_violations.unwrap_or(0);
+                if diagnostics.messages.len() > max_violations {
+                    return Ok(ExitCode::FAILURE);
+                }
             }
         }
     }
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
+use std::fs;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use annotate_snippets::display_list::{DisplayList, FormatOptions};
 use annotate_snippets::snippet::{Annotation, AnnotationType, Slice, Snippet, SourceAnnotation};
@@ -65,6 +66,8 @@ pub struct Printer<'a> {
     log_level: &'a LogLevel,
     autofix: &'a fix::FixMode,
     violations: &'a Violations,
+    output_file: Option<&'a Path>,
+    max_violations: Option<usize>,
 }
 
 impl<'a> Printer<'a> {
@@ -73,12 +76,16 @@ impl<'a> Printer<'a> {
         log_level: &'a LogLevel,
         autofix: &'a fix::FixMode,
         violations: &'a Violations,
+        output_file: Option<&'a Path>,
+        max_violations: Option<usize>,
     ) -> Self {
         Self {
             format,
             log_level,
             autofix,
             violations,
+            output_file,
+            max_violations,
         }
     }
 
@@ -104,6 +111,15 @@ impl<'a> Printer<'a> {
                         writeln!(stdout, "Found {remaining} error(s).")?;
                     }
 
+                    if let Some(max_violations) = self.max_violations {
+                        if remaining > max_violations {
+                            writeln!(
+                                stdout,
+                                "Exceeds the allowed maximum of {max_violations} violation(s)."
+                            )?;
+                        }
+                    }
+
                     if !matches!(self.autofix, fix::FixMode::Apply) {
                         let num_fixable = diagnostics
                             .messages
@@ -139,17 +155,23 @@ impl<'a> Printer<'a> {
         }
 
         if matches!(self.violations, Violations::Hide) {
-            let mut stdout = BufWriter::new(io::stdout().lock());
+            let mut summary_writer = self.summary_writer();
             if matches!(
                 self.format,
                 SerializationFormat::Text | SerializationFormat::Grouped
             ) {
-                self.post_text(&mut stdout, diagnostics)?;
+                self.post_text(&mut summary_writer, diagnostics)?;
             }
+            summary_writer.flush()?;
             return Ok(());
         }
 
-        let mut stdout = BufWriter::new(io::stdout().lock());
+        let mut stdout = BufWriter::new(Vec::new());
+        if self.output_file.is_some() {
+            // `colored` colorizes based on whether the real stdout is a TTY, which
+            // is meaningless once we're writing bytes to a file instead.
+            colored::control::set_override(false);
+        }
         match self.format {
             SerializationFormat::Json => {
                 writeln!(
@@ -217,8 +239,6 @@ impl<'a> Printer<'a> {
                 for message in &diagnostics.messages {
                     print_message(&mut stdout, message)?;
                 }
-
-                self.post_text(&mut stdout, diagnostics)?;
             }
             SerializationFormat::Grouped => {
                 for (filename, messages) in group_messages_by_filename(&diagnostics.messages) {
@@ -252,8 +272,6 @@ impl<'a> Printer<'a> {
                     }
                     writeln!(stdout)?;
                 }
-
-                self.post_text(&mut stdout, diagnostics)?;
             }
             SerializationFormat::Github => {
                 // Generate error workflow command in GitHub Actions format.
@@ -312,13 +330,97 @@ impl<'a> Printer<'a> {
                     )?
                 )?;
             }
+            SerializationFormat::Rdjson => {
+                // Generate JSON with errors in RDJSON format, for use with Reviewdog.
+                // https://github.com/reviewdog/reviewdog/tree/master/proto/rdf
+                writeln!(
+                    stdout,
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "source": {
+                            "name": "ruff",
+                            "url": "https://github.com/charliermarsh/ruff",
+                        },
+                        "severity": "ERROR",
+                        "diagnostics": diagnostics
+                            .messages
+                            .iter()
+                            .map(|message| {
+                                json!({
+                                    "message": message.kind.body(),
+                                    "code": {
+                                        "value": message.kind.rule().code(),
+                                    },
+                                    "location": {
+                                        "path": message.filename,
+                                        "range": {
+                                            "start": {
+                                                "line": message.location.row(),
+                                                "column": message.location.column(),
+                                            },
+                                            "end": {
+                                                "line": message.end_location.row(),
+                                                "column": message.end_location.column(),
+                                            },
+                                        },
+                                    },
+                                    "suggestions": message.fix.as_ref().map_or_else(Vec::new, |fix| {
+                                        vec![json!({
+                                            "range": {
+                                                "start": {
+                                                    "line": fix.location.row(),
+                                                    "column": fix.location.column(),
+                                                },
+                                                "end": {
+                                                    "line": fix.end_location.row(),
+                                                    "column": fix.end_location.column(),
+                                                },
+                                            },
+                                            "text": fix.content,
+                                        })]
+                                    }),
+                                })
+                            })
+                            .collect::<Vec<_>>(),
+                    }))?
+                )?;
+            }
+        }
+        if self.output_file.is_some() {
+            colored::control::unset_override();
         }
 
-        stdout.flush()?;
+        let content = stdout.into_inner()?;
+        if let Some(output_file) = self.output_file {
+            write_atomic(output_file, &content)?;
+        } else {
+            io::stdout().write_all(&content)?;
+        }
+
+        if matches!(
+            self.format,
+            SerializationFormat::Text | SerializationFormat::Grouped
+        ) {
+            let mut summary_writer = self.summary_writer();
+            self.post_text(&mut summary_writer, diagnostics)?;
+            summary_writer.flush()?;
+        }
 
         Ok(())
     }
 
+    /// Return the writer to which the run summary (e.g., "Found 1 error.") should
+    /// be printed: stderr if diagnostics are being written to `--output-file`,
+    /// so that the file contains only the formatted diagnostics; stdout
+    /// otherwise.
+    fn summary_writer(&self) -> Box<dyn Write> {
+        if self.output_file.is_some() {
+            Box::new(io::stderr())
+        } else {
+            Box::new(io::stdout())
+        }
+    }
+
     pub fn write_continuously(&self, diagnostics: &Diagnostics) -> Result<()> {
         if matches!(self.log_level, LogLevel::Silent) {
             return Ok(());
@@ -352,6 +454,21 @@ impl<'a> Printer<'a> {
     }
 }
 
+/// Write `content` to `path`, replacing any existing file at that path only
+/// once the write has fully succeeded (by writing to a sibling temporary
+/// file, then renaming it into place).
+fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("output");
+    let tmp_path: PathBuf =
+        path.with_file_name(format!("{file_name}.{}.tmp", std::process::id()));
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
 fn group_messages_by_filename(messages: &[Message]) -> BTreeMap<&String, Vec<&Message>> {
     let mut grouped_messages = BTreeMap::default();
     for message in messages {
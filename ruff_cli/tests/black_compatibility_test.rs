@@ -12,7 +12,7 @@ use anyhow::{anyhow, Context, Result};
 use assert_cmd::Command;
 use itertools::Itertools;
 use log::info;
-use ruff::logging::{set_up_logging, LogLevel};
+use ruff::logging::{set_up_logging, LogFormat, LogLevel};
 use ruff::registry::RuleOrigin;
 use strum::IntoEnumIterator;
 use walkdir::WalkDir;
@@ -148,7 +148,7 @@ fn run_test(path: &Path, blackd: &Blackd, ruff_args: &[&str]) -> Result<()> {
 #[test]
 #[ignore]
 fn test_ruff_black_compatibility() -> Result<()> {
-    set_up_logging(&LogLevel::Default)?;
+    set_up_logging(&LogLevel::Default, LogFormat::Text)?;
 
     let blackd = Blackd::new()?;
 
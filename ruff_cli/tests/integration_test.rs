@@ -1,5 +1,6 @@
 #![cfg(not(target_family = "wasm"))]
 
+use std::fs;
 use std::str;
 
 use anyhow::Result;
@@ -151,3 +152,48 @@ fn test_show_source() -> Result<()> {
     assert!(str::from_utf8(&output.get_output().stdout)?.contains("l = 1"));
     Ok(())
 }
+
+#[test]
+fn test_grouped_format() -> Result<()> {
+    let mut cmd = Command::cargo_bin(BIN_NAME)?;
+    let output = cmd
+        .args(["-", "--format", "grouped", "--stdin-filename", "F401.py"])
+        .write_stdin("import os\n")
+        .assert()
+        .failure();
+    assert_eq!(
+        str::from_utf8(&output.get_output().stdout)?,
+        "F401.py:\n  1:8  F401  `os` imported but unused\n\nFound 1 error(s).\n1 potentially \
+         fixable with the --fix option.\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_output_file() -> Result<()> {
+    let output_file =
+        std::env::temp_dir().join(format!("ruff_output_file_{}.txt", std::process::id()));
+    let mut cmd = Command::cargo_bin(BIN_NAME)?;
+    let output = cmd
+        .args([
+            "-",
+            "--format",
+            "text",
+            "--stdin-filename",
+            "F401.py",
+            "--output-file",
+        ])
+        .arg(&output_file)
+        .write_stdin("import os\n")
+        .assert()
+        .failure();
+    assert_eq!(str::from_utf8(&output.get_output().stdout)?, "");
+    assert_eq!(
+        str::from_utf8(&output.get_output().stderr)?,
+        "Found 1 error(s).\n1 potentially fixable with the --fix option.\n"
+    );
+    let contents = fs::read_to_string(&output_file)?;
+    fs::remove_file(&output_file)?;
+    assert_eq!(contents, "F401.py:1:8: F401 `os` imported but unused\n");
+    Ok(())
+}
@@ -11,6 +11,7 @@ pub fn define_rule_mapping(mapping: &Mapping) -> proc_macro2::TokenStream {
     let mut rule_kind_match_arms = quote!();
     let mut rule_origin_match_arms = quote!();
     let mut rule_code_match_arms = quote!();
+    let mut rule_example_match_arms = quote!();
     let mut rule_from_code_match_arms = quote!();
     let mut diagkind_code_match_arms = quote!();
     let mut diagkind_body_match_arms = quote!();
@@ -28,6 +29,8 @@ pub fn define_rule_mapping(mapping: &Mapping) -> proc_macro2::TokenStream {
         rule_origin_match_arms.extend(quote! {Self::#name => RuleOrigin::#origin,});
         let code_str = LitStr::new(&code.to_string(), Span::call_site());
         rule_code_match_arms.extend(quote! {Self::#name => #code_str,});
+        rule_example_match_arms
+            .extend(quote! {Self::#name => <#path as Violation>::example(),});
         rule_from_code_match_arms.extend(quote! {#code_str => Ok(&Rule::#name), });
         diagkind_code_match_arms.extend(quote! {Self::#name(..) => &Rule::#name, });
         diagkind_body_match_arms.extend(quote! {Self::#name(x) => Violation::message(x), });
@@ -99,6 +102,12 @@ pub fn define_rule_mapping(mapping: &Mapping) -> proc_macro2::TokenStream {
                     _ => Err(FromCodeError::Unknown),
                 }
             }
+
+            /// A minimal snippet of Python that triggers this rule, if one has
+            /// been recorded for it. See [`Violation::example`].
+            pub fn example(&self) -> Option<&'static str> {
+                match self { #rule_example_match_arms }
+            }
         }
 
 
@@ -1,3 +1,4 @@
+use anyhow::{bail, Result};
 use itertools::Itertools;
 use log::error;
 use once_cell::sync::Lazy;
@@ -366,8 +367,12 @@ pub fn collect_arg_names<'a>(arguments: &'a Arguments) -> FxHashSet<&'a str> {
 
 /// Returns `true` if a statement or expression includes at least one comment.
 pub fn has_comments_in(range: Range, locator: &Locator) -> bool {
-    lexer::make_tokenizer(&locator.slice_source_code_range(&range))
-        .any(|result| result.map_or(false, |(_, tok, _)| matches!(tok, Tok::Comment(..))))
+    locator.contains_comments(&range)
+}
+
+/// Returns `true` if a statement or expression includes a multi-line string literal.
+pub fn has_multiline_string(range: Range, locator: &Locator) -> bool {
+    locator.contains_multiline_string(&range)
 }
 
 /// Returns `true` if a call is an argumented `super` invocation.
@@ -656,6 +661,45 @@ pub fn first_colon_range(range: Range, locator: &Locator) -> Option<Range> {
     range
 }
 
+/// Return the `Location` of the colon that terminates a function's parameter list, i.e. the
+/// point at which a return type annotation should be inserted.
+///
+/// This scans the signature's token stream (rather than assuming a fixed column offset), so it
+/// correctly handles signatures that span multiple lines, contain comments, or have parameter
+/// defaults with their own colons (e.g. `dict` literals). Shared by any fix that needs to edit a
+/// function signature in place, e.g. the "add a `None` return annotation" fixes in
+/// `flake8_annotations`.
+pub fn end_of_arguments(stmt: &Stmt, locator: &Locator) -> Result<Location> {
+    let range = Range::from_located(stmt);
+    let contents = locator.slice_source_code_range(&range);
+
+    let mut seen_lpar = false;
+    let mut seen_rpar = false;
+    let mut count: usize = 0;
+    for (start, tok, ..) in lexer::make_tokenizer_located(&contents, range.location).flatten() {
+        if seen_lpar && seen_rpar {
+            if matches!(tok, Tok::Colon) {
+                return Ok(start);
+            }
+        }
+
+        if matches!(tok, Tok::Lpar) {
+            if count == 0 {
+                seen_lpar = true;
+            }
+            count += 1;
+        }
+        if matches!(tok, Tok::Rpar) {
+            count -= 1;
+            if count == 0 {
+                seen_rpar = true;
+            }
+        }
+    }
+
+    bail!("Unable to locate colon in function definition")
+}
+
 /// Return the `Range` of the first `Elif` or `Else` token in an `If` statement.
 pub fn elif_else_range(stmt: &Stmt, locator: &Locator) -> Option<Range> {
     let StmtKind::If { body, orelse, .. } = &stmt.node else {
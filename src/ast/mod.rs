@@ -6,5 +6,6 @@ pub mod helpers;
 pub mod operations;
 pub mod relocate;
 pub mod types;
+pub mod version;
 pub mod visitor;
 pub mod whitespace;
@@ -0,0 +1,83 @@
+//! Helpers for statically evaluating `sys.version_info` comparisons against a
+//! configured `target-version`, e.g. `sys.version_info < (3, 8)`.
+
+use num_bigint::BigInt;
+use rustpython_ast::{Cmpop, Constant, Expr, ExprKind};
+
+use crate::checkers::ast::Checker;
+use crate::settings::types::PythonVersion;
+
+/// Return `true` if `expr` resolves to `sys.version_info`.
+pub fn is_sys_version_info(checker: &Checker, expr: &Expr) -> bool {
+    checker.resolve_call_path(expr).map_or(false, |call_path| {
+        call_path.as_slice() == ["sys", "version_info"]
+    })
+}
+
+/// Extract the elements of a tuple of integer constants, e.g. `(3, 8)`.
+pub fn int_tuple(expr: &Expr) -> Option<Vec<BigInt>> {
+    let ExprKind::Tuple { elts, .. } = &expr.node else {
+        return None;
+    };
+    elts.iter()
+        .map(|elt| match &elt.node {
+            ExprKind::Constant {
+                value: Constant::Int(i),
+                ..
+            } => Some(i.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn target_version_tuple(target_version: PythonVersion) -> (u32, u32) {
+    match target_version {
+        PythonVersion::Py33 => (3, 3),
+        PythonVersion::Py34 => (3, 4),
+        PythonVersion::Py35 => (3, 5),
+        PythonVersion::Py36 => (3, 6),
+        PythonVersion::Py37 => (3, 7),
+        PythonVersion::Py38 => (3, 8),
+        PythonVersion::Py39 => (3, 9),
+        PythonVersion::Py310 => (3, 10),
+        PythonVersion::Py311 => (3, 11),
+    }
+}
+
+/// Statically evaluate `sys.version_info <op> version` for the configured
+/// `target-version`, if that's possible without knowing the interpreter's
+/// micro/releaselevel/serial fields.
+///
+/// This only supports one- and two-element comparison tuples (`(3,)`,
+/// `(3, 8)`), which covers the realistic `sys.version_info < (3, X)` idiom.
+/// Since `sys.version_info` itself always has more than two elements, an
+/// exact match on the compared prefix still resolves the comparison (the
+/// real tuple is "greater" by virtue of its extra trailing elements), so
+/// every `Cmpop` here is fully decidable once the prefix comparison is
+/// known.
+pub fn compare_version(
+    target_version: PythonVersion,
+    op: &Cmpop,
+    version: &[BigInt],
+) -> Option<bool> {
+    if version.is_empty() || version.len() > 2 {
+        return None;
+    }
+    let (major, minor) = target_version_tuple(target_version);
+    let target = [BigInt::from(major), BigInt::from(minor)];
+    let ordering = target[..version.len()]
+        .iter()
+        .zip(version.iter())
+        .map(|(a, b)| a.cmp(b))
+        .find(|ordering| !ordering.is_eq())
+        .unwrap_or(std::cmp::Ordering::Greater);
+    Some(match op {
+        Cmpop::Lt => ordering.is_lt(),
+        Cmpop::LtE => ordering.is_le(),
+        Cmpop::Gt => ordering.is_gt(),
+        Cmpop::GtE => ordering.is_ge(),
+        Cmpop::Eq => ordering.is_eq(),
+        Cmpop::NotEq => ordering.is_ne(),
+        _ => return None,
+    })
+}
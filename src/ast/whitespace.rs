@@ -41,6 +41,61 @@ pub fn clean(indentation: &str) -> String {
         .collect()
 }
 
+/// Remove exactly `width` columns of leading whitespace from every line in `contents`
+/// whose first `width` bytes are all whitespace. Any other line (e.g. a blank line, or
+/// one with fewer than `width` columns of leading whitespace) is left untouched, so blank
+/// lines and short comments don't lose content.
+pub fn dedent(contents: &str, width: usize) -> String {
+    let mut output = String::with_capacity(contents.len());
+    for line in contents.split_inclusive('\n') {
+        let (line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line, "\n"),
+            None => (line, ""),
+        };
+        if line.len() >= width && line.as_bytes()[..width].iter().all(u8::is_ascii_whitespace) {
+            output.push_str(&line[width..]);
+        } else {
+            output.push_str(line);
+        }
+        output.push_str(newline);
+    }
+    output
+}
+
+/// Prepend `indent` to every non-blank line in `contents`. The inverse of [`dedent`]; together
+/// they let a fix re-indent an arbitrary span of source by a signed delta (shrink via `dedent`,
+/// grow via `indent`) while leaving blank lines alone.
+///
+/// Like `dedent`, this is purely textual: it doesn't parse `contents`, so a line that happens to
+/// fall inside a multiline string literal is indented the same as any other line. Callers that
+/// need to move a span containing a multiline string should verify that ahead of time.
+pub fn indent(contents: &str, indent: &str) -> String {
+    let mut output = String::with_capacity(contents.len());
+    for line in contents.split_inclusive('\n') {
+        let (line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line, "\n"),
+            None => (line, ""),
+        };
+        if !line.is_empty() {
+            output.push_str(indent);
+        }
+        output.push_str(line);
+        output.push_str(newline);
+    }
+    output
+}
+
+/// Return `true` if any line in `contents` mixes tabs and spaces in its leading whitespace.
+/// [`dedent`] and [`indent`] operate byte-wise on that prefix, so a caller re-indenting a span
+/// should check this first and decline to fix rather than risk misaligning mixed-indentation
+/// code.
+pub fn has_mixed_indentation(contents: &str) -> bool {
+    contents.lines().any(|line| {
+        let indent = leading_space(line);
+        indent.contains(' ') && indent.contains('\t')
+    })
+}
+
 /// Like `str#lines`, but includes a trailing newline as an empty line.
 pub struct LinesWithTrailingNewline<'a> {
     trailing: Option<&'a str>,
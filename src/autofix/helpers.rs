@@ -8,10 +8,12 @@ use rustpython_parser::ast::{ExcepthandlerKind, Location, Stmt, StmtKind};
 use crate::ast::helpers;
 use crate::ast::helpers::to_absolute;
 use crate::ast::types::Range;
+use crate::ast::whitespace;
 use crate::ast::whitespace::LinesWithTrailingNewline;
 use crate::cst::helpers::compose_module_path;
 use crate::cst::matchers::match_module;
 use crate::fix::Fix;
+use crate::noqa::{extract_noqa_directive, Directive};
 use crate::source_code::{Indexer, Locator};
 
 /// Determine if a body contains only a single statement, taking into account
@@ -192,6 +194,75 @@ pub fn delete_stmt(
     }
 }
 
+/// Generate a `Fix` to split an `import a, b, c` statement into one `import` statement per
+/// member, e.g. for `E401`.
+///
+/// Each alias is re-rendered as `import <name>` (or `import <name> as <asname>`) rather than
+/// sliced out of the original source, since an import alias is just a dotted name and an
+/// optional `as` clause, with no formatting nuance worth preserving beyond that. Any comment
+/// trailing the original statement is kept on the last of the split lines, unless it's a `noqa`
+/// directive: `noqa` suppresses diagnostics reported *on that line*, so moving it onto only the
+/// last split import would silently narrow its suppression scope away from the earlier ones. A
+/// `noqa` is duplicated onto every split line instead.
+pub fn split_multi_import(stmt: &Stmt, locator: &Locator) -> Option<Fix> {
+    let StmtKind::Import { names } = &stmt.node else {
+        return None;
+    };
+    if names.len() < 2 {
+        return None;
+    }
+
+    let indent = whitespace::indentation(locator, stmt).unwrap_or_default();
+    let trailing_comment = trailing_end_of_line_comment(stmt, locator);
+    let trailing_comment_is_noqa = trailing_comment.as_ref().map_or(false, |(comment, _)| {
+        !matches!(extract_noqa_directive(comment), Directive::None)
+    });
+
+    let lines: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(index, alias)| {
+            let mut line = match &alias.node.asname {
+                Some(asname) => format!("import {} as {asname}", alias.node.name),
+                None => format!("import {}", alias.node.name),
+            };
+            if trailing_comment_is_noqa || index + 1 == names.len() {
+                if let Some((comment, _)) = &trailing_comment {
+                    line.push_str("  ");
+                    line.push_str(comment);
+                }
+            }
+            line
+        })
+        .collect();
+
+    // The trailing comment is re-emitted above, so replace through its end.
+    let fix_end = trailing_comment
+        .as_ref()
+        .map_or(stmt.end_location.unwrap(), |(_, comment_end)| *comment_end);
+    Some(Fix::replacement(
+        lines.join(&format!("\n{indent}")),
+        stmt.location,
+        fix_end,
+    ))
+}
+
+/// Return the comment (if any) trailing a `Stmt` on its last physical line, along with the
+/// `Location` at which that comment ends.
+fn trailing_end_of_line_comment(stmt: &Stmt, locator: &Locator) -> Option<(String, Location)> {
+    let end = stmt.end_location.unwrap();
+    let rest_of_line = locator.slice_source_code_range(&Range::new(
+        end,
+        Location::new(end.row() + 1, 0),
+    ));
+    let rest_of_line = rest_of_line.trim_end();
+    let trimmed = rest_of_line.trim_start();
+    trimmed.starts_with('#').then(|| {
+        let comment_end = Location::new(end.row(), end.column() + rest_of_line.chars().count());
+        (trimmed.to_string(), comment_end)
+    })
+}
+
 /// Generate a `Fix` to remove any unused imports from an `import` statement.
 pub fn remove_unused_imports<'a>(
     unused_imports: impl Iterator<Item = &'a str>,
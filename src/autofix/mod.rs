@@ -2,43 +2,64 @@ use std::borrow::Cow;
 use std::collections::BTreeSet;
 
 use itertools::Itertools;
+use log::debug;
 use ropey::RopeBuilder;
 use rustpython_ast::Location;
 
 use crate::ast::types::Range;
 use crate::fix::Fix;
-use crate::registry::Diagnostic;
+use crate::registry::{Diagnostic, Rule};
 use crate::source_code::Locator;
 
 pub mod fixer;
 pub mod helpers;
 
+/// A fix that was dropped because it overlapped with a fix that was already
+/// applied, keyed by the two rules whose fixes conflicted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFix {
+    /// The rule whose fix was dropped.
+    pub rule: Rule,
+    /// The rule whose (already-applied) fix it conflicted with.
+    pub conflicts_with: Rule,
+    pub location: Location,
+    pub end_location: Location,
+}
+
 /// Auto-fix errors in a file, and write the fixed source code to disk.
 pub fn fix_file<'a>(
     diagnostics: &'a [Diagnostic],
     locator: &'a Locator<'a>,
-) -> Option<(Cow<'a, str>, usize)> {
+) -> Option<(Cow<'a, str>, usize, Vec<SkippedFix>)> {
     if diagnostics.iter().all(|check| check.fix.is_none()) {
         return None;
     }
 
-    Some(apply_fixes(
-        diagnostics.iter().filter_map(|check| check.fix.as_ref()),
+    let (contents, fixed, skipped) = apply_fixes(
+        diagnostics.iter().filter(|check| check.fix.is_some()),
         locator,
-    ))
+    );
+    debug!("Applied {fixed} fix(es), skipped {} due to conflicts", skipped.len());
+    Some((contents, fixed, skipped))
 }
 
 /// Apply a series of fixes.
 fn apply_fixes<'a>(
-    fixes: impl Iterator<Item = &'a Fix>,
+    diagnostics: impl Iterator<Item = &'a Diagnostic>,
     locator: &'a Locator<'a>,
-) -> (Cow<'a, str>, usize) {
+) -> (Cow<'a, str>, usize, Vec<SkippedFix>) {
     let mut output = RopeBuilder::new();
     let mut last_pos: Location = Location::new(1, 0);
+    let mut last_rule: Option<&'static Rule> = None;
     let mut applied: BTreeSet<&Fix> = BTreeSet::default();
     let mut num_fixed: usize = 0;
+    let mut skipped: Vec<SkippedFix> = Vec::new();
+
+    let diagnostics =
+        diagnostics.sorted_by_key(|diagnostic| diagnostic.fix.as_ref().unwrap().location);
+    for diagnostic in diagnostics {
+        let fix = diagnostic.fix.as_ref().unwrap();
 
-    for fix in fixes.sorted_by_key(|fix| fix.location) {
         // If we already applied an identical fix as part of another correction, skip
         // any re-application.
         if applied.contains(&fix) {
@@ -47,8 +68,17 @@ fn apply_fixes<'a>(
         }
 
         // Best-effort approach: if this fix overlaps with a fix we've already applied,
-        // skip it.
+        // skip it, but record which rules were involved so the conflict can be
+        // surfaced to the user instead of silently dropped.
         if last_pos > fix.location {
+            skipped.push(SkippedFix {
+                rule: diagnostic.kind.rule().clone(),
+                conflicts_with: last_rule
+                    .expect("a conflict implies a previously-applied fix")
+                    .clone(),
+                location: fix.location,
+                end_location: fix.end_location,
+            });
             continue;
         }
 
@@ -61,6 +91,7 @@ fn apply_fixes<'a>(
 
         // Track that the fix was applied.
         last_pos = fix.end_location;
+        last_rule = Some(diagnostic.kind.rule());
         applied.insert(fix);
         num_fixed += 1;
     }
@@ -69,33 +100,53 @@ fn apply_fixes<'a>(
     let slice = locator.slice_source_code_at(last_pos);
     output.append(&slice);
 
-    (Cow::from(output.finish()), num_fixed)
+    (Cow::from(output.finish()), num_fixed, skipped)
 }
 
 #[cfg(test)]
 mod tests {
     use rustpython_parser::ast::Location;
 
+    use crate::ast::types::Range;
     use crate::autofix::apply_fixes;
     use crate::fix::Fix;
+    use crate::registry::{Diagnostic, Rule};
     use crate::source_code::Locator;
+    use crate::violations::{RedundantBackslash, TypeComparison};
+
+    /// Build a diagnostic with an attached fix, using two distinct rules so that
+    /// tests can assert on which rule's fix was applied vs. skipped.
+    fn diagnostic_with_fix(first: bool, fix: Fix) -> Diagnostic {
+        let range = Range::new(fix.location, fix.end_location);
+        let mut diagnostic = if first {
+            Diagnostic::new(RedundantBackslash, range)
+        } else {
+            Diagnostic::new(TypeComparison, range)
+        };
+        diagnostic.amend(fix);
+        diagnostic
+    }
 
     #[test]
     fn empty_file() {
-        let fixes = vec![];
+        let diagnostics = vec![];
         let locator = Locator::new(r#""#);
-        let (contents, fixed) = apply_fixes(fixes.iter(), &locator);
+        let (contents, fixed, skipped) = apply_fixes(diagnostics.iter(), &locator);
         assert_eq!(contents, "");
         assert_eq!(fixed, 0);
+        assert!(skipped.is_empty());
     }
 
     #[test]
     fn apply_single_replacement() {
-        let fixes = vec![Fix {
-            content: "Bar".to_string(),
-            location: Location::new(1, 8),
-            end_location: Location::new(1, 14),
-        }];
+        let diagnostics = vec![diagnostic_with_fix(
+            true,
+            Fix {
+                content: "Bar".to_string(),
+                location: Location::new(1, 8),
+                end_location: Location::new(1, 14),
+            },
+        )];
         let locator = Locator::new(
             r#"
 class A(object):
@@ -103,7 +154,7 @@ class A(object):
 "#
             .trim(),
         );
-        let (contents, fixed) = apply_fixes(fixes.iter(), &locator);
+        let (contents, fixed, skipped) = apply_fixes(diagnostics.iter(), &locator);
         assert_eq!(
             contents,
             r#"
@@ -113,15 +164,19 @@ class A(Bar):
             .trim(),
         );
         assert_eq!(fixed, 1);
+        assert!(skipped.is_empty());
     }
 
     #[test]
     fn apply_single_removal() {
-        let fixes = vec![Fix {
-            content: String::new(),
-            location: Location::new(1, 7),
-            end_location: Location::new(1, 15),
-        }];
+        let diagnostics = vec![diagnostic_with_fix(
+            true,
+            Fix {
+                content: String::new(),
+                location: Location::new(1, 7),
+                end_location: Location::new(1, 15),
+            },
+        )];
         let locator = Locator::new(
             r#"
 class A(object):
@@ -129,7 +184,7 @@ class A(object):
 "#
             .trim(),
         );
-        let (contents, fixed) = apply_fixes(fixes.iter(), &locator);
+        let (contents, fixed, skipped) = apply_fixes(diagnostics.iter(), &locator);
         assert_eq!(
             contents,
             r#"
@@ -139,21 +194,28 @@ class A:
             .trim()
         );
         assert_eq!(fixed, 1);
+        assert!(skipped.is_empty());
     }
 
     #[test]
     fn apply_double_removal() {
-        let fixes = vec![
-            Fix {
-                content: String::new(),
-                location: Location::new(1, 7),
-                end_location: Location::new(1, 16),
-            },
-            Fix {
-                content: String::new(),
-                location: Location::new(1, 16),
-                end_location: Location::new(1, 23),
-            },
+        let diagnostics = vec![
+            diagnostic_with_fix(
+                true,
+                Fix {
+                    content: String::new(),
+                    location: Location::new(1, 7),
+                    end_location: Location::new(1, 16),
+                },
+            ),
+            diagnostic_with_fix(
+                false,
+                Fix {
+                    content: String::new(),
+                    location: Location::new(1, 16),
+                    end_location: Location::new(1, 23),
+                },
+            ),
         ];
         let locator = Locator::new(
             r#"
@@ -162,7 +224,7 @@ class A(object, object):
 "#
             .trim(),
         );
-        let (contents, fixed) = apply_fixes(fixes.iter(), &locator);
+        let (contents, fixed, skipped) = apply_fixes(diagnostics.iter(), &locator);
 
         assert_eq!(
             contents,
@@ -173,21 +235,28 @@ class A:
             .trim()
         );
         assert_eq!(fixed, 2);
+        assert!(skipped.is_empty());
     }
 
     #[test]
     fn ignore_overlapping_fixes() {
-        let fixes = vec![
-            Fix {
-                content: String::new(),
-                location: Location::new(1, 7),
-                end_location: Location::new(1, 15),
-            },
-            Fix {
-                content: "ignored".to_string(),
-                location: Location::new(1, 9),
-                end_location: Location::new(1, 11),
-            },
+        let diagnostics = vec![
+            diagnostic_with_fix(
+                true,
+                Fix {
+                    content: String::new(),
+                    location: Location::new(1, 7),
+                    end_location: Location::new(1, 15),
+                },
+            ),
+            diagnostic_with_fix(
+                false,
+                Fix {
+                    content: "ignored".to_string(),
+                    location: Location::new(1, 9),
+                    end_location: Location::new(1, 11),
+                },
+            ),
         ];
         let locator = Locator::new(
             r#"
@@ -196,7 +265,7 @@ class A(object):
 "#
             .trim(),
         );
-        let (contents, fixed) = apply_fixes(fixes.iter(), &locator);
+        let (contents, fixed, skipped) = apply_fixes(diagnostics.iter(), &locator);
         assert_eq!(
             contents,
             r#"
@@ -206,5 +275,8 @@ class A:
             .trim(),
         );
         assert_eq!(fixed, 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].rule, Rule::TypeComparison);
+        assert_eq!(skipped[0].conflicts_with, Rule::RedundantBackslash);
     }
 }
@@ -25,6 +25,8 @@ use crate::ast::types::{
 use crate::ast::visitor::{walk_excepthandler, Visitor};
 use crate::ast::{branch_detection, cast, helpers, operations, visitor};
 use crate::docstrings::definition::{Definition, DefinitionKind, Docstring, Documentable};
+use crate::fix::Fix;
+use crate::fs;
 use crate::noqa::Directive;
 use crate::python::builtins::{BUILTINS, MAGIC_GLOBALS};
 use crate::python::future::ALL_FEATURE_NAMES;
@@ -35,7 +37,7 @@ use crate::rules::{
     flake8_2020, flake8_annotations, flake8_bandit, flake8_blind_except, flake8_boolean_trap,
     flake8_bugbear, flake8_builtins, flake8_comprehensions, flake8_datetimez, flake8_debugger,
     flake8_errmsg, flake8_implicit_str_concat, flake8_import_conventions, flake8_pie, flake8_print,
-    flake8_pytest_style, flake8_return, flake8_simplify, flake8_tidy_imports,
+    flake8_pyi, flake8_pytest_style, flake8_return, flake8_simplify, flake8_tidy_imports,
     flake8_unused_arguments, mccabe, pandas_vet, pep8_naming, pycodestyle, pydocstyle, pyflakes,
     pygrep_hooks, pylint, pyupgrade, ruff,
 };
@@ -43,9 +45,87 @@ use crate::settings::types::PythonVersion;
 use crate::settings::{flags, Settings};
 use crate::source_code::{Indexer, Locator, Stylist};
 use crate::violations::DeferralKeyword;
-use crate::visibility::{module_visibility, transition_scope, Modifier, Visibility, VisibleScope};
+use crate::visibility::{
+    is_stub_body, module_visibility, transition_scope, Modifier, Visibility, VisibleScope,
+};
 use crate::{autofix, docstrings, noqa, violations, visibility};
 
+/// A lint check that runs once a scope has closed, over that scope's finalized bindings. See
+/// [`Checker::scope_exit_rules`] for how these are registered and run.
+trait ScopeExitRule: Sync {
+    fn rule(&self) -> Rule;
+    fn check(&self, checker: &Checker, scope: &Scope) -> Vec<Diagnostic>;
+}
+
+struct GlobalVariableNotAssignedRule;
+
+impl ScopeExitRule for GlobalVariableNotAssignedRule {
+    fn rule(&self) -> Rule {
+        Rule::GlobalVariableNotAssigned
+    }
+
+    fn check(&self, checker: &Checker, scope: &Scope) -> Vec<Diagnostic> {
+        scope
+            .values
+            .iter()
+            .filter_map(|(name, index)| {
+                let binding = &checker.bindings[*index];
+                if matches!(binding.kind, BindingKind::Global) {
+                    Some(Diagnostic::new(
+                        violations::GlobalVariableNotAssigned((*name).to_string()),
+                        binding.range,
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+struct UnusedPrivateModuleFunctionRule;
+
+impl ScopeExitRule for UnusedPrivateModuleFunctionRule {
+    fn rule(&self) -> Rule {
+        Rule::UnusedPrivateModuleFunction
+    }
+
+    fn check(&self, checker: &Checker, scope: &Scope) -> Vec<Diagnostic> {
+        if !matches!(scope.kind, ScopeKind::Module) {
+            return vec![];
+        }
+        scope
+            .values
+            .iter()
+            .filter_map(|(name, index)| {
+                let binding = &checker.bindings[*index];
+                if !matches!(binding.kind, BindingKind::FunctionDefinition) {
+                    return None;
+                }
+                if !name.starts_with('_') || visibility::is_magic(name) {
+                    return None;
+                }
+                if checker
+                    .settings
+                    .ruff
+                    .ignore_names
+                    .iter()
+                    .any(|ignored| ignored.as_str() == *name)
+                {
+                    return None;
+                }
+                if binding.used.is_some() {
+                    return None;
+                }
+                Some(Diagnostic::new(
+                    violations::UnusedPrivateModuleFunction((*name).to_string()),
+                    binding.range,
+                ))
+            })
+            .collect()
+    }
+}
+
 const GLOBAL_SCOPE_INDEX: usize = 0;
 
 type DeferralContext<'a> = (Vec<usize>, Vec<RefEquality<'a, Stmt>>);
@@ -57,10 +137,14 @@ pub struct Checker<'a> {
     autofix: flags::Autofix,
     noqa: flags::Noqa,
     pub(crate) settings: &'a Settings,
+    // The `target-version` to enforce, taking into account any
+    // `[[tool.ruff.overrides]]` block that matches `path`.
+    pub(crate) target_version: PythonVersion,
     pub(crate) noqa_line_for: &'a IntMap<usize, usize>,
     pub(crate) locator: &'a Locator<'a>,
     pub(crate) stylist: &'a Stylist<'a>,
     pub(crate) indexer: &'a Indexer,
+    pub(crate) is_stub: bool,
     // Computed diagnostics.
     pub(crate) diagnostics: Vec<Diagnostic>,
     // Function and class definition tracking (e.g., for docstring enforcement).
@@ -73,6 +157,11 @@ pub struct Checker<'a> {
     pub(crate) parents: Vec<RefEquality<'a, Stmt>>,
     pub(crate) depths: FxHashMap<RefEquality<'a, Stmt>, usize>,
     pub(crate) child_to_parent: FxHashMap<RefEquality<'a, Stmt>, RefEquality<'a, Stmt>>,
+    // Parent-expression and enclosing-statement links, keyed by every expression visited so
+    // far (not just those on the current traversal path), so that rules and fixes can look up
+    // enclosing context for an arbitrary `Expr` without maintaining their own stacks.
+    pub(crate) expr_parents: FxHashMap<RefEquality<'a, Expr>, RefEquality<'a, Expr>>,
+    pub(crate) expr_parent_stmt: FxHashMap<RefEquality<'a, Expr>, RefEquality<'a, Stmt>>,
     pub(crate) bindings: Vec<Binding<'a>>,
     pub(crate) redefinitions: IntMap<usize, Vec<usize>>,
     pub(crate) exprs: Vec<RefEquality<'a, Expr>>,
@@ -112,8 +201,13 @@ impl<'a> Checker<'a> {
         style: &'a Stylist,
         indexer: &'a Indexer,
     ) -> Checker<'a> {
+        let target_version = fs::first_matching_override(path, &settings.overrides)
+            .ok()
+            .flatten()
+            .map_or(settings.target_version, |over| over.target_version);
         Checker {
             settings,
+            target_version,
             noqa_line_for,
             autofix,
             noqa,
@@ -121,12 +215,15 @@ impl<'a> Checker<'a> {
             locator,
             stylist: style,
             indexer,
+            is_stub: path.extension().map_or(false, |ext| ext == "pyi"),
             diagnostics: vec![],
             definitions: vec![],
             deletions: FxHashSet::default(),
             parents: vec![],
             depths: FxHashMap::default(),
             child_to_parent: FxHashMap::default(),
+            expr_parents: FxHashMap::default(),
+            expr_parent_stmt: FxHashMap::default(),
             bindings: vec![],
             redefinitions: IntMap::default(),
             exprs: vec![],
@@ -164,6 +261,11 @@ impl<'a> Checker<'a> {
         matches!(self.autofix, flags::Autofix::Enabled) && self.settings.rules.should_fix(code)
     }
 
+    /// Return the path to the file being checked.
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
     /// Return `true` if the `Expr` is a reference to `typing.${target}`.
     pub fn match_typing_expr(&self, expr: &Expr, target: &str) -> bool {
         self.resolve_call_path(expr).map_or(false, |call_path| {
@@ -510,12 +612,12 @@ where
                     .settings
                     .rules
                     .enabled(&Rule::LRUCacheWithoutParameters)
-                    && self.settings.target_version >= PythonVersion::Py38
+                    && self.target_version >= PythonVersion::Py38
                 {
                     pyupgrade::rules::lru_cache_without_parameters(self, decorator_list);
                 }
                 if self.settings.rules.enabled(&Rule::FunctoolsCache)
-                    && self.settings.target_version >= PythonVersion::Py39
+                    && self.target_version >= PythonVersion::Py39
                 {
                     pyupgrade::rules::functools_cache(self, decorator_list);
                 }
@@ -561,6 +663,24 @@ where
                     pylint::rules::property_with_parameters(self, stmt, decorator_list, args);
                 }
 
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::UnexpectedSpecialMethodSignature)
+                {
+                    pylint::rules::unexpected_special_method_signature(
+                        self,
+                        stmt,
+                        name,
+                        decorator_list,
+                        args,
+                    );
+                }
+
+                if self.settings.rules.enabled(&Rule::ImplicitOptional) {
+                    ruff::rules::implicit_optional(self, args);
+                }
+
                 if self
                     .settings
                     .rules
@@ -768,6 +888,23 @@ where
                     flake8_pie::rules::prefer_unique_enums(self, stmt, body);
                 }
 
+                if self.settings.rules.enabled(&Rule::MutableClassDefault) {
+                    ruff::rules::mutable_class_default(self, body, decorator_list);
+                }
+
+                if self.settings.rules.enabled(&Rule::SingleStringSlots) {
+                    pylint::rules::single_string_slots(self, body);
+                }
+
+                if self.settings.rules.enabled(&Rule::TooManyPublicMethods) {
+                    pylint::rules::too_many_public_methods(
+                        self,
+                        stmt,
+                        body,
+                        self.settings.pylint.max_public_methods,
+                    );
+                }
+
                 self.check_builtin_shadowing(name, stmt, false);
 
                 for expr in bases {
@@ -783,10 +920,18 @@ where
             StmtKind::Import { names } => {
                 if self.settings.rules.enabled(&Rule::MultipleImportsOnOneLine) {
                     if names.len() > 1 {
-                        self.diagnostics.push(Diagnostic::new(
+                        let mut diagnostic = Diagnostic::new(
                             violations::MultipleImportsOnOneLine,
                             Range::from_located(stmt),
-                        ));
+                        );
+                        if self.patch(&Rule::MultipleImportsOnOneLine) {
+                            if let Some(fix) =
+                                autofix::helpers::split_multi_import(stmt, self.locator)
+                            {
+                                diagnostic.amend(fix);
+                            }
+                        }
+                        self.diagnostics.push(diagnostic);
                     }
                 }
 
@@ -865,9 +1010,12 @@ where
 
                     // flake8-debugger
                     if self.settings.rules.enabled(&Rule::Debugger) {
-                        if let Some(diagnostic) =
-                            flake8_debugger::rules::debugger_import(stmt, None, &alias.node.name)
-                        {
+                        if let Some(diagnostic) = flake8_debugger::rules::debugger_import(
+                            stmt,
+                            None,
+                            &alias.node.name,
+                            &self.settings.flake8_debugger.extend_banned_calls,
+                        ) {
                             self.diagnostics.push(diagnostic);
                         }
                     }
@@ -876,6 +1024,7 @@ where
                     if self.settings.rules.enabled(&Rule::BannedApi) {
                         if let Some(diagnostic) =
                             flake8_tidy_imports::banned_api::name_or_parent_is_banned(
+                                self,
                                 alias,
                                 &alias.node.name,
                                 &self.settings.flake8_tidy_imports.banned_api,
@@ -998,6 +1147,19 @@ where
                         }
                     }
 
+                    if self.settings.rules.enabled(&Rule::BannedImportAlias) {
+                        if let Some(diagnostic) =
+                            flake8_import_conventions::rules::check_banned_import_alias(
+                                stmt,
+                                &alias.node.name,
+                                alias.node.asname.as_deref(),
+                                &self.settings.flake8_import_conventions.banned_aliases,
+                            )
+                        {
+                            self.diagnostics.push(diagnostic);
+                        }
+                    }
+
                     if self.settings.rules.enabled(&Rule::IncorrectPytestImport) {
                         if let Some(diagnostic) = flake8_pytest_style::rules::import(
                             stmt,
@@ -1049,6 +1211,7 @@ where
                         for name in names {
                             if let Some(diagnostic) =
                                 flake8_tidy_imports::banned_api::name_is_banned(
+                                    self,
                                     module,
                                     name,
                                     &self.settings.flake8_tidy_imports.banned_api,
@@ -1059,6 +1222,7 @@ where
                         }
                         if let Some(diagnostic) =
                             flake8_tidy_imports::banned_api::name_or_parent_is_banned(
+                                self,
                                 stmt,
                                 module,
                                 &self.settings.flake8_tidy_imports.banned_api,
@@ -1207,6 +1371,20 @@ where
                         );
                     }
 
+                    if self.settings.rules.enabled(&Rule::BannedImportFrom) {
+                        if let Some(module) = module.as_deref() {
+                            if let Some(diagnostic) =
+                                flake8_import_conventions::rules::check_banned_import_from(
+                                    stmt,
+                                    module,
+                                    &self.settings.flake8_import_conventions.banned_from,
+                                )
+                            {
+                                self.diagnostics.push(diagnostic);
+                            }
+                        }
+                    }
+
                     if self.settings.rules.enabled(&Rule::RelativeImports) {
                         if let Some(diagnostic) =
                             flake8_tidy_imports::relative_imports::banned_relative_import(
@@ -1225,6 +1403,7 @@ where
                             stmt,
                             module.as_deref(),
                             &alias.node.name,
+                            &self.settings.flake8_debugger.extend_banned_calls,
                         ) {
                             self.diagnostics.push(diagnostic);
                         }
@@ -1377,6 +1556,9 @@ where
                         self, stmt, test, body, orelse,
                     );
                 }
+                if self.settings.rules.enabled(&Rule::OutdatedVersionBlock) {
+                    pyupgrade::rules::outdated_version_block(self, stmt, test, body, orelse);
+                }
             }
             StmtKind::Assert { test, msg } => {
                 if self.settings.rules.enabled(&Rule::AssertTuple) {
@@ -1394,6 +1576,9 @@ where
                     self.diagnostics
                         .push(flake8_bandit::rules::assert_used(stmt));
                 }
+                if self.settings.rules.enabled(&Rule::AssertOnStringLiteral) {
+                    pylint::rules::assert_on_string_literal(self, stmt, test);
+                }
                 if self.settings.rules.enabled(&Rule::AssertAlwaysFalse) {
                     if let Some(diagnostic) = flake8_pytest_style::rules::assert_falsy(stmt, test) {
                         self.diagnostics.push(diagnostic);
@@ -1530,6 +1715,12 @@ where
                         self, body, handlers, finalbody,
                     );
                 }
+                if self.settings.rules.enabled(&Rule::TryExceptPass) {
+                    flake8_bandit::rules::try_except_pass(self, handlers);
+                }
+                if self.settings.rules.enabled(&Rule::TryExceptContinue) {
+                    flake8_bandit::rules::try_except_continue(self, handlers);
+                }
             }
             StmtKind::Assign { targets, value, .. } => {
                 if self.settings.rules.enabled(&Rule::DoNotAssignLambda) {
@@ -1542,6 +1733,10 @@ where
                     flake8_bugbear::rules::assignment_to_os_environ(self, targets);
                 }
 
+                if self.settings.rules.enabled(&Rule::SelfAssigningVariable) {
+                    pylint::rules::self_assigning_variable(self, stmt, targets, value);
+                }
+
                 if self.settings.rules.enabled(&Rule::HardcodedPasswordString) {
                     if let Some(diagnostic) =
                         flake8_bandit::rules::assign_hardcoded_password_string(value, targets)
@@ -1600,6 +1795,9 @@ where
                 {
                     flake8_simplify::rules::use_capital_environment_variables(self, value);
                 }
+                if self.settings.rules.enabled(&Rule::AsyncioDanglingTask) {
+                    ruff::rules::asyncio_dangling_task(self, value);
+                }
             }
             _ => {}
         }
@@ -1821,6 +2019,9 @@ where
                 ..
             } = &expr.node
             {
+                if self.in_annotation && self.settings.rules.enabled(&Rule::QuotedAnnotation) {
+                    ruff::rules::quoted_annotation(self, expr, value);
+                }
                 self.deferred_string_type_definitions.push((
                     Range::from_located(expr),
                     value,
@@ -1849,8 +2050,8 @@ where
                 if !self.in_deferred_string_type_definition
                     && self.in_annotation
                     && self.settings.rules.enabled(&Rule::UsePEP604Annotation)
-                    && (self.settings.target_version >= PythonVersion::Py310
-                        || (self.settings.target_version >= PythonVersion::Py37
+                    && (self.target_version >= PythonVersion::Py310
+                        || (self.target_version >= PythonVersion::Py37
                             && !self.settings.pyupgrade.keep_runtime_typing
                             && self.annotations_future_enabled))
                 {
@@ -1903,8 +2104,8 @@ where
                         // Ex) List[...]
                         if !self.in_deferred_string_type_definition
                             && self.settings.rules.enabled(&Rule::UsePEP585Annotation)
-                            && (self.settings.target_version >= PythonVersion::Py39
-                                || (self.settings.target_version >= PythonVersion::Py37
+                            && (self.target_version >= PythonVersion::Py39
+                                || (self.target_version >= PythonVersion::Py37
                                     && !self.settings.pyupgrade.keep_runtime_typing
                                     && self.annotations_future_enabled
                                     && self.in_annotation))
@@ -1948,8 +2149,8 @@ where
                 // Ex) typing.List[...]
                 if !self.in_deferred_string_type_definition
                     && self.settings.rules.enabled(&Rule::UsePEP585Annotation)
-                    && (self.settings.target_version >= PythonVersion::Py39
-                        || (self.settings.target_version >= PythonVersion::Py37
+                    && (self.target_version >= PythonVersion::Py39
+                        || (self.target_version >= PythonVersion::Py37
                             && self.annotations_future_enabled
                             && self.in_annotation))
                     && typing::is_pep585_builtin(self, expr)
@@ -1962,7 +2163,7 @@ where
                 }
 
                 if self.settings.rules.enabled(&Rule::DatetimeTimezoneUTC)
-                    && self.settings.target_version >= PythonVersion::Py311
+                    && self.target_version >= PythonVersion::Py311
                 {
                     pyupgrade::rules::datetime_utc_alias(self, expr);
                 }
@@ -1991,29 +2192,7 @@ where
                                     continue;
                                 }
                             }
-                            // Avoid flagging on non-DataFrames (e.g., `{"a": 1}.values`).
-                            if pandas_vet::helpers::is_dataframe_candidate(value) {
-                                // If the target is a named variable, avoid triggering on
-                                // irrelevant bindings (like imports).
-                                if let ExprKind::Name { id, .. } = &value.node {
-                                    if self.find_binding(id).map_or(true, |binding| {
-                                        matches!(
-                                            binding.kind,
-                                            BindingKind::Builtin
-                                                | BindingKind::ClassDefinition
-                                                | BindingKind::FunctionDefinition
-                                                | BindingKind::Export(..)
-                                                | BindingKind::FutureImportation
-                                                | BindingKind::StarImportation(..)
-                                                | BindingKind::Importation(..)
-                                                | BindingKind::FromImportation(..)
-                                                | BindingKind::SubmoduleImportation(..)
-                                        )
-                                    }) {
-                                        continue;
-                                    }
-                                }
-
+                            if pandas_vet::helpers::is_valid_pandas_receiver(self, value, false) {
                                 self.diagnostics
                                     .push(Diagnostic::new(code.kind(), Range::from_located(expr)));
                             }
@@ -2192,7 +2371,7 @@ where
                     );
                 }
                 if self.settings.rules.enabled(&Rule::ZipWithoutExplicitStrict)
-                    && self.settings.target_version >= PythonVersion::Py310
+                    && self.target_version >= PythonVersion::Py310
                 {
                     flake8_bugbear::rules::zip_without_explicit_strict(self, expr, func, keywords);
                 }
@@ -2243,6 +2422,42 @@ where
                 if self.settings.rules.enabled(&Rule::RequestWithoutTimeout) {
                     flake8_bandit::rules::request_without_timeout(self, func, args, keywords);
                 }
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::SubprocessPopenWithShellEqualsTrue)
+                {
+                    flake8_bandit::rules::subprocess_popen_with_shell_equals_true(
+                        self, func, args, keywords,
+                    );
+                }
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::SubprocessWithoutShellEqualsTrue)
+                {
+                    flake8_bandit::rules::subprocess_without_shell_equals_true(
+                        self, func, args, keywords,
+                    );
+                }
+                if self.settings.rules.enabled(&Rule::CallWithShellEqualsTrue) {
+                    flake8_bandit::rules::call_with_shell_equals_true(self, func, args, keywords);
+                }
+                if self.settings.rules.enabled(&Rule::StartProcessWithAShell) {
+                    flake8_bandit::rules::start_process_with_a_shell(self, func);
+                }
+                if self.settings.rules.enabled(&Rule::StartProcessWithNoShell) {
+                    flake8_bandit::rules::start_process_with_no_shell(self, func);
+                }
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::StartProcessWithPartialPath)
+                {
+                    flake8_bandit::rules::start_process_with_partial_path(
+                        self, func, args, keywords,
+                    );
+                }
 
                 // flake8-comprehensions
                 if self.settings.rules.enabled(&Rule::UnnecessaryGeneratorList) {
@@ -2348,6 +2563,11 @@ where
                 if self.settings.rules.enabled(&Rule::UnnecessaryMap) {
                     flake8_comprehensions::rules::unnecessary_map(self, expr, func, args);
                 }
+                if self.settings.rules.enabled(&Rule::UnnecessaryDictCall) {
+                    flake8_comprehensions::rules::unnecessary_dict_call(
+                        self, expr, func, args, keywords,
+                    );
+                }
 
                 // flake8-boolean-trap
                 if self
@@ -2359,6 +2579,15 @@ where
                         self, args, func,
                     );
                 }
+
+                // flake8-pie
+                if self.settings.rules.enabled(&Rule::UnnecessaryDictKwargs) {
+                    flake8_pie::rules::unnecessary_dict_kwargs(self, keywords);
+                }
+                if self.settings.rules.enabled(&Rule::UnnecessaryRangeStart) {
+                    flake8_pie::rules::unnecessary_range_start(self, func, args);
+                }
+
                 if let ExprKind::Name { id, ctx } = &func.node {
                     if id == "locals" && matches!(ctx, ExprContext::Load) {
                         let scope = &mut self.scopes
@@ -2384,43 +2613,17 @@ where
                     (Rule::UseOfDotPivotOrUnstack, "unstack"),
                     (Rule::UseOfDotReadTable, "read_table"),
                     (Rule::UseOfDotStack, "stack"),
+                    (Rule::UseOfDotNunique, "nunique"),
                 ] {
                     if self.settings.rules.enabled(&code) {
                         if let ExprKind::Attribute { value, attr, .. } = &func.node {
-                            if attr == name {
-                                if pandas_vet::helpers::is_dataframe_candidate(value) {
-                                    // If the target is a named variable, avoid triggering on
-                                    // irrelevant bindings (like non-Pandas imports).
-                                    if let ExprKind::Name { id, .. } = &value.node {
-                                        if self.find_binding(id).map_or(true, |binding| {
-                                            if let BindingKind::Importation(.., module) =
-                                                &binding.kind
-                                            {
-                                                module != &"pandas"
-                                            } else {
-                                                matches!(
-                                                    binding.kind,
-                                                    BindingKind::Builtin
-                                                        | BindingKind::ClassDefinition
-                                                        | BindingKind::FunctionDefinition
-                                                        | BindingKind::Export(..)
-                                                        | BindingKind::FutureImportation
-                                                        | BindingKind::StarImportation(..)
-                                                        | BindingKind::Importation(..)
-                                                        | BindingKind::FromImportation(..)
-                                                        | BindingKind::SubmoduleImportation(..)
-                                                )
-                                            }
-                                        }) {
-                                            continue;
-                                        }
-                                    }
-
-                                    self.diagnostics.push(Diagnostic::new(
-                                        code.kind(),
-                                        Range::from_located(func),
-                                    ));
-                                }
+                            if attr == name
+                                && pandas_vet::helpers::is_valid_pandas_receiver(self, value, true)
+                            {
+                                self.diagnostics.push(Diagnostic::new(
+                                    code.kind(),
+                                    Range::from_located(func),
+                                ));
                             };
                         }
                     }
@@ -2432,91 +2635,97 @@ where
                 }
 
                 // flake8-datetimez
-                if self
-                    .settings
-                    .rules
-                    .enabled(&Rule::CallDatetimeWithoutTzinfo)
-                {
-                    flake8_datetimez::rules::call_datetime_without_tzinfo(
-                        self,
-                        func,
-                        args,
-                        keywords,
-                        Range::from_located(expr),
-                    );
-                }
-                if self.settings.rules.enabled(&Rule::CallDatetimeToday) {
-                    flake8_datetimez::rules::call_datetime_today(
-                        self,
-                        func,
-                        Range::from_located(expr),
-                    );
-                }
-                if self.settings.rules.enabled(&Rule::CallDatetimeUtcnow) {
-                    flake8_datetimez::rules::call_datetime_utcnow(
-                        self,
-                        func,
-                        Range::from_located(expr),
-                    );
-                }
-                if self
-                    .settings
-                    .rules
-                    .enabled(&Rule::CallDatetimeUtcfromtimestamp)
-                {
-                    flake8_datetimez::rules::call_datetime_utcfromtimestamp(
-                        self,
-                        func,
-                        Range::from_located(expr),
-                    );
-                }
-                if self
-                    .settings
-                    .rules
-                    .enabled(&Rule::CallDatetimeNowWithoutTzinfo)
-                {
-                    flake8_datetimez::rules::call_datetime_now_without_tzinfo(
-                        self,
-                        func,
-                        args,
-                        keywords,
-                        Range::from_located(expr),
-                    );
-                }
-                if self
-                    .settings
-                    .rules
-                    .enabled(&Rule::CallDatetimeFromtimestamp)
-                {
-                    flake8_datetimez::rules::call_datetime_fromtimestamp(
-                        self,
-                        func,
-                        args,
-                        keywords,
-                        Range::from_located(expr),
-                    );
-                }
-                if self
-                    .settings
-                    .rules
-                    .enabled(&Rule::CallDatetimeStrptimeWithoutZone)
-                {
-                    flake8_datetimez::rules::call_datetime_strptime_without_zone(
-                        self,
-                        func,
-                        args,
-                        Range::from_located(expr),
-                    );
-                }
-                if self.settings.rules.enabled(&Rule::CallDateToday) {
-                    flake8_datetimez::rules::call_date_today(self, func, Range::from_located(expr));
-                }
-                if self.settings.rules.enabled(&Rule::CallDateFromtimestamp) {
-                    flake8_datetimez::rules::call_date_fromtimestamp(
-                        self,
-                        func,
-                        Range::from_located(expr),
-                    );
+                if !flake8_datetimez::helpers::in_exempt_time_freezing_context(self) {
+                    if self
+                        .settings
+                        .rules
+                        .enabled(&Rule::CallDatetimeWithoutTzinfo)
+                    {
+                        flake8_datetimez::rules::call_datetime_without_tzinfo(
+                            self,
+                            func,
+                            args,
+                            keywords,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self.settings.rules.enabled(&Rule::CallDatetimeToday) {
+                        flake8_datetimez::rules::call_datetime_today(
+                            self,
+                            func,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self.settings.rules.enabled(&Rule::CallDatetimeUtcnow) {
+                        flake8_datetimez::rules::call_datetime_utcnow(
+                            self,
+                            func,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self
+                        .settings
+                        .rules
+                        .enabled(&Rule::CallDatetimeUtcfromtimestamp)
+                    {
+                        flake8_datetimez::rules::call_datetime_utcfromtimestamp(
+                            self,
+                            func,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self
+                        .settings
+                        .rules
+                        .enabled(&Rule::CallDatetimeNowWithoutTzinfo)
+                    {
+                        flake8_datetimez::rules::call_datetime_now_without_tzinfo(
+                            self,
+                            func,
+                            args,
+                            keywords,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self
+                        .settings
+                        .rules
+                        .enabled(&Rule::CallDatetimeFromtimestamp)
+                    {
+                        flake8_datetimez::rules::call_datetime_fromtimestamp(
+                            self,
+                            func,
+                            args,
+                            keywords,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self
+                        .settings
+                        .rules
+                        .enabled(&Rule::CallDatetimeStrptimeWithoutZone)
+                    {
+                        flake8_datetimez::rules::call_datetime_strptime_without_zone(
+                            self,
+                            func,
+                            args,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self.settings.rules.enabled(&Rule::CallDateToday) {
+                        flake8_datetimez::rules::call_date_today(
+                            self,
+                            func,
+                            Range::from_located(expr),
+                        );
+                    }
+                    if self.settings.rules.enabled(&Rule::CallDateFromtimestamp) {
+                        flake8_datetimez::rules::call_date_fromtimestamp(
+                            self,
+                            func,
+                            Range::from_located(expr),
+                        );
+                    }
                 }
 
                 // pygrep-hooks
@@ -2554,6 +2763,13 @@ where
                         self.diagnostics.push(diagnostic);
                     }
                 }
+                if self.settings.rules.enabled(&Rule::UnittestRaisesAssertion) {
+                    if let Some(diagnostic) = flake8_pytest_style::rules::unittest_raises_assertion(
+                        self, expr, func, args, keywords,
+                    ) {
+                        self.diagnostics.push(diagnostic);
+                    }
+                }
 
                 if self.settings.rules.enabled(&Rule::RaisesWithoutException)
                     || self.settings.rules.enabled(&Rule::RaisesTooBroad)
@@ -2598,6 +2814,9 @@ where
                 {
                     pyflakes::rules::repeated_keys(self, keys, values);
                 }
+                if self.settings.rules.enabled(&Rule::UnnecessarySpread) {
+                    flake8_pie::rules::unnecessary_spread(self, expr, keys, values);
+                }
             }
             ExprKind::Yield { .. } => {
                 if self.settings.rules.enabled(&Rule::YieldOutsideFunction) {
@@ -2643,6 +2862,16 @@ where
                 {
                     pyflakes::rules::f_string_missing_placeholders(expr, values, self);
                 }
+                if self.settings.rules.enabled(&Rule::FStringStrCall) {
+                    for value in values {
+                        if let ExprKind::FormattedValue {
+                            value, conversion, ..
+                        } = &value.node
+                        {
+                            ruff::rules::f_string_str_call(self, value, *conversion);
+                        }
+                    }
+                }
             }
             ExprKind::BinOp {
                 left,
@@ -2799,7 +3028,9 @@ where
                 }
             }
             ExprKind::BinOp {
-                op: Operator::Add, ..
+                left,
+                op: Operator::Add,
+                right,
             } => {
                 if self
                     .settings
@@ -2810,6 +3041,13 @@ where
                         self.diagnostics.push(diagnostic);
                     }
                 }
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::CollectionLiteralConcatenation)
+                {
+                    ruff::rules::collection_literal_concatenation(self, expr, left, right);
+                }
             }
             ExprKind::UnaryOp { op, operand } => {
                 let check_not_in = self.settings.rules.enabled(&Rule::NotInTest);
@@ -3019,7 +3257,26 @@ where
                 }
                 self.push_scope(Scope::new(ScopeKind::Generator));
             }
-            ExprKind::GeneratorExp { .. } | ExprKind::DictComp { .. } => {
+            ExprKind::DictComp {
+                key,
+                value,
+                generators,
+            } => {
+                if self
+                    .settings
+                    .rules
+                    .enabled(&Rule::UnnecessaryDictComprehensionForIterable)
+                {
+                    flake8_comprehensions::rules::unnecessary_dict_comprehension_for_iterable(
+                        self, expr, key, value, generators,
+                    );
+                }
+                if self.settings.rules.enabled(&Rule::FunctionUsesLoopVariable) {
+                    flake8_bugbear::rules::function_uses_loop_variable(self, &Node::Expr(expr));
+                }
+                self.push_scope(Scope::new(ScopeKind::Generator));
+            }
+            ExprKind::GeneratorExp { .. } => {
                 if self.settings.rules.enabled(&Rule::FunctionUsesLoopVariable) {
                     flake8_bugbear::rules::function_uses_loop_variable(self, &Node::Expr(expr));
                 }
@@ -3051,6 +3308,9 @@ where
                 if self.settings.rules.enabled(&Rule::AndFalse) {
                     flake8_simplify::rules::and_false(self, expr);
                 }
+                if self.settings.rules.enabled(&Rule::MultipleStartsEndsWith) {
+                    flake8_pie::rules::multiple_starts_ends_with(self, expr);
+                }
             }
             _ => {}
         };
@@ -3335,6 +3595,22 @@ where
                         body,
                     );
                 }
+                if self.settings.rules.enabled(&Rule::BlindExceptSwallow) {
+                    flake8_blind_except::rules::blind_except_swallow(
+                        self,
+                        excepthandler,
+                        type_.as_deref(),
+                        body,
+                    );
+                }
+                if self.settings.rules.enabled(&Rule::BlindExceptWithoutLogging) {
+                    flake8_blind_except::rules::blind_except_without_logging(
+                        self,
+                        excepthandler,
+                        type_.as_deref(),
+                        body,
+                    );
+                }
                 match name {
                     Some(name) => {
                         if self.settings.rules.enabled(&Rule::AmbiguousVariableName) {
@@ -3568,6 +3844,12 @@ impl<'a> Checker<'a> {
     }
 
     fn push_expr(&mut self, expr: &'a Expr) {
+        if let Some(parent) = self.exprs.last() {
+            self.expr_parents
+                .insert(RefEquality(expr), parent.clone());
+        }
+        self.expr_parent_stmt
+            .insert(RefEquality(expr), self.current_stmt().clone());
         self.exprs.push(RefEquality(expr));
     }
 
@@ -3630,6 +3912,23 @@ impl<'a> Checker<'a> {
         self.exprs.iter().rev().nth(2)
     }
 
+    /// Return the parent `Stmt` of `stmt`, if any. Unlike `current_stmt_parent`, this works
+    /// for any statement visited so far, not just the one currently being visited.
+    pub fn parent_stmt(&self, stmt: &'a Stmt) -> Option<&'a Stmt> {
+        self.child_to_parent.get(&RefEquality(stmt)).map(Into::into)
+    }
+
+    /// Return the parent `Expr` of `expr`, if any. Unlike `current_expr_parent`, this works
+    /// for any expression visited so far, not just the one currently being visited.
+    pub fn parent_expr(&self, expr: &'a Expr) -> Option<&'a Expr> {
+        self.expr_parents.get(&RefEquality(expr)).map(Into::into)
+    }
+
+    /// Return the `Stmt` that directly encloses `expr`.
+    pub fn expr_stmt(&self, expr: &'a Expr) -> Option<&'a Stmt> {
+        self.expr_parent_stmt.get(&RefEquality(expr)).map(Into::into)
+    }
+
     pub fn current_scope(&self) -> &Scope {
         &self.scopes[*(self.scope_stack.last().expect("No current scope found"))]
     }
@@ -3641,6 +3940,96 @@ impl<'a> Checker<'a> {
             .map(|index| &self.scopes[*index])
     }
 
+    /// Return `true` if `stmt` is nested inside a conditional-import guard —
+    /// a `try`/`except ImportError` (or `ModuleNotFoundError`) fallback, or
+    /// an `if` gated on `typing.TYPE_CHECKING`, `sys.version_info`,
+    /// `sys.platform`, or `os.name` — where the "unused" branch typically
+    /// exists on purpose (e.g. a fallback import for an older Python, or a
+    /// type-only import) rather than by mistake.
+    fn is_conditional_import(&self, stmt: &'a Stmt) -> bool {
+        let mut current = RefEquality(stmt);
+        while let Some(parent) = self.child_to_parent.get(&current) {
+            let parent_stmt: &Stmt = parent.into();
+            match &parent_stmt.node {
+                StmtKind::Try { body, handlers, .. }
+                    if body.iter().any(|stmt| std::ptr::eq(stmt, current.0)) =>
+                {
+                    if handlers.iter().any(|handler| {
+                        let ExcepthandlerKind::ExceptHandler { type_, .. } = &handler.node;
+                        type_.as_ref().map_or(false, |type_| {
+                            matches!(
+                                collect_call_path(type_).last().copied(),
+                                Some("ImportError" | "ModuleNotFoundError")
+                            )
+                        })
+                    }) {
+                        return true;
+                    }
+                }
+                StmtKind::If { test, .. } => {
+                    if helpers::contains_call_path(self, test, &["typing", "TYPE_CHECKING"])
+                        || helpers::contains_call_path(self, test, &["sys", "version_info"])
+                        || helpers::contains_call_path(self, test, &["sys", "platform"])
+                        || helpers::contains_call_path(self, test, &["os", "name"])
+                    {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+            current = *parent;
+        }
+        false
+    }
+
+    /// If `existing` is an unused import, generate a fix that removes it in
+    /// favor of the binding that's about to shadow it. Returns `None` for
+    /// anything riskier than a plain import (star imports, `__future__`
+    /// imports, or non-import bindings such as functions and classes), since
+    /// there's no safe single-name removal primitive for those, or when the
+    /// import lives inside a conditional-import guard (see
+    /// `is_conditional_import`).
+    fn try_remove_shadowed_import(&mut self, existing: &Binding<'a>) -> Option<Fix> {
+        let full_name = match &existing.kind {
+            BindingKind::Importation(.., full_name) => full_name,
+            BindingKind::FromImportation(.., full_name) => full_name.as_str(),
+            BindingKind::SubmoduleImportation(.., full_name) => full_name,
+            _ => return None,
+        };
+
+        let defined_by = existing.source.as_ref()?;
+        let child: &Stmt = defined_by.into();
+        if self.is_conditional_import(child) {
+            return None;
+        }
+        let parent: Option<&Stmt> = self.child_to_parent.get(defined_by).map(Into::into);
+        let deleted: Vec<&Stmt> = self
+            .deletions
+            .iter()
+            .map(std::convert::Into::into)
+            .collect();
+
+        match autofix::helpers::remove_unused_imports(
+            std::iter::once(full_name),
+            child,
+            parent,
+            &deleted,
+            self.locator,
+            self.indexer,
+        ) {
+            Ok(fix) => {
+                if fix.content.is_empty() || fix.content == "pass" {
+                    self.deletions.insert(defined_by.clone());
+                }
+                Some(fix)
+            }
+            Err(e) => {
+                error!("Failed to remove shadowed import: {e}");
+                None
+            }
+        }
+    }
+
     fn add_binding<'b>(&mut self, name: &'b str, binding: Binding<'a>)
     where
         'b: 'a,
@@ -3701,13 +4090,20 @@ impl<'a> Checker<'a> {
                     {
                         overridden = Some((*scope_index, *existing_binding_index));
                         if self.settings.rules.enabled(&Rule::RedefinedWhileUnused) {
-                            self.diagnostics.push(Diagnostic::new(
+                            let mut diagnostic = Diagnostic::new(
                                 violations::RedefinedWhileUnused(
                                     name.to_string(),
                                     existing.range.location.row(),
                                 ),
                                 binding_range(&binding, self.locator),
-                            ));
+                            );
+                            if self.patch(&Rule::RedefinedWhileUnused) {
+                                let existing = existing.clone();
+                                if let Some(fix) = self.try_remove_shadowed_import(&existing) {
+                                    diagnostic.amend(fix);
+                                }
+                            }
+                            self.diagnostics.push(diagnostic);
                         }
                     }
                 } else if existing_is_import && binding.redefines(existing) {
@@ -4210,6 +4606,21 @@ impl<'a> Checker<'a> {
         }
     }
 
+    /// A lint check that runs once a scope has closed, over that scope's finalized bindings.
+    /// Rules that need to aggregate over everything defined in a scope (e.g. "is this global
+    /// ever assigned anywhere in the scope that declares it?") implement this trait instead of
+    /// hand-rolling another pass over `self.dead_scopes` in [`Checker::check_dead_scopes`].
+    ///
+    /// The rest of `check_dead_scopes`'s inline checks (unused imports, redefinitions, `__all__`
+    /// exports) are more deeply coupled to loop-local state (e.g. `all_names`) and are left as-is
+    /// rather than risk a blind, unverifiable rewrite of the whole function.
+    fn scope_exit_rules() -> &'static [&'static dyn ScopeExitRule] {
+        &[
+            &GlobalVariableNotAssignedRule,
+            &UnusedPrivateModuleFunctionRule,
+        ]
+    }
+
     fn check_dead_scopes(&mut self) {
         if !self.settings.rules.enabled(&Rule::UnusedImport)
             && !self.settings.rules.enabled(&Rule::ImportStarUsage)
@@ -4219,6 +4630,10 @@ impl<'a> Checker<'a> {
                 .settings
                 .rules
                 .enabled(&Rule::GlobalVariableNotAssigned)
+            && !self
+                .settings
+                .rules
+                .enabled(&Rule::UnusedPrivateModuleFunction)
         {
             return;
         }
@@ -4230,20 +4645,9 @@ impl<'a> Checker<'a> {
             .rev()
             .map(|index| &self.scopes[*index])
         {
-            // PLW0602
-            if self
-                .settings
-                .rules
-                .enabled(&Rule::GlobalVariableNotAssigned)
-            {
-                for (name, index) in &scope.values {
-                    let binding = &self.bindings[*index];
-                    if matches!(binding.kind, BindingKind::Global) {
-                        diagnostics.push(Diagnostic::new(
-                            violations::GlobalVariableNotAssigned((*name).to_string()),
-                            binding.range,
-                        ));
-                    }
+            for rule in Self::scope_exit_rules() {
+                if self.settings.rules.enabled(&rule.rule()) {
+                    diagnostics.extend(rule.check(self, scope));
                 }
             }
 
@@ -4425,7 +4829,10 @@ impl<'a> Checker<'a> {
                     let child: &Stmt = defined_by.into();
                     let parent: Option<&Stmt> = defined_in.map(std::convert::Into::into);
 
-                    let fix = if !ignore_init && self.patch(&Rule::UnusedImport) {
+                    let fix = if !ignore_init
+                        && self.patch(&Rule::UnusedImport)
+                        && !self.is_conditional_import(child)
+                    {
                         let deleted: Vec<&Stmt> = self
                             .deletions
                             .iter()
@@ -4605,7 +5012,8 @@ impl<'a> Checker<'a> {
             || self.settings.rules.enabled(&Rule::SectionNameEndsInColon)
             || self.settings.rules.enabled(&Rule::DocumentAllArguments)
             || self.settings.rules.enabled(&Rule::SkipDocstring)
-            || self.settings.rules.enabled(&Rule::NonEmpty);
+            || self.settings.rules.enabled(&Rule::NonEmpty)
+            || self.settings.rules.enabled(&Rule::SyntaxErrorInDoctest);
 
         let mut overloaded_name: Option<String> = None;
         self.definitions.reverse();
@@ -4632,8 +5040,31 @@ impl<'a> Checker<'a> {
                 overloaded_name = flake8_annotations::helpers::overloaded_name(self, &definition);
             }
 
+            // flake8-pyi
+            if self.is_stub && self.settings.rules.enabled(&Rule::DocstringInStub) {
+                if let Some(docstring) = definition.docstring {
+                    flake8_pyi::rules::docstring_in_stub(self, docstring);
+                }
+            }
+
             // pydocstyle
             if enforce_docstrings {
+                if self.settings.pydocstyle.ignore_stub_functions
+                    && definition.docstring.is_none()
+                    && match &definition.kind {
+                        DefinitionKind::Function(stmt)
+                        | DefinitionKind::NestedFunction(stmt)
+                        | DefinitionKind::Method(stmt) => match &stmt.node {
+                            StmtKind::FunctionDef { body, .. }
+                            | StmtKind::AsyncFunctionDef { body, .. } => is_stub_body(body),
+                            _ => false,
+                        },
+                        _ => false,
+                    }
+                {
+                    continue;
+                }
+
                 if definition.docstring.is_none() {
                     pydocstyle::rules::not_missing(self, &definition, &visibility);
                     continue;
@@ -4661,6 +5092,10 @@ impl<'a> Checker<'a> {
                     continue;
                 }
 
+                if self.settings.rules.enabled(&Rule::SyntaxErrorInDoctest) {
+                    ruff::rules::doctest_syntax_error(self, &docstring);
+                }
+
                 if self.settings.rules.enabled(&Rule::FitsOnOneLine) {
                     pydocstyle::rules::one_liner(self, &docstring);
                 }
@@ -4779,6 +5214,9 @@ impl<'a> Checker<'a> {
                         self.settings.pydocstyle.convention.as_ref(),
                     );
                 }
+                if self.settings.rules.enabled(&Rule::UndocumentedException) {
+                    ruff::rules::undocumented_raises(self, &docstring);
+                }
             }
         }
     }
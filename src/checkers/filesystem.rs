@@ -2,14 +2,33 @@ use std::path::Path;
 
 use crate::registry::{Diagnostic, Rule};
 use crate::rules::flake8_no_pep420::rules::implicit_namespace_package;
+use crate::rules::pep8_naming::rules::invalid_module_name;
 use crate::settings::Settings;
 
 pub fn check_file_path(path: &Path, settings: &Settings) -> Vec<Diagnostic> {
     let mut diagnostics: Vec<Diagnostic> = vec![];
 
     // flake8-no-pep420
-    if settings.rules.enabled(&Rule::ImplicitNamespacePackage) {
-        if let Some(diagnostic) = implicit_namespace_package(path) {
+    if settings.rules.enabled(&Rule::ImplicitNamespacePackage)
+        || settings
+            .rules
+            .enabled(&Rule::ImplicitNamespacePackageInScriptDirectory)
+    {
+        if let Some(diagnostic) = implicit_namespace_package(
+            path,
+            &settings.flake8_no_pep420.script_directories,
+        ) {
+            if settings.rules.enabled(diagnostic.kind.rule()) {
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    // pep8-naming
+    if settings.rules.enabled(&Rule::InvalidModuleName) {
+        if let Some(diagnostic) =
+            invalid_module_name(path, &settings.pep8_naming.ignore_names)
+        {
             diagnostics.push(diagnostic);
         }
     }
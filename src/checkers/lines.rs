@@ -4,8 +4,13 @@ use crate::registry::{Diagnostic, Rule};
 use crate::rules::pycodestyle::rules::{
     doc_line_too_long, line_too_long, no_newline_at_end_of_file,
 };
-use crate::rules::pygrep_hooks::rules::{blanket_noqa, blanket_type_ignore};
-use crate::rules::pyupgrade::rules::unnecessary_coding_comment;
+use crate::rules::pygrep_hooks::rules::{
+    blanket_noqa, blanket_type_ignore, type_ignore_missing_code,
+};
+use crate::rules::pyupgrade::rules::{
+    invalid_encoding_declaration, unnecessary_coding_comment, utf8_bom,
+};
+use crate::rules::ruff::rules::missing_copyright_notice;
 use crate::settings::{flags, Settings};
 
 pub fn check_lines(
@@ -19,12 +24,16 @@ pub fn check_lines(
 
     let enforce_blanket_noqa = settings.rules.enabled(&Rule::BlanketNOQA);
     let enforce_blanket_type_ignore = settings.rules.enabled(&Rule::BlanketTypeIgnore);
+    let enforce_type_ignore_missing_code = settings.rules.enabled(&Rule::TypeIgnoreMissingCode);
     let enforce_doc_line_too_long = settings.rules.enabled(&Rule::DocLineTooLong);
     let enforce_line_too_long = settings.rules.enabled(&Rule::LineTooLong);
     let enforce_no_newline_at_end_of_file = settings.rules.enabled(&Rule::NoNewLineAtEndOfFile);
     let enforce_unnecessary_coding_comment = settings
         .rules
         .enabled(&Rule::PEP3120UnnecessaryCodingComment);
+    let enforce_invalid_encoding_declaration =
+        settings.rules.enabled(&Rule::InvalidEncodingDeclaration);
+    let enforce_missing_copyright_notice = settings.rules.enabled(&Rule::MissingCopyrightNotice);
 
     let mut commented_lines_iter = commented_lines.iter().peekable();
     let mut doc_lines_iter = doc_lines.iter().peekable();
@@ -48,6 +57,19 @@ pub fn check_lines(
                 }
             }
 
+            if enforce_invalid_encoding_declaration {
+                if index < 2 {
+                    if let Some(diagnostic) = invalid_encoding_declaration(
+                        index,
+                        line,
+                        matches!(autofix, flags::Autofix::Enabled)
+                            && settings.rules.should_fix(&Rule::InvalidEncodingDeclaration),
+                    ) {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+
             if enforce_blanket_type_ignore {
                 if let Some(diagnostic) = blanket_type_ignore(index, line) {
                     diagnostics.push(diagnostic);
@@ -59,6 +81,18 @@ pub fn check_lines(
                     diagnostics.push(diagnostic);
                 }
             }
+
+            if enforce_type_ignore_missing_code {
+                if let Some(diagnostic) = type_ignore_missing_code(
+                    index,
+                    line,
+                    settings.pygrep_hooks.default_type_ignore_code.as_deref(),
+                    matches!(autofix, flags::Autofix::Enabled)
+                        && settings.rules.should_fix(&Rule::TypeIgnoreMissingCode),
+                ) {
+                    diagnostics.push(diagnostic);
+                }
+            }
         }
 
         while doc_lines_iter
@@ -66,7 +100,13 @@ pub fn check_lines(
             .is_some()
         {
             if enforce_doc_line_too_long {
-                if let Some(diagnostic) = doc_line_too_long(index, line, settings) {
+                if let Some(diagnostic) = doc_line_too_long(
+                    index,
+                    line,
+                    settings,
+                    matches!(autofix, flags::Autofix::Enabled)
+                        && settings.rules.should_fix(&Rule::DocLineTooLong),
+                ) {
                     diagnostics.push(diagnostic);
                 }
             }
@@ -89,6 +129,27 @@ pub fn check_lines(
         }
     }
 
+    if enforce_invalid_encoding_declaration {
+        if let Some(diagnostic) = utf8_bom(
+            contents,
+            matches!(autofix, flags::Autofix::Enabled)
+                && settings.rules.should_fix(&Rule::InvalidEncodingDeclaration),
+        ) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    if enforce_missing_copyright_notice {
+        if let Some(diagnostic) = missing_copyright_notice(
+            contents,
+            settings,
+            matches!(autofix, flags::Autofix::Enabled)
+                && settings.rules.should_fix(&Rule::MissingCopyrightNotice),
+        ) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
     diagnostics
 }
 
@@ -6,7 +6,7 @@ use rustpython_parser::ast::Location;
 use crate::ast::types::Range;
 use crate::fix::Fix;
 use crate::noqa::{is_file_exempt, Directive};
-use crate::registry::{Diagnostic, DiagnosticKind, Rule, CODE_REDIRECTS};
+use crate::registry::{Diagnostic, DiagnosticKind, Rule, RuleOrigin, CODE_REDIRECTS};
 use crate::settings::{flags, Settings};
 use crate::violations::UnusedCodes;
 use crate::{noqa, violations};
@@ -75,6 +75,7 @@ pub fn check_noqa(
         let noqa_lineno = noqa_line_for
             .get(&diagnostic_lineno)
             .unwrap_or(&diagnostic_lineno);
+        let mut is_ignored = false;
         if commented_lines.contains(noqa_lineno) {
             let noqa = noqa_directives
                 .entry(noqa_lineno - 1)
@@ -83,16 +84,39 @@ pub fn check_noqa(
                 (Directive::All(..), matches) => {
                     matches.push(diagnostic.kind.rule().code());
                     ignored.push(index);
+                    is_ignored = true;
                 }
                 (Directive::Codes(.., codes), matches) => {
                     if noqa::includes(diagnostic.kind.rule(), codes) {
                         matches.push(diagnostic.kind.rule().code());
                         ignored.push(index);
+                        is_ignored = true;
                     }
                 }
                 (Directive::None, ..) => {}
             }
         }
+
+        // For `S`-prefixed rules, optionally honor `bandit`'s `# nosec` directive on
+        // the same line, to ease migration from `bandit` without requiring users to
+        // rewrite existing suppression comments.
+        if !is_ignored
+            && settings.flake8_bandit.check_nosec
+            && diagnostic.kind.rule().origin() == RuleOrigin::Flake8Bandit
+            && commented_lines.contains(noqa_lineno)
+        {
+            match noqa::extract_nosec_directive(lines[noqa_lineno - 1]) {
+                Directive::All(..) => {
+                    ignored.push(index);
+                }
+                Directive::Codes(.., codes) => {
+                    if noqa::includes(diagnostic.kind.rule(), &codes) {
+                        ignored.push(index);
+                    }
+                }
+                Directive::None => {}
+            }
+        }
     }
 
     // Enforce that the noqa directive was actually used (RUF100).
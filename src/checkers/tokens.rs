@@ -1,12 +1,15 @@
 //! Lint rules based on token traversal.
 
+use rustpython_ast::Location;
 use rustpython_parser::lexer::{LexResult, Tok};
 
+use crate::ast::types::Range;
 use crate::lex::docstring_detection::StateMachine;
 use crate::registry::{Diagnostic, Rule};
 use crate::rules::ruff::rules::Context;
 use crate::rules::{
-    eradicate, flake8_commas, flake8_implicit_str_concat, flake8_quotes, pycodestyle, ruff,
+    eradicate, flake8_commas, flake8_fixme, flake8_implicit_str_concat, flake8_quotes,
+    flake8_todos, pycodestyle, ruff,
 };
 use crate::settings::{flags, Settings};
 use crate::source_code::Locator;
@@ -33,7 +36,22 @@ pub fn check_tokens(
         || settings.rules.enabled(&Rule::BadQuotesDocstring)
         || settings.rules.enabled(&Rule::AvoidQuoteEscape);
     let enforce_commented_out_code = settings.rules.enabled(&Rule::CommentedOutCode);
+    let enforce_todos = settings.rules.enabled(&Rule::InvalidTodoTag)
+        || settings.rules.enabled(&Rule::MissingTodoAuthor);
+    let enforce_fixmes = settings.rules.enabled(&Rule::LineContainsFixme)
+        || settings.rules.enabled(&Rule::LineContainsTodo)
+        || settings.rules.enabled(&Rule::LineContainsXxx)
+        || settings.rules.enabled(&Rule::LineContainsHack);
     let enforce_invalid_escape_sequence = settings.rules.enabled(&Rule::InvalidEscapeSequence);
+    let enforce_redundant_backslash = settings.rules.enabled(&Rule::RedundantBackslash);
+    let enforce_compound_statements = settings
+        .rules
+        .enabled(&Rule::MultipleStatementsOnOneLineColon)
+        || settings
+            .rules
+            .enabled(&Rule::MultipleStatementsOnOneLineSemicolon)
+        || settings.rules.enabled(&Rule::UselessSemicolon)
+        || settings.rules.enabled(&Rule::StatementOnOneLineDef);
     let enforce_implicit_string_concatenation = settings
         .rules
         .enabled(&Rule::SingleLineImplicitStringConcatenation)
@@ -45,6 +63,10 @@ pub fn check_tokens(
             .rules
             .enabled(&Rule::TrailingCommaOnBareTupleProhibited)
         || settings.rules.enabled(&Rule::TrailingCommaProhibited);
+    let enforce_line_break_around_binary_operator = settings
+        .rules
+        .enabled(&Rule::LineBreakBeforeBinaryOperator)
+        || settings.rules.enabled(&Rule::LineBreakAfterBinaryOperator);
 
     let mut state_machine = StateMachine::default();
     for &(start, ref tok, end) in tokens.iter().flatten() {
@@ -94,13 +116,32 @@ pub fn check_tokens(
             }
         }
 
-        // eradicate
-        if enforce_commented_out_code {
+        // eradicate, flake8-todos, flake8-fixme
+        //
+        // These rules all reason about the full line containing a given comment token, so the
+        // line is sliced out of the source once and shared across all of them, rather than each
+        // rule re-slicing the same range independently.
+        if enforce_commented_out_code || enforce_todos || enforce_fixmes {
             if matches!(tok, Tok::Comment(_)) {
-                if let Some(diagnostic) =
-                    eradicate::rules::commented_out_code(locator, start, end, settings, autofix)
-                {
-                    diagnostics.push(diagnostic);
+                let line = locator.slice_source_code_range(&Range::new(
+                    Location::new(start.row(), 0),
+                    Location::new(end.row() + 1, 0),
+                ));
+
+                if enforce_commented_out_code {
+                    if let Some(diagnostic) =
+                        eradicate::rules::commented_out_code(&line, start, end, settings, autofix)
+                    {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+
+                if enforce_todos {
+                    diagnostics.extend(flake8_todos::rules::todos(&line, start, end, settings));
+                }
+
+                if enforce_fixmes {
+                    diagnostics.extend(flake8_fixme::rules::fixmes(&line, start, end, settings));
                 }
             }
         }
@@ -119,6 +160,31 @@ pub fn check_tokens(
         }
     }
 
+    // E502
+    if enforce_redundant_backslash {
+        diagnostics.extend(pycodestyle::rules::redundant_backslash(
+            locator,
+            tokens,
+            matches!(autofix, flags::Autofix::Enabled)
+                && settings.rules.should_fix(&Rule::RedundantBackslash),
+        ));
+    }
+
+    // E701, E702, E703, E704
+    if enforce_compound_statements {
+        diagnostics.extend(
+            pycodestyle::rules::compound_statements(
+                locator,
+                tokens,
+                matches!(autofix, flags::Autofix::Enabled)
+                    && (settings.rules.should_fix(&Rule::MultipleStatementsOnOneLineSemicolon)
+                        || settings.rules.should_fix(&Rule::UselessSemicolon)),
+            )
+            .into_iter()
+            .filter(|diagnostic| settings.rules.enabled(diagnostic.kind.rule())),
+        );
+    }
+
     // ISC001, ISC002
     if enforce_implicit_string_concatenation {
         diagnostics.extend(
@@ -137,5 +203,17 @@ pub fn check_tokens(
         );
     }
 
+    // W503, W504
+    if enforce_line_break_around_binary_operator {
+        diagnostics.extend(
+            pycodestyle::rules::break_around_binary_operator(
+                tokens,
+                settings.pycodestyle.line_break_style,
+            )
+            .into_iter()
+            .filter(|diagnostic| settings.rules.enabled(diagnostic.kind.rule())),
+        );
+    }
+
     diagnostics
 }
@@ -9,6 +9,12 @@ pub(crate) struct SectionContext<'a> {
     pub(crate) following_lines: &'a [&'a str],
     pub(crate) is_last_section: bool,
     pub(crate) original_index: usize,
+    /// The character offset of `section_name` within `line`. Precomputed here so that
+    /// call sites building fixes around the section name don't each need to re-derive it
+    /// via `line.find(section_name)`, which is redundant (the offset is always just the
+    /// length of `line`'s leading whitespace) and easy to mis-anchor if `section_name`
+    /// happens to recur elsewhere in the line.
+    pub(crate) section_name_start: usize,
 }
 
 fn suspected_as_section(line: &str, style: &SectionStyle) -> bool {
@@ -63,13 +69,15 @@ pub(crate) fn section_contexts<'a>(
 
     let mut contexts = vec![];
     for lineno in suspected_section_indices {
+        let line = lines[lineno];
         let context = SectionContext {
-            section_name: whitespace::leading_words(lines[lineno]),
+            section_name: whitespace::leading_words(line),
             previous_line: lines[lineno - 1],
-            line: lines[lineno],
+            line,
             following_lines: &lines[lineno + 1..],
             original_index: lineno,
             is_last_section: false,
+            section_name_start: whitespace::leading_space(line).chars().count(),
         };
         if is_docstring_section(&context) {
             contexts.push(context);
@@ -91,6 +99,7 @@ pub(crate) fn section_contexts<'a>(
             },
             original_index: context.original_index,
             is_last_section: end.is_none(),
+            section_name_start: context.section_name_start,
         });
         end = Some(next_end);
     }
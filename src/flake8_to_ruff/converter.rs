@@ -407,6 +407,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -424,8 +426,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: None,
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -443,11 +447,15 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: None,
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -474,6 +482,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -491,8 +501,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: Some(100),
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -510,11 +522,15 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: None,
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -541,6 +557,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -558,8 +576,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: Some(100),
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -577,11 +597,15 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: None,
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -608,6 +632,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -625,8 +651,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: None,
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -644,11 +672,15 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: None,
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -675,6 +707,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -692,8 +726,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: None,
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -711,6 +747,7 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: Some(flake8_quotes::settings::Options {
@@ -721,6 +758,9 @@ mod tests {
             }),
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -750,6 +790,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -767,8 +809,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: None,
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -787,11 +831,15 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: None,
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -799,6 +847,9 @@ mod tests {
             pycodestyle: None,
             pydocstyle: Some(pydocstyle::settings::Options {
                 convention: Some(Convention::Numpy),
+                docstring_template: None,
+                ignore_stub_functions: false,
+                class_docstring_init_args: false,
             }),
             pylint: None,
             pyupgrade: None,
@@ -820,6 +871,8 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             allowed_confusables: None,
+            allowed_locales: None,
+            max_confusables_per_token: None,
             builtins: None,
             cache_dir: None,
             dummy_variable_rgx: None,
@@ -837,8 +890,10 @@ mod tests {
             ignore: Some(vec![]),
             ignore_init_module_imports: None,
             line_length: None,
+            max_file_size: None,
             namespace_packages: None,
             per_file_ignores: None,
+            overrides: None,
             required_version: None,
             respect_gitignore: None,
             select: Some(vec![
@@ -857,6 +912,7 @@ mod tests {
             flake8_annotations: None,
             flake8_bandit: None,
             flake8_bugbear: None,
+            flake8_debugger: None,
             flake8_errmsg: None,
             flake8_pytest_style: None,
             flake8_quotes: Some(flake8_quotes::settings::Options {
@@ -867,6 +923,9 @@ mod tests {
             }),
             flake8_tidy_imports: None,
             flake8_import_conventions: None,
+            flake8_no_pep420: None,
+            flake8_print: None,
+            flake8_todos: None,
             flake8_unused_arguments: None,
             isort: None,
             mccabe: None,
@@ -9,6 +9,7 @@ use rustc_hash::FxHashSet;
 
 use crate::registry::Rule;
 use crate::settings::hashable::{HashableGlobMatcher, HashableHashSet};
+use crate::settings::Override;
 
 /// Extract the absolute path and basename (as strings) from a Path.
 pub fn extract_path_names(path: &Path) -> Result<(&str, &str)> {
@@ -42,6 +43,18 @@ pub(crate) fn ignores_from_path<'a>(
         .collect())
 }
 
+/// Return the first `[[tool.ruff.overrides]]` block, if any, whose file
+/// patterns match the given `Path`.
+pub(crate) fn first_matching_override<'a>(
+    path: &Path,
+    overrides: &'a [Override],
+) -> Result<Option<&'a Override>> {
+    let (file_path, file_basename) = extract_path_names(path)?;
+    Ok(overrides
+        .iter()
+        .find(|over| over.include.is_match(file_path) || over.include.is_match(file_basename)))
+}
+
 /// Convert any path to an absolute path (based on the current working
 /// directory).
 pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
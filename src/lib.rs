@@ -19,7 +19,7 @@
 #![forbid(unsafe_code)]
 
 mod ast;
-mod autofix;
+pub mod autofix;
 pub mod cache;
 mod checkers;
 mod cst;
@@ -41,6 +41,7 @@ mod rules;
 mod rustpython_helpers;
 pub mod settings;
 pub mod source_code;
+mod str;
 mod vendor;
 mod violation;
 mod violations;
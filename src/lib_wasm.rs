@@ -10,8 +10,9 @@ use crate::linter::check_path;
 use crate::registry::Rule;
 use crate::rules::{
     flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pylint, pyupgrade,
+    flake8_no_pep420, flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_todos,
+    flake8_unused_arguments, isort, mccabe, pep8_naming, pycodestyle, pydocstyle, pylint,
+    pyupgrade, ruff,
 };
 use crate::rustpython_helpers::tokenize;
 use crate::settings::configuration::Configuration;
@@ -102,6 +103,8 @@ pub fn defaultSettings() -> Result<JsValue, JsValue> {
     Ok(serde_wasm_bindgen::to_value(&Options {
         // Propagate defaults.
         allowed_confusables: Some(Vec::default()),
+        allowed_locales: Some(Vec::default()),
+        max_confusables_per_token: None,
         builtins: Some(Vec::default()),
         dummy_variable_rgx: Some(defaults::DUMMY_VARIABLE_RGX.as_str().to_string()),
         extend_ignore: Some(Vec::default()),
@@ -122,8 +125,10 @@ pub fn defaultSettings() -> Result<JsValue, JsValue> {
         force_exclude: None,
         format: None,
         ignore_init_module_imports: None,
+        max_file_size: None,
         namespace_packages: None,
         per_file_ignores: None,
+        overrides: None,
         required_version: None,
         respect_gitignore: None,
         show_source: None,
@@ -143,6 +148,8 @@ pub fn defaultSettings() -> Result<JsValue, JsValue> {
         flake8_import_conventions: Some(
             flake8_import_conventions::settings::Settings::default().into(),
         ),
+        flake8_no_pep420: Some(flake8_no_pep420::settings::Settings::default().into()),
+        flake8_todos: Some(flake8_todos::settings::Settings::default().into()),
         flake8_unused_arguments: Some(
             flake8_unused_arguments::settings::Settings::default().into(),
         ),
@@ -153,6 +160,7 @@ pub fn defaultSettings() -> Result<JsValue, JsValue> {
         pydocstyle: Some(pydocstyle::settings::Settings::default().into()),
         pylint: Some(pylint::settings::Settings::default().into()),
         pyupgrade: Some(pyupgrade::settings::Settings::default().into()),
+        ruff: Some(ruff::settings::Settings::default().into()),
     })?)
 }
 
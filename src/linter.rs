@@ -2,10 +2,11 @@ use std::path::Path;
 
 use anyhow::Result;
 use colored::Colorize;
+use log::{debug, trace};
 use rustpython_parser::lexer::LexResult;
 
 use crate::ast::types::Range;
-use crate::autofix::fix_file;
+use crate::autofix::{fix_file, SkippedFix};
 use crate::checkers::ast::check_ast;
 use crate::checkers::filesystem::check_file_path;
 use crate::checkers::imports::check_imports;
@@ -43,6 +44,8 @@ pub fn check_path(
     // Validate the `Settings` and return any errors.
     settings.validate()?;
 
+    trace!("Checking: {}", path.display());
+
     // Aggregate all diagnostics.
     let mut diagnostics: Vec<Diagnostic> = vec![];
 
@@ -60,6 +63,7 @@ pub fn check_path(
         .iter_enabled()
         .any(|rule_code| matches!(rule_code.lint_source(), LintSource::Tokens))
     {
+        trace!("Running token-based checks");
         diagnostics.extend(check_tokens(locator, &tokens, settings, autofix));
     }
 
@@ -69,6 +73,7 @@ pub fn check_path(
         .iter_enabled()
         .any(|rule_code| matches!(rule_code.lint_source(), LintSource::Filesystem))
     {
+        trace!("Running filesystem-based checks");
         diagnostics.extend(check_file_path(path, settings));
     }
 
@@ -86,6 +91,7 @@ pub fn check_path(
         match rustpython_helpers::parse_program_tokens(tokens, "<filename>") {
             Ok(python_ast) => {
                 if use_ast {
+                    trace!("Running AST-based checks");
                     diagnostics.extend(check_ast(
                         &python_ast,
                         locator,
@@ -99,6 +105,7 @@ pub fn check_path(
                     ));
                 }
                 if use_imports {
+                    trace!("Running import-based checks");
                     diagnostics.extend(check_imports(
                         &python_ast,
                         locator,
@@ -168,10 +175,14 @@ pub fn check_path(
     if !diagnostics.is_empty() && !settings.per_file_ignores.is_empty() {
         let ignores = fs::ignores_from_path(path, &settings.per_file_ignores)?;
         if !ignores.is_empty() {
-            return Ok(diagnostics
-                .into_iter()
-                .filter(|diagnostic| !ignores.contains(&diagnostic.kind.rule()))
-                .collect());
+            diagnostics.retain(|diagnostic| !ignores.contains(&diagnostic.kind.rule()));
+        }
+    }
+
+    // Apply the first matching `[[tool.ruff.overrides]]` block's rule selection.
+    if !diagnostics.is_empty() && !settings.overrides.is_empty() {
+        if let Some(over) = fs::first_matching_override(path, &settings.overrides)? {
+            diagnostics.retain(|diagnostic| over.rules.enabled(&diagnostic.kind.rule()));
         }
     }
 
@@ -237,6 +248,7 @@ pub fn lint_only(
     package: Option<&Path>,
     settings: &Settings,
     autofix: flags::Autofix,
+    noqa: flags::Noqa,
 ) -> Result<Vec<Message>> {
     // Tokenize once.
     let tokens: Vec<LexResult> = rustpython_helpers::tokenize(contents);
@@ -266,7 +278,7 @@ pub fn lint_only(
         &directives,
         settings,
         autofix,
-        flags::Noqa::Enabled,
+        noqa,
     )?;
 
     // Convert from diagnostics to messages.
@@ -291,12 +303,15 @@ pub fn lint_fix(
     path: &Path,
     package: Option<&Path>,
     settings: &Settings,
-) -> Result<(String, usize, Vec<Message>)> {
+) -> Result<(String, usize, Vec<Message>, Vec<SkippedFix>)> {
     let mut contents = contents.to_string();
 
     // Track the number of fixed errors across iterations.
     let mut fixed = 0;
 
+    // Track fixes skipped due to conflicts with another fix, across iterations.
+    let mut skipped = Vec::new();
+
     // As an escape hatch, bail after 100 iterations.
     let mut iterations = 0;
 
@@ -334,11 +349,20 @@ pub fn lint_fix(
         )?;
 
         // Apply autofix.
-        if let Some((fixed_contents, applied)) = fix_file(&diagnostics, &locator) {
+        if let Some((fixed_contents, applied, skipped_this_iteration)) =
+            fix_file(&diagnostics, &locator)
+        {
+            debug!(
+                "Fixed {applied} error(s) in {} on iteration {iterations}",
+                path.display()
+            );
             if iterations < MAX_ITERATIONS {
                 // Count the number of fixed errors.
                 fixed += applied;
 
+                // Track any fixes that were dropped due to conflicts.
+                skipped.extend(skipped_this_iteration);
+
                 // Store the fixed contents.
                 contents = fixed_contents.to_string();
 
@@ -380,7 +404,7 @@ quoting the contents of `{}`, along with the `pyproject.toml` settings and execu
                 Message::from_diagnostic(diagnostic, path_lossy.to_string(), source)
             })
             .collect();
-        return Ok((contents, fixed, messages));
+        return Ok((contents, fixed, messages, skipped));
     }
 }
 
@@ -437,7 +461,7 @@ pub fn test_path(path: &Path, settings: &Settings) -> Result<Vec<Diagnostic>> {
                 flags::Autofix::Enabled,
                 flags::Noqa::Enabled,
             )?;
-            if let Some((fixed_contents, _)) = fix_file(&diagnostics, &locator) {
+            if let Some((fixed_contents, ..)) = fix_file(&diagnostics, &locator) {
                 if iterations < max_iterations {
                     iterations += 1;
                     contents = fixed_contents.to_string();
@@ -456,3 +480,21 @@ pub fn test_path(path: &Path, settings: &Settings) -> Result<Vec<Diagnostic>> {
     diagnostics.sort_by_key(|diagnostic| diagnostic.location);
     Ok(diagnostics)
 }
+
+/// Assert that every diagnostic in `diagnostics` spans a non-empty range.
+///
+/// A diagnostic whose `location` equals its `end_location` renders as a zero-width squiggle in
+/// editor integrations, which is invisible to the user. Call this from rule tests that are
+/// specifically expected to always point at real source text (as opposed to, say, a
+/// whole-file diagnostic with no single offending construct to underline).
+#[cfg(test)]
+pub fn assert_ranges_non_degenerate(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        assert_ne!(
+            diagnostic.location, diagnostic.end_location,
+            "{:?} produced a zero-width range at {:?}",
+            diagnostic.kind.rule(),
+            diagnostic.location,
+        );
+    }
+}
@@ -52,8 +52,12 @@ pub enum LogLevel {
     Quiet,
     // All user-facing output (+ `log::LevelFilter::Info`).
     Default,
-    // All user-facing output (+ `log::LevelFilter::Debug`).
+    // All user-facing output, plus debug-level diagnostics from the resolver, cache, and
+    // checker dispatch (+ `log::LevelFilter::Debug`). Enabled by a single `-v`.
     Verbose,
+    // As `Verbose`, but also includes trace-level diagnostics (+ `log::LevelFilter::Trace`).
+    // Enabled by `-vv`.
+    Trace,
 }
 
 impl LogLevel {
@@ -61,10 +65,20 @@ impl LogLevel {
         match self {
             LogLevel::Default => log::LevelFilter::Info,
             LogLevel::Verbose => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
             LogLevel::Quiet => log::LevelFilter::Off,
             LogLevel::Silent => log::LevelFilter::Off,
         }
     }
+
+    /// Derive a [`LogLevel`] from a `-v`/`-vv` occurrence count, as collected by the CLI.
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        match verbosity {
+            0 => LogLevel::Default,
+            1 => LogLevel::Verbose,
+            _ => LogLevel::Trace,
+        }
+    }
 }
 
 impl Default for LogLevel {
@@ -73,16 +87,55 @@ impl Default for LogLevel {
     }
 }
 
-pub fn set_up_logging(level: &LogLevel) -> Result<()> {
+/// The output format for log records emitted via [`set_up_logging`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable text, e.g. `[2023-01-01][12:00:00][ruff::resolver][DEBUG] ...`.
+    Text,
+    /// One JSON object per line, for consumption by log aggregators.
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        LogFormat::Text
+    }
+}
+
+/// Escape a log message for embedding in a JSON string.
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn set_up_logging(level: &LogLevel, format: LogFormat) -> Result<()> {
     fern::Dispatch::new()
-        .format(|out, message, record| {
-            out.finish(format_args!(
+        .format(move |out, message, record| match format {
+            LogFormat::Text => out.finish(format_args!(
                 "{}[{}][{}] {}",
                 chrono::Local::now().format("[%Y-%m-%d][%H:%M:%S]"),
                 record.target(),
                 record.level(),
                 message
-            ));
+            )),
+            LogFormat::Json => out.finish(format_args!(
+                r#"{{"timestamp":"{}","level":"{}","target":"{}","message":"{}"}}"#,
+                chrono::Local::now().to_rfc3339(),
+                record.level(),
+                escape_json(record.target()),
+                escape_json(&message.to_string()),
+            )),
         })
         .level(level.level_filter())
         .chain(std::io::stderr())
@@ -101,5 +154,14 @@ mod tests {
         assert!(LogLevel::Quiet > LogLevel::Silent);
         assert!(LogLevel::Verbose > LogLevel::Default);
         assert!(LogLevel::Verbose > LogLevel::Silent);
+        assert!(LogLevel::Trace > LogLevel::Verbose);
+    }
+
+    #[test]
+    fn from_verbosity() {
+        assert_eq!(LogLevel::from_verbosity(0), LogLevel::Default);
+        assert_eq!(LogLevel::from_verbosity(1), LogLevel::Verbose);
+        assert_eq!(LogLevel::from_verbosity(2), LogLevel::Trace);
+        assert_eq!(LogLevel::from_verbosity(9), LogLevel::Trace);
     }
 }
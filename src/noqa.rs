@@ -18,6 +18,13 @@ static NOQA_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
     )
     .unwrap()
 });
+// `bandit`'s own suppression comment, honored by `S`-prefixed rules when
+// `flake8-bandit.check-nosec` is enabled. Unlike `# noqa`, `bandit` doesn't
+// require a colon before the list of codes.
+static NOSEC_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?P<spaces>\s*)(?P<nosec>(?i:# nosec)(?::?\s?(?P<codes>([A-Z]+[0-9]+(?:[,\s]+)?)+))?)")
+        .unwrap()
+});
 static SPLIT_COMMA_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[,\s]").unwrap());
 
 /// Return `true` if a file is exempt from checking based on the contents of the
@@ -67,6 +74,35 @@ pub fn extract_noqa_directive(line: &str) -> Directive {
     }
 }
 
+/// Extract the `bandit`-style `# nosec` `Directive` from a line of Python
+/// source code.
+pub fn extract_nosec_directive(line: &str) -> Directive {
+    match NOSEC_LINE_REGEX.captures(line) {
+        Some(caps) => match caps.name("spaces") {
+            Some(spaces) => match caps.name("nosec") {
+                Some(nosec) => match caps.name("codes") {
+                    Some(codes) => Directive::Codes(
+                        spaces.as_str().chars().count(),
+                        nosec.start(),
+                        nosec.end(),
+                        SPLIT_COMMA_REGEX
+                            .split(codes.as_str())
+                            .map(str::trim)
+                            .filter(|code| !code.is_empty())
+                            .collect(),
+                    ),
+                    None => {
+                        Directive::All(spaces.as_str().chars().count(), nosec.start(), nosec.end())
+                    }
+                },
+                None => Directive::None,
+            },
+            None => Directive::None,
+        },
+        None => Directive::None,
+    }
+}
+
 /// Returns `true` if the string list of `codes` includes `code` (or an alias
 /// thereof).
 pub fn includes(needle: &Rule, haystack: &[&str]) -> bool {
@@ -2,6 +2,7 @@ pub mod builtins;
 pub mod future;
 pub mod identifiers;
 pub mod keyword;
+pub mod site_packages;
 pub mod string;
 pub mod sys;
 pub mod typing;
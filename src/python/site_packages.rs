@@ -0,0 +1,92 @@
+//! Discovery of installed third-party packages via `site-packages` metadata.
+//!
+//! This allows import categorization to distinguish "third-party" from
+//! "first-party" with more confidence than a bare lack-of-evidence fallback,
+//! by consulting the distributions actually installed in the running (or
+//! configured) Python environment.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// A cache of previously-scanned `site-packages` directories, to avoid
+/// re-reading the filesystem for every import statement in a project.
+static CACHE: Lazy<Mutex<FxHashMap<PathBuf, FxHashSet<String>>>> =
+    Lazy::new(|| Mutex::new(FxHashMap::default()));
+
+/// Return the top-level import names of every distribution installed in
+/// `site_packages`, memoizing the result for subsequent lookups.
+pub fn installed_packages(site_packages: &Path) -> FxHashSet<String> {
+    if let Some(packages) = CACHE.lock().unwrap().get(site_packages) {
+        return packages.clone();
+    }
+
+    let packages = read_site_packages(site_packages);
+    CACHE
+        .lock()
+        .unwrap()
+        .insert(site_packages.to_path_buf(), packages.clone());
+    packages
+}
+
+/// Scan `site_packages` for installed distributions, deriving the top-level
+/// import name of each from its directory or file name.
+fn read_site_packages(site_packages: &Path) -> FxHashSet<String> {
+    let mut packages = FxHashSet::default();
+    let Ok(entries) = std::fs::read_dir(site_packages) else {
+        return packages;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        if file_name.starts_with('_') || file_name == "__pycache__" {
+            continue;
+        }
+        // Metadata directories (e.g., `requests-2.28.1.dist-info`) and `.egg-info`
+        // directories are named after the distribution: the name is everything
+        // before the first hyphen (`requests`), used as-is as the import name, so a
+        // distribution whose import name differs from it is not mapped.
+        let name = file_name
+            .strip_suffix(".dist-info")
+            .or_else(|| file_name.strip_suffix(".egg-info"))
+            .or_else(|| file_name.strip_suffix(".egg-link"))
+            .or_else(|| file_name.strip_suffix(".py"))
+            .or_else(|| file_name.strip_suffix(".pyi"))
+            .unwrap_or(file_name);
+        let name = name.split('-').next().unwrap_or(name);
+        if !name.is_empty() {
+            packages.insert(name.to_string());
+        }
+    }
+    packages
+}
+
+/// Resolve the `site-packages` directory for the active virtual environment,
+/// as indicated by the `VIRTUAL_ENV` environment variable, if any.
+pub fn detect_virtual_env() -> Option<PathBuf> {
+    let virtual_env = env::var_os("VIRTUAL_ENV")?;
+    site_packages_dir(Path::new(&virtual_env))
+}
+
+/// Given the root of a virtual environment, locate its `site-packages`
+/// directory, accounting for the platform-specific layout.
+fn site_packages_dir(venv: &Path) -> Option<PathBuf> {
+    if cfg!(windows) {
+        let candidate = venv.join("Lib").join("site-packages");
+        return candidate.is_dir().then_some(candidate);
+    }
+    let lib = venv.join("lib");
+    let entries = std::fs::read_dir(lib).ok()?;
+    for entry in entries.flatten() {
+        let candidate = entry.path().join("site-packages");
+        if candidate.is_dir() {
+            return Some(candidate);
+        }
+    }
+    None
+}
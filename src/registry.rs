@@ -17,6 +17,11 @@ ruff_macros::define_rule_mapping!(
     E401 => violations::MultipleImportsOnOneLine,
     E402 => violations::ModuleImportNotAtTopOfFile,
     E501 => violations::LineTooLong,
+    E502 => violations::RedundantBackslash,
+    E701 => violations::MultipleStatementsOnOneLineColon,
+    E702 => violations::MultipleStatementsOnOneLineSemicolon,
+    E703 => violations::UselessSemicolon,
+    E704 => violations::StatementOnOneLineDef,
     E711 => violations::NoneComparison,
     E712 => violations::TrueFalseComparison,
     E713 => violations::NotInTest,
@@ -31,6 +36,8 @@ ruff_macros::define_rule_mapping!(
     E999 => violations::SyntaxError,
     // pycodestyle warnings
     W292 => violations::NoNewLineAtEndOfFile,
+    W503 => violations::LineBreakBeforeBinaryOperator,
+    W504 => violations::LineBreakAfterBinaryOperator,
     W505 => violations::DocLineTooLong,
     W605 => violations::InvalidEscapeSequence,
     // pyflakes
@@ -78,18 +85,23 @@ ruff_macros::define_rule_mapping!(
     F842 => violations::UnusedAnnotation,
     F901 => violations::RaiseNotImplemented,
     // pylint
+    PLC0205 => violations::SingleStringSlots,
     PLC0414 => violations::UselessImportAlias,
     PLC3002 => violations::UnnecessaryDirectLambdaCall,
     PLE0117 => violations::NonlocalWithoutBinding,
     PLE0118 => violations::UsedPriorGlobalDeclaration,
+    PLE0302 => violations::UnexpectedSpecialMethodSignature,
     PLE1142 => violations::AwaitOutsideAsync,
     PLR0206 => violations::PropertyWithParameters,
     PLR0402 => violations::ConsiderUsingFromImport,
     PLR0133 => violations::ConstantComparison,
+    PLR0904 => violations::TooManyPublicMethods,
     PLR1701 => violations::ConsiderMergingIsinstance,
     PLR1722 => violations::UseSysExit,
     PLR2004 => violations::MagicValueComparison,
     PLW0120 => violations::UselessElseOnLoop,
+    PLW0127 => violations::SelfAssigningVariable,
+    PLW0129 => violations::AssertOnStringLiteral,
     PLW0602 => violations::GlobalVariableNotAssigned,
     // flake8-builtins
     A001 => violations::BuiltinVariableShadowing,
@@ -126,6 +138,8 @@ ruff_macros::define_rule_mapping!(
     B905 => violations::ZipWithoutExplicitStrict,
     // flake8-blind-except
     BLE001 => violations::BlindExcept,
+    BLE002 => violations::BlindExceptSwallow,
+    BLE003 => violations::BlindExceptWithoutLogging,
     // flake8-comprehensions
     C400 => violations::UnnecessaryGeneratorList,
     C401 => violations::UnnecessaryGeneratorSet,
@@ -143,6 +157,8 @@ ruff_macros::define_rule_mapping!(
     C415 => violations::UnnecessarySubscriptReversal,
     C416 => violations::UnnecessaryComprehension,
     C417 => violations::UnnecessaryMap,
+    C418 => violations::UnnecessaryDictCall,
+    C420 => violations::UnnecessaryDictComprehensionForIterable,
     // flake8-debugger
     T100 => violations::Debugger,
     // mccabe
@@ -252,6 +268,8 @@ ruff_macros::define_rule_mapping!(
     UP030 => violations::FormatLiterals,
     UP032 => violations::FString,
         UP033 => violations::FunctoolsCache,
+    UP034 => violations::InvalidEncodingDeclaration,
+    UP036 => violations::OutdatedVersionBlock,
     // pydocstyle
     D100 => violations::PublicModule,
     D101 => violations::PublicClass,
@@ -314,6 +332,7 @@ ruff_macros::define_rule_mapping!(
     N816 => violations::MixedCaseVariableInGlobalScope,
     N817 => violations::CamelcaseImportedAsAcronym,
     N818 => violations::ErrorSuffixOnExceptionName,
+    N999 => violations::InvalidModuleName,
     // isort
     I001 => violations::UnsortedImports,
     I002 => violations::MissingRequiredImport,
@@ -328,12 +347,20 @@ ruff_macros::define_rule_mapping!(
     S106 => violations::HardcodedPasswordFuncArg,
     S107 => violations::HardcodedPasswordDefault,
     S108 => violations::HardcodedTempFile,
+    S110 => violations::TryExceptPass,
+    S112 => violations::TryExceptContinue,
     S113 => violations::RequestWithoutTimeout,
     S324 => violations::HashlibInsecureHashFunction,
     S501 => violations::RequestWithNoCertValidation,
     S506 => violations::UnsafeYAMLLoad,
     S508 => violations::SnmpInsecureVersion,
     S509 => violations::SnmpWeakCryptography,
+    S602 => violations::SubprocessPopenWithShellEqualsTrue,
+    S603 => violations::SubprocessWithoutShellEqualsTrue,
+    S604 => violations::CallWithShellEqualsTrue,
+    S605 => violations::StartProcessWithAShell,
+    S606 => violations::StartProcessWithNoShell,
+    S607 => violations::StartProcessWithPartialPath,
     S701 => violations::Jinja2AutoescapeFalse,
     // flake8-boolean-trap
     FBT001 => violations::BooleanPositionalArgInFunctionDefinition,
@@ -347,6 +374,8 @@ ruff_macros::define_rule_mapping!(
     ARG005 => violations::UnusedLambdaArgument,
     // flake8-import-conventions
     ICN001 => violations::ImportAliasIsNotConventional,
+    ICN002 => violations::BannedImportAlias,
+    ICN003 => violations::BannedImportFrom,
     // flake8-datetimez
     DTZ001 => violations::CallDatetimeWithoutTzinfo,
     DTZ002 => violations::CallDatetimeToday,
@@ -362,6 +391,7 @@ ruff_macros::define_rule_mapping!(
     PGH002 => violations::DeprecatedLogWarn,
     PGH003 => violations::BlanketTypeIgnore,
     PGH004 => violations::BlanketNOQA,
+    PGH005 => violations::TypeIgnoreMissingCode,
     // pandas-vet
     PD002 => violations::UseOfInplaceArgument,
     PD003 => violations::UseOfDotIsNull,
@@ -374,6 +404,7 @@ ruff_macros::define_rule_mapping!(
     PD012 => violations::UseOfDotReadTable,
     PD013 => violations::UseOfDotStack,
     PD015 => violations::UseOfPdMerge,
+    PD101 => violations::UseOfDotNunique,
     PD901 => violations::DfIsABadVariableName,
     // flake8-errmsg
     EM101 => violations::RawStringInException,
@@ -405,22 +436,48 @@ ruff_macros::define_rule_mapping!(
     PT024 => violations::UnnecessaryAsyncioMarkOnFixture,
     PT025 => violations::ErroneousUseFixturesOnFixture,
     PT026 => violations::UseFixturesWithoutParameters,
+    PT027 => violations::UnittestRaisesAssertion,
     // flake8-pie
     PIE790 => violations::NoUnnecessaryPass,
     PIE794 => violations::DupeClassFieldDefinitions,
     PIE796 => violations::PreferUniqueEnums,
+    PIE800 => violations::UnnecessarySpread,
+    PIE804 => violations::UnnecessaryDictKwargs,
     PIE807 => violations::PreferListBuiltin,
+    PIE808 => violations::UnnecessaryRangeStart,
+    PIE810 => violations::MultipleStartsEndsWith,
     // flake8-commas
     COM812 => violations::TrailingCommaMissing,
     COM818 => violations::TrailingCommaOnBareTupleProhibited,
     COM819 => violations::TrailingCommaProhibited,
     // flake8-no-pep420
     INP001 => violations::ImplicitNamespacePackage,
+    INP002 => violations::ImplicitNamespacePackageInScriptDirectory,
+    // flake8-pyi
+    PYI021 => violations::DocstringInStub,
+    // flake8-todos
+    TD001 => violations::InvalidTodoTag,
+    TD002 => violations::MissingTodoAuthor,
+    // flake8-fixme
+    FIX001 => violations::LineContainsFixme,
+    FIX002 => violations::LineContainsTodo,
+    FIX003 => violations::LineContainsXxx,
+    FIX004 => violations::LineContainsHack,
     // Ruff
     RUF001 => violations::AmbiguousUnicodeCharacterString,
     RUF002 => violations::AmbiguousUnicodeCharacterDocstring,
     RUF003 => violations::AmbiguousUnicodeCharacterComment,
     RUF004 => violations::KeywordArgumentBeforeStarArgument,
+    RUF005 => violations::MissingCopyrightNotice,
+    RUF006 => violations::CollectionLiteralConcatenation,
+    RUF007 => violations::AsyncioDanglingTask,
+    RUF008 => violations::QuotedAnnotation,
+    RUF009 => violations::SyntaxErrorInDoctest,
+    RUF010 => violations::MutableClassDefault,
+    RUF011 => violations::FStringStrCall,
+    RUF013 => violations::ImplicitOptional,
+    RUF014 => violations::UndocumentedException,
+    RUF015 => violations::UnusedPrivateModuleFunction,
     RUF100 => violations::UnusedNOQA,
 );
 
@@ -460,6 +517,9 @@ pub enum RuleOrigin {
     Flake8Pie,
     Flake8Commas,
     Flake8NoPep420,
+    Flake8Pyi,
+    Flake8Todos,
+    Flake8Fixme,
     Ruff,
 }
 
@@ -527,6 +587,9 @@ impl RuleOrigin {
             RuleOrigin::Flake8Pie => Prefixes::Single(RuleCodePrefix::PIE),
             RuleOrigin::Flake8Commas => Prefixes::Single(RuleCodePrefix::COM),
             RuleOrigin::Flake8NoPep420 => Prefixes::Single(RuleCodePrefix::INP),
+            RuleOrigin::Flake8Pyi => Prefixes::Single(RuleCodePrefix::PYI),
+            RuleOrigin::Flake8Todos => Prefixes::Single(RuleCodePrefix::TD),
+            RuleOrigin::Flake8Fixme => Prefixes::Single(RuleCodePrefix::FIX),
             RuleOrigin::Ruff => Prefixes::Single(RuleCodePrefix::RUF),
         }
     }
@@ -552,8 +615,11 @@ impl Rule {
             | Rule::NoNewLineAtEndOfFile
             | Rule::DocLineTooLong
             | Rule::PEP3120UnnecessaryCodingComment
+            | Rule::InvalidEncodingDeclaration
             | Rule::BlanketTypeIgnore
-            | Rule::BlanketNOQA => &LintSource::Lines,
+            | Rule::BlanketNOQA
+            | Rule::TypeIgnoreMissingCode
+            | Rule::MissingCopyrightNotice => &LintSource::Lines,
             Rule::CommentedOutCode
             | Rule::SingleLineImplicitStringConcatenation
             | Rule::MultiLineImplicitStringConcatenation
@@ -567,10 +633,21 @@ impl Rule {
             | Rule::TrailingCommaProhibited
             | Rule::AmbiguousUnicodeCharacterString
             | Rule::AmbiguousUnicodeCharacterDocstring
-            | Rule::AmbiguousUnicodeCharacterComment => &LintSource::Tokens,
+            | Rule::AmbiguousUnicodeCharacterComment
+            | Rule::InvalidTodoTag
+            | Rule::MissingTodoAuthor
+            | Rule::LineContainsFixme
+            | Rule::LineContainsTodo
+            | Rule::LineContainsXxx
+            | Rule::LineContainsHack
+            | Rule::RedundantBackslash
+            | Rule::LineBreakBeforeBinaryOperator
+            | Rule::LineBreakAfterBinaryOperator => &LintSource::Tokens,
             Rule::IOError => &LintSource::Io,
             Rule::UnsortedImports | Rule::MissingRequiredImport => &LintSource::Imports,
-            Rule::ImplicitNamespacePackage => &LintSource::Filesystem,
+            Rule::ImplicitNamespacePackage
+            | Rule::ImplicitNamespacePackageInScriptDirectory
+            | Rule::InvalidModuleName => &LintSource::Filesystem,
             _ => &LintSource::Ast,
         }
     }
@@ -648,13 +725,24 @@ impl Diagnostic {
     }
 }
 
-/// Pairs of checks that shouldn't be enabled together.
-pub const INCOMPATIBLE_CODES: &[(Rule, Rule, &str)] = &[(
-    Rule::OneBlankLineBeforeClass,
-    Rule::NoBlankLineBeforeClass,
-    "`D203` (OneBlankLineBeforeClass) and `D211` (NoBlankLinesBeforeClass) are incompatible. \
-     Consider adding `D203` to `ignore`.",
-)];
+/// Pairs of checks that shouldn't be enabled together, in `(preferred, alternative, message)`
+/// order. When both codes in a pair are enabled (e.g., via `--select ALL` or `--select D`,
+/// without a `pydocstyle.convention` to disambiguate), the alternative is dropped in favor of
+/// the preferred, convention-consistent code.
+pub const INCOMPATIBLE_CODES: &[(Rule, Rule, &str)] = &[
+    (
+        Rule::NoBlankLineBeforeClass,
+        Rule::OneBlankLineBeforeClass,
+        "`D211` (NoBlankLineBeforeClass) and `D203` (OneBlankLineBeforeClass) are incompatible. \
+         Ignoring `D203`.",
+    ),
+    (
+        Rule::MultiLineSummaryFirstLine,
+        Rule::MultiLineSummarySecondLine,
+        "`D212` (MultiLineSummaryFirstLine) and `D213` (MultiLineSummarySecondLine) are \
+         incompatible. Ignoring `D213`.",
+    ),
+];
 
 /// A hash map from deprecated to latest `Rule`.
 pub static CODE_REDIRECTS: Lazy<FxHashMap<&'static str, Rule>> = Lazy::new(|| {
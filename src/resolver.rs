@@ -228,6 +228,34 @@ pub fn is_python_entry(entry: &DirEntry) -> bool {
             .map_or(false, |file_type| file_type.is_dir())
 }
 
+/// Return `true` if the `Entry` matches one of the `extend-include` patterns
+/// for its resolved `Settings`.
+fn is_included_entry(
+    entry: &DirEntry,
+    resolver: &Resolver,
+    pyproject_strategy: &PyprojectDiscovery,
+) -> bool {
+    if entry
+        .file_type()
+        .map_or(false, |file_type| file_type.is_dir())
+    {
+        return false;
+    }
+
+    let path = entry.path();
+    let settings = resolver.resolve(path, pyproject_strategy);
+    if settings.extend_include.is_empty() {
+        return false;
+    }
+
+    match fs::extract_path_names(path) {
+        Ok((file_path, file_basename)) => {
+            match_exclusion(file_path, file_basename, &settings.extend_include)
+        }
+        Err(_) => false,
+    }
+}
+
 /// Find all Python (`.py` and `.pyi` files) in a set of paths.
 pub fn python_files_in_path(
     paths: &[PathBuf],
@@ -349,6 +377,8 @@ pub fn python_files_in_path(
                 (entry.depth() == 0 && entry.file_type().map_or(false, |ft| ft.is_file()))
                     // Accept all Python files.
                     || is_python_entry(entry)
+                    // Accept any files matching the `extend-include` patterns.
+                    || is_included_entry(entry, &resolver.read().unwrap(), pyproject_strategy)
             }) {
                 files.lock().unwrap().push(result);
             }
@@ -27,6 +27,21 @@ pub(super) fn match_function_def(
     }
 }
 
+/// Return `true` if the function has no type annotations at all, on any argument or on its
+/// return type. Used to support `ignore-fully-untyped`, which exempts such functions from
+/// `ANN*` rules entirely, rather than flagging every missing annotation individually.
+pub(super) fn is_fully_untyped(args: &Arguments, returns: &Option<Box<Expr>>) -> bool {
+    returns.is_none()
+        && args
+            .args
+            .iter()
+            .chain(args.posonlyargs.iter())
+            .chain(args.kwonlyargs.iter())
+            .all(|arg| arg.node.annotation.is_none())
+        && args.vararg.as_ref().map_or(true, |arg| arg.node.annotation.is_none())
+        && args.kwarg.as_ref().map_or(true, |arg| arg.node.annotation.is_none())
+}
+
 /// Return the name of the function, if it's overloaded.
 pub fn overloaded_name(checker: &Checker, definition: &Definition) -> Option<String> {
     if let DefinitionKind::Function(stmt)
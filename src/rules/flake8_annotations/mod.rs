@@ -38,6 +38,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dynamically_typed_string_annotation() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/dynamically_typed_string.py"),
+            &Settings {
+                ..Settings::for_rules(vec![Rule::DynamicallyTypedExpression])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn suppress_dummy_args() -> Result<()> {
         let diagnostics = test_path(
@@ -48,6 +60,7 @@ mod tests {
                     suppress_dummy_args: true,
                     suppress_none_returning: false,
                     allow_star_arg_any: false,
+                    ignore_fully_untyped: false,
                 },
                 ..Settings::for_rules(vec![
                     Rule::MissingTypeFunctionArgument,
@@ -72,6 +85,7 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: false,
                     allow_star_arg_any: false,
+                    ignore_fully_untyped: false,
                 },
                 ..Settings::for_rules(vec![
                     Rule::MissingReturnTypePublicFunction,
@@ -96,6 +110,7 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: true,
                     allow_star_arg_any: false,
+                    ignore_fully_untyped: false,
                 },
                 ..Settings::for_rules(vec![
                     Rule::MissingReturnTypePublicFunction,
@@ -120,6 +135,7 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: false,
                     allow_star_arg_any: true,
+                    ignore_fully_untyped: false,
                 },
                 ..Settings::for_rules(vec![Rule::DynamicallyTypedExpression])
             },
@@ -128,6 +144,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn ignore_fully_untyped() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/ignore_fully_untyped.py"),
+            &Settings {
+                flake8_annotations: super::settings::Settings {
+                    mypy_init_return: false,
+                    suppress_dummy_args: false,
+                    suppress_none_returning: false,
+                    allow_star_arg_any: false,
+                    ignore_fully_untyped: true,
+                },
+                ..Settings::for_rules(vec![
+                    Rule::MissingTypeFunctionArgument,
+                    Rule::MissingTypeArgs,
+                    Rule::MissingTypeKwargs,
+                    Rule::MissingTypeSelf,
+                    Rule::MissingTypeCls,
+                    Rule::MissingReturnTypePublicFunction,
+                    Rule::MissingReturnTypePrivateFunction,
+                    Rule::MissingReturnTypeSpecialMethod,
+                    Rule::MissingReturnTypeStaticMethod,
+                    Rule::MissingReturnTypeClassMethod,
+                ])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn allow_overload() -> Result<()> {
         let diagnostics = test_path(
@@ -146,6 +192,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn return_none() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/return_none.py"),
+            &Settings {
+                ..Settings::for_rules(vec![
+                    Rule::MissingReturnTypePublicFunction,
+                    Rule::MissingReturnTypePrivateFunction,
+                    Rule::MissingReturnTypeSpecialMethod,
+                ])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn allow_nested_overload() -> Result<()> {
         let diagnostics = test_path(
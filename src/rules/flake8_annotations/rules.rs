@@ -1,8 +1,9 @@
 use log::error;
 use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+use rustpython_parser::parser;
 
 use super::fixes;
-use super::helpers::match_function_def;
+use super::helpers::{is_fully_untyped, match_function_def};
 use crate::ast::types::Range;
 use crate::ast::visitor::Visitor;
 use crate::ast::{cast, helpers, visitor};
@@ -56,7 +57,20 @@ fn check_dynamically_typed<F>(checker: &mut Checker, annotation: &Expr, func: F)
 where
     F: FnOnce() -> String,
 {
-    if checker.match_typing_expr(annotation, "Any") {
+    let is_dynamic = if let ExprKind::Constant {
+        value: Constant::Str(value),
+        ..
+    } = &annotation.node
+    {
+        // The annotation is a manually-quoted forward reference (e.g. `"Any"`); parse
+        // it so that we can still resolve it against `typing.Any`.
+        parser::parse_expression(value, "<filename>")
+            .map_or(false, |parsed| checker.match_typing_expr(&parsed, "Any"))
+    } else {
+        checker.match_typing_expr(annotation, "Any")
+    };
+
+    if is_dynamic {
         checker.diagnostics.push(Diagnostic::new(
             violations::DynamicallyTypedExpression(func()),
             Range::from_located(annotation),
@@ -77,6 +91,14 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
         DefinitionKind::Function(stmt) | DefinitionKind::NestedFunction(stmt) => {
             let (name, args, returns, body) = match_function_def(stmt);
 
+            // If the function is fully untyped, and the user has opted in to
+            // `ignore-fully-untyped`, don't bother emitting any `ANN*` diagnostics for it.
+            if checker.settings.flake8_annotations.ignore_fully_untyped
+                && is_fully_untyped(args, returns)
+            {
+                return;
+            }
+
             // ANN001, ANN401
             for arg in args
                 .args
@@ -176,9 +198,8 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
             } else {
                 // Allow omission of return annotation in `__init__` functions, if the function
                 // only returns `None` (explicitly or implicitly).
-                if checker.settings.flake8_annotations.suppress_none_returning
-                    && is_none_returning(body)
-                {
+                let none_returning = is_none_returning(body);
+                if checker.settings.flake8_annotations.suppress_none_returning && none_returning {
                     return;
                 }
 
@@ -189,10 +210,17 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                             .rules
                             .enabled(&Rule::MissingReturnTypePublicFunction)
                         {
-                            checker.diagnostics.push(Diagnostic::new(
+                            let mut diagnostic = Diagnostic::new(
                                 violations::MissingReturnTypePublicFunction(name.to_string()),
                                 helpers::identifier_range(stmt, checker.locator),
-                            ));
+                            );
+                            if none_returning && checker.patch(diagnostic.kind.rule()) {
+                                match fixes::add_return_none_annotation(checker.locator, stmt) {
+                                    Ok(fix) => diagnostic.amend(fix),
+                                    Err(e) => error!("Failed to generate fix: {e}"),
+                                }
+                            }
+                            checker.diagnostics.push(diagnostic);
                         }
                     }
                     Visibility::Private => {
@@ -201,10 +229,17 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                             .rules
                             .enabled(&Rule::MissingReturnTypePrivateFunction)
                         {
-                            checker.diagnostics.push(Diagnostic::new(
+                            let mut diagnostic = Diagnostic::new(
                                 violations::MissingReturnTypePrivateFunction(name.to_string()),
                                 helpers::identifier_range(stmt, checker.locator),
-                            ));
+                            );
+                            if none_returning && checker.patch(diagnostic.kind.rule()) {
+                                match fixes::add_return_none_annotation(checker.locator, stmt) {
+                                    Ok(fix) => diagnostic.amend(fix),
+                                    Err(e) => error!("Failed to generate fix: {e}"),
+                                }
+                            }
+                            checker.diagnostics.push(diagnostic);
                         }
                     }
                 }
@@ -212,6 +247,13 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
         }
         DefinitionKind::Method(stmt) => {
             let (name, args, returns, body) = match_function_def(stmt);
+
+            if checker.settings.flake8_annotations.ignore_fully_untyped
+                && is_fully_untyped(args, returns)
+            {
+                return;
+            }
+
             let mut has_any_typed_arg = false;
 
             // ANN001
@@ -347,9 +389,8 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
             } else {
                 // Allow omission of return annotation if the function only returns `None`
                 // (explicitly or implicitly).
-                if checker.settings.flake8_annotations.suppress_none_returning
-                    && is_none_returning(body)
-                {
+                let none_returning = is_none_returning(body);
+                if checker.settings.flake8_annotations.suppress_none_returning && none_returning {
                     return;
                 }
 
@@ -407,10 +448,17 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                         .rules
                         .enabled(&Rule::MissingReturnTypeSpecialMethod)
                     {
-                        checker.diagnostics.push(Diagnostic::new(
+                        let mut diagnostic = Diagnostic::new(
                             violations::MissingReturnTypeSpecialMethod(name.to_string()),
                             helpers::identifier_range(stmt, checker.locator),
-                        ));
+                        );
+                        if none_returning && checker.patch(diagnostic.kind.rule()) {
+                            match fixes::add_return_none_annotation(checker.locator, stmt) {
+                                Ok(fix) => diagnostic.amend(fix),
+                                Err(e) => error!("Failed to generate fix: {e}"),
+                            }
+                        }
+                        checker.diagnostics.push(diagnostic);
                     }
                 } else {
                     match visibility {
@@ -420,10 +468,18 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                                 .rules
                                 .enabled(&Rule::MissingReturnTypePublicFunction)
                             {
-                                checker.diagnostics.push(Diagnostic::new(
+                                let mut diagnostic = Diagnostic::new(
                                     violations::MissingReturnTypePublicFunction(name.to_string()),
                                     helpers::identifier_range(stmt, checker.locator),
-                                ));
+                                );
+                                if none_returning && checker.patch(diagnostic.kind.rule()) {
+                                    match fixes::add_return_none_annotation(checker.locator, stmt)
+                                    {
+                                        Ok(fix) => diagnostic.amend(fix),
+                                        Err(e) => error!("Failed to generate fix: {e}"),
+                                    }
+                                }
+                                checker.diagnostics.push(diagnostic);
                             }
                         }
                         Visibility::Private => {
@@ -432,10 +488,18 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                                 .rules
                                 .enabled(&Rule::MissingReturnTypePrivateFunction)
                             {
-                                checker.diagnostics.push(Diagnostic::new(
+                                let mut diagnostic = Diagnostic::new(
                                     violations::MissingReturnTypePrivateFunction(name.to_string()),
                                     helpers::identifier_range(stmt, checker.locator),
-                                ));
+                                );
+                                if none_returning && checker.patch(diagnostic.kind.rule()) {
+                                    match fixes::add_return_none_annotation(checker.locator, stmt)
+                                    {
+                                        Ok(fix) => diagnostic.amend(fix),
+                                        Err(e) => error!("Failed to generate fix: {e}"),
+                                    }
+                                }
+                                checker.diagnostics.push(diagnostic);
                             }
                         }
                     }
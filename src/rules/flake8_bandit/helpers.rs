@@ -1,5 +1,85 @@
 use rustpython_ast::{Constant, Expr, ExprKind};
 
+use crate::ast::helpers::collect_call_path;
+use crate::ast::types::CallPath;
+
+/// The `subprocess` functions that accept a `shell` keyword argument.
+const SUBPROCESS_FUNCTIONS: [&str; 5] = ["Popen", "call", "check_call", "check_output", "run"];
+
+/// Functions that always invoke a shell to execute a command, regardless of
+/// a `shell` keyword argument.
+const SHELL_START_FUNCTIONS: [[&str; 2]; 8] = [
+    ["os", "system"],
+    ["os", "popen"],
+    ["os", "popen2"],
+    ["os", "popen3"],
+    ["os", "popen4"],
+    ["popen2", "popen2"],
+    ["popen2", "popen3"],
+    ["popen2", "popen4"],
+];
+
+/// Functions that start a new process without going through a shell.
+const NO_SHELL_START_FUNCTIONS: [[&str; 2]; 17] = [
+    ["os", "execl"],
+    ["os", "execle"],
+    ["os", "execlp"],
+    ["os", "execlpe"],
+    ["os", "execv"],
+    ["os", "execve"],
+    ["os", "execvp"],
+    ["os", "execvpe"],
+    ["os", "spawnl"],
+    ["os", "spawnle"],
+    ["os", "spawnlp"],
+    ["os", "spawnlpe"],
+    ["os", "spawnv"],
+    ["os", "spawnve"],
+    ["os", "spawnvp"],
+    ["os", "spawnvpe"],
+    ["os", "startfile"],
+];
+
+/// Returns `true` if `call_path` refers to a `subprocess` module function
+/// that accepts a `shell` keyword argument (e.g. `subprocess.run`).
+pub fn is_subprocess_call(call_path: &CallPath) -> bool {
+    call_path.len() == 2
+        && call_path[0] == "subprocess"
+        && SUBPROCESS_FUNCTIONS.contains(&call_path[1])
+}
+
+/// Returns `true` if `call_path` refers to a function (e.g. `os.system`)
+/// that always spawns a shell to execute its command.
+pub fn is_shell_start_function(call_path: &CallPath) -> bool {
+    SHELL_START_FUNCTIONS
+        .iter()
+        .any(|target| call_path.as_slice() == *target)
+}
+
+/// Returns `true` if `call_path` refers to a function (e.g. `os.execv`) that
+/// starts a new process without going through a shell.
+pub fn is_no_shell_start_function(call_path: &CallPath) -> bool {
+    NO_SHELL_START_FUNCTIONS
+        .iter()
+        .any(|target| call_path.as_slice() == *target)
+}
+
+/// Return the executable that a process-spawning call would invoke, if it
+/// can be statically determined from `expr` (a string, or the first element
+/// of a list/tuple of strings).
+pub fn get_executable(expr: &Expr) -> Option<&str> {
+    match &expr.node {
+        ExprKind::Constant {
+            value: Constant::Str(string),
+            ..
+        } => string.split_whitespace().next(),
+        ExprKind::List { elts, .. } | ExprKind::Tuple { elts, .. } => {
+            elts.first().and_then(string_literal)
+        }
+        _ => None,
+    }
+}
+
 const PASSWORD_NAMES: [&str; 7] = [
     "password", "pass", "passwd", "pwd", "secret", "token", "secrete",
 ];
@@ -20,3 +100,23 @@ pub fn matches_password_name(string: &str) -> bool {
         .iter()
         .any(|name| string.to_lowercase().contains(name))
 }
+
+/// Return `true` if a caught exception `type_` (which may be a single name,
+/// a dotted attribute, or a tuple of either) is entirely covered by
+/// `allowed_exceptions` (a list of dotted names, e.g. `KeyError` or
+/// `socket.timeout`).
+pub fn is_allowed_try_except_exception(type_: &Expr, allowed_exceptions: &[String]) -> bool {
+    if allowed_exceptions.is_empty() {
+        return false;
+    }
+    let exceptions: Vec<&Expr> = match &type_.node {
+        ExprKind::Tuple { elts, .. } => elts.iter().collect(),
+        _ => vec![type_],
+    };
+    exceptions.iter().all(|exception| {
+        let call_path = collect_call_path(exception);
+        allowed_exceptions
+            .iter()
+            .any(|allowed| call_path.as_slice() == allowed.split('.').collect::<Vec<_>>()[..])
+    })
+}
@@ -22,12 +22,20 @@ mod tests {
     #[test_case(Rule::HardcodedPasswordFuncArg, Path::new("S106.py"); "S106")]
     #[test_case(Rule::HardcodedPasswordDefault, Path::new("S107.py"); "S107")]
     #[test_case(Rule::HardcodedTempFile, Path::new("S108.py"); "S108")]
+    #[test_case(Rule::TryExceptPass, Path::new("S110.py"); "S110")]
+    #[test_case(Rule::TryExceptContinue, Path::new("S112.py"); "S112")]
     #[test_case(Rule::RequestWithoutTimeout, Path::new("S113.py"); "S113")]
     #[test_case(Rule::HashlibInsecureHashFunction, Path::new("S324.py"); "S324")]
     #[test_case(Rule::RequestWithNoCertValidation, Path::new("S501.py"); "S501")]
     #[test_case(Rule::UnsafeYAMLLoad, Path::new("S506.py"); "S506")]
     #[test_case(Rule::SnmpInsecureVersion, Path::new("S508.py"); "S508")]
     #[test_case(Rule::SnmpWeakCryptography, Path::new("S509.py"); "S509")]
+    #[test_case(Rule::SubprocessPopenWithShellEqualsTrue, Path::new("S602.py"); "S602")]
+    #[test_case(Rule::SubprocessWithoutShellEqualsTrue, Path::new("S603.py"); "S603")]
+    #[test_case(Rule::CallWithShellEqualsTrue, Path::new("S604.py"); "S604")]
+    #[test_case(Rule::StartProcessWithAShell, Path::new("S605.py"); "S605")]
+    #[test_case(Rule::StartProcessWithNoShell, Path::new("S606.py"); "S606")]
+    #[test_case(Rule::StartProcessWithPartialPath, Path::new("S607.py"); "S607")]
     #[test_case(Rule::Jinja2AutoescapeFalse, Path::new("S701.py"); "S701")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
@@ -53,6 +61,7 @@ mod tests {
                         "/dev/shm".to_string(),
                         "/foo".to_string(),
                     ],
+                    ..super::settings::Settings::default()
                 },
                 ..Settings::for_rule(Rule::HardcodedTempFile)
             },
@@ -60,4 +69,36 @@ mod tests {
         insta::assert_yaml_snapshot!("S108_extend", diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn check_allowed_try_except_exceptions() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_bandit/S110.py"),
+            &Settings {
+                flake8_bandit: super::settings::Settings {
+                    allowed_try_except_exceptions: vec!["KeyError".to_string()],
+                    ..super::settings::Settings::default()
+                },
+                ..Settings::for_rule(Rule::TryExceptPass)
+            },
+        )?;
+        insta::assert_yaml_snapshot!("S110_allowed_exceptions", diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn check_nosec_compat() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_bandit/S101_nosec.py"),
+            &Settings {
+                flake8_bandit: super::settings::Settings {
+                    check_nosec: true,
+                    ..super::settings::Settings::default()
+                },
+                ..Settings::for_rule(Rule::AssertUsed)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
@@ -0,0 +1,38 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::SimpleCallArgs;
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::is_subprocess_call;
+use crate::violations;
+
+/// S604
+pub fn call_with_shell_equals_true(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    // Calls to the `subprocess` module are covered by `S602`.
+    if checker
+        .resolve_call_path(func)
+        .map_or(false, |call_path| is_subprocess_call(&call_path))
+    {
+        return;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let Some(shell_arg) = call_args.get_argument("shell", None) else {
+        return;
+    };
+    if let ExprKind::Constant {
+        value: Constant::Bool(true),
+        ..
+    } = &shell_arg.node
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::CallWithShellEqualsTrue,
+            Range::from_located(shell_arg),
+        ));
+    }
+}
@@ -1,5 +1,6 @@
 pub use assert_used::assert_used;
 pub use bad_file_permissions::bad_file_permissions;
+pub use call_with_shell_equals_true::call_with_shell_equals_true;
 pub use exec_used::exec_used;
 pub use hardcoded_bind_all_interfaces::hardcoded_bind_all_interfaces;
 pub use hardcoded_password_default::hardcoded_password_default;
@@ -14,10 +15,18 @@ pub use request_with_no_cert_validation::request_with_no_cert_validation;
 pub use request_without_timeout::request_without_timeout;
 pub use snmp_insecure_version::snmp_insecure_version;
 pub use snmp_weak_cryptography::snmp_weak_cryptography;
+pub use start_process_with_a_shell::start_process_with_a_shell;
+pub use start_process_with_no_shell::start_process_with_no_shell;
+pub use start_process_with_partial_path::start_process_with_partial_path;
+pub use subprocess_popen_with_shell_equals_true::subprocess_popen_with_shell_equals_true;
+pub use subprocess_without_shell_equals_true::subprocess_without_shell_equals_true;
+pub use try_except_continue::try_except_continue;
+pub use try_except_pass::try_except_pass;
 pub use unsafe_yaml_load::unsafe_yaml_load;
 
 mod assert_used;
 mod bad_file_permissions;
+mod call_with_shell_equals_true;
 mod exec_used;
 mod hardcoded_bind_all_interfaces;
 mod hardcoded_password_default;
@@ -30,4 +39,11 @@ mod request_with_no_cert_validation;
 mod request_without_timeout;
 mod snmp_insecure_version;
 mod snmp_weak_cryptography;
+mod start_process_with_a_shell;
+mod start_process_with_no_shell;
+mod start_process_with_partial_path;
+mod subprocess_popen_with_shell_equals_true;
+mod subprocess_without_shell_equals_true;
+mod try_except_continue;
+mod try_except_pass;
 mod unsafe_yaml_load;
@@ -0,0 +1,20 @@
+use rustpython_ast::Expr;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::is_shell_start_function;
+use crate::violations;
+
+/// S605
+pub fn start_process_with_a_shell(checker: &mut Checker, func: &Expr) {
+    if checker
+        .resolve_call_path(func)
+        .map_or(false, |call_path| is_shell_start_function(&call_path))
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::StartProcessWithAShell,
+            Range::from_located(func),
+        ));
+    }
+}
@@ -0,0 +1,20 @@
+use rustpython_ast::Expr;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::is_no_shell_start_function;
+use crate::violations;
+
+/// S606
+pub fn start_process_with_no_shell(checker: &mut Checker, func: &Expr) {
+    if checker
+        .resolve_call_path(func)
+        .map_or(false, |call_path| is_no_shell_start_function(&call_path))
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::StartProcessWithNoShell,
+            Range::from_located(func),
+        ));
+    }
+}
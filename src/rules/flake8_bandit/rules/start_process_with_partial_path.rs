@@ -0,0 +1,41 @@
+use rustpython_ast::{Expr, Keyword};
+
+use crate::ast::helpers::SimpleCallArgs;
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::{
+    get_executable, is_no_shell_start_function, is_shell_start_function, is_subprocess_call,
+};
+use crate::violations;
+
+/// S607
+pub fn start_process_with_partial_path(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let Some(call_path) = checker.resolve_call_path(func) else {
+        return;
+    };
+    if !(is_subprocess_call(&call_path)
+        || is_shell_start_function(&call_path)
+        || is_no_shell_start_function(&call_path))
+    {
+        return;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let Some(executable_arg) = call_args.get_argument("args", Some(0)) else {
+        return;
+    };
+    let Some(executable) = get_executable(executable_arg) else {
+        return;
+    };
+    if !executable.starts_with('/') {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::StartProcessWithPartialPath(executable.to_string()),
+            Range::from_located(executable_arg),
+        ));
+    }
+}
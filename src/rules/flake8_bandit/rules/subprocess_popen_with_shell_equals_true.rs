@@ -0,0 +1,37 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::SimpleCallArgs;
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::is_subprocess_call;
+use crate::violations;
+
+/// S602
+pub fn subprocess_popen_with_shell_equals_true(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let Some(call_path) = checker.resolve_call_path(func) else {
+        return;
+    };
+    if !is_subprocess_call(&call_path) {
+        return;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let Some(shell_arg) = call_args.get_argument("shell", None) else {
+        return;
+    };
+    if let ExprKind::Constant {
+        value: Constant::Bool(true),
+        ..
+    } = &shell_arg.node
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::SubprocessPopenWithShellEqualsTrue,
+            Range::from_located(shell_arg),
+        ));
+    }
+}
@@ -0,0 +1,42 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::SimpleCallArgs;
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::rules::flake8_bandit::helpers::is_subprocess_call;
+use crate::violations;
+
+/// S603
+pub fn subprocess_without_shell_equals_true(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let Some(call_path) = checker.resolve_call_path(func) else {
+        return;
+    };
+    if !is_subprocess_call(&call_path) {
+        return;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let shell_is_true = call_args
+        .get_argument("shell", None)
+        .map_or(false, |shell_arg| {
+            matches!(
+                &shell_arg.node,
+                ExprKind::Constant {
+                    value: Constant::Bool(true),
+                    ..
+                }
+            )
+        });
+    if shell_is_true {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        violations::SubprocessWithoutShellEqualsTrue,
+        Range::from_located(func),
+    ));
+}
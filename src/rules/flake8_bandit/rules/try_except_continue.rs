@@ -0,0 +1,33 @@
+use rustpython_ast::{Excepthandler, ExcepthandlerKind, Stmt, StmtKind};
+
+use super::super::helpers::is_allowed_try_except_exception;
+use crate::ast::helpers::except_range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// S112
+pub fn try_except_continue(checker: &mut Checker, handlers: &[Excepthandler]) {
+    for handler in handlers {
+        let ExcepthandlerKind::ExceptHandler { type_, body, .. } = &handler.node;
+        let [Stmt {
+            node: StmtKind::Continue,
+            ..
+        }] = body.as_slice()
+        else {
+            continue;
+        };
+        if type_.as_ref().map_or(false, |type_| {
+            is_allowed_try_except_exception(
+                type_,
+                &checker.settings.flake8_bandit.allowed_try_except_exceptions,
+            )
+        }) {
+            continue;
+        }
+        checker.diagnostics.push(Diagnostic::new(
+            violations::TryExceptContinue,
+            except_range(handler, checker.locator),
+        ));
+    }
+}
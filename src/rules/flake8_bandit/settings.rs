@@ -34,11 +34,36 @@ pub struct Options {
     /// A list of directories to consider temporary, in addition to those
     /// specified by `hardcoded-tmp-directory`.
     pub hardcoded_tmp_directory_extend: Option<Vec<String>>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "check-nosec = true"
+    )]
+    /// Allow `# nosec` comments (as recognized by `bandit`) to suppress
+    /// `S`-prefixed rules, in addition to `# noqa`. Comments may optionally
+    /// include a comma-separated list of codes to restrict which findings
+    /// are suppressed (e.g., `# nosec S101,S105`); a bare `# nosec` (or
+    /// `# nosec: SOME_STRING`, per `bandit`'s own grammar) suppresses all
+    /// `S`-prefixed rules on the line. Intended to ease migration from
+    /// `bandit` without having to rewrite existing suppression comments.
+    pub check_nosec: Option<bool>,
+    #[option(
+        default = "[]",
+        value_type = "Vec<String>",
+        example = "allowed-try-except-exceptions = [\"KeyError\"]"
+    )]
+    /// A list of exception types (by dotted name, e.g. `KeyError` or
+    /// `socket.timeout`) that are allowed to be silently swallowed by a
+    /// `try`/`except: pass` or `try`/`except: continue` handler, without
+    /// triggering `S110` or `S112`.
+    pub allowed_try_except_exceptions: Option<Vec<String>>,
 }
 
 #[derive(Debug, Hash)]
 pub struct Settings {
     pub hardcoded_tmp_directory: Vec<String>,
+    pub check_nosec: bool,
+    pub allowed_try_except_exceptions: Vec<String>,
 }
 
 impl From<Options> for Settings {
@@ -55,6 +80,10 @@ impl From<Options> for Settings {
                         .into_iter(),
                 )
                 .collect(),
+            check_nosec: options.check_nosec.unwrap_or_default(),
+            allowed_try_except_exceptions: options
+                .allowed_try_except_exceptions
+                .unwrap_or_default(),
         }
     }
 }
@@ -64,6 +93,8 @@ impl From<Settings> for Options {
         Self {
             hardcoded_tmp_directory: Some(settings.hardcoded_tmp_directory),
             hardcoded_tmp_directory_extend: None,
+            check_nosec: Some(settings.check_nosec),
+            allowed_try_except_exceptions: Some(settings.allowed_try_except_exceptions),
         }
     }
 }
@@ -72,6 +103,8 @@ impl Default for Settings {
     fn default() -> Self {
         Self {
             hardcoded_tmp_directory: default_tmp_dirs(),
+            check_nosec: false,
+            allowed_try_except_exceptions: Vec::new(),
         }
     }
 }
@@ -13,6 +13,8 @@ mod tests {
     use crate::settings;
 
     #[test_case(Rule::BlindExcept, Path::new("BLE.py"); "BLE001")]
+    #[test_case(Rule::BlindExceptSwallow, Path::new("BLE002.py"); "BLE002")]
+    #[test_case(Rule::BlindExceptWithoutLogging, Path::new("BLE003.py"); "BLE003")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -1,10 +1,55 @@
-use rustpython_ast::{Expr, ExprKind, Stmt, StmtKind};
+use rustpython_ast::{Excepthandler, Expr, ExprKind, Stmt, StmtKind};
 
+use crate::ast::helpers::except_range;
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
 use crate::registry::Diagnostic;
 use crate::violations;
 
+/// Return `true` if the exception caught by an `except` handler is re-raised
+/// somewhere in its body, either bare (`raise`) or by name (`raise err`).
+fn is_reraised(body: &[Stmt], name: Option<&str>) -> bool {
+    body.iter().any(|stmt| {
+        if let StmtKind::Raise { exc, .. } = &stmt.node {
+            if let Some(exc) = exc {
+                if let ExprKind::Name { id, .. } = &exc.node {
+                    name.map_or(false, |name| name == id)
+                } else {
+                    false
+                }
+            } else {
+                true
+            }
+        } else {
+            false
+        }
+    })
+}
+
+/// Return `true` if the exception handler's body raises anything at all,
+/// bare or otherwise.
+fn has_raise(body: &[Stmt]) -> bool {
+    body.iter()
+        .any(|stmt| matches!(stmt.node, StmtKind::Raise { .. }))
+}
+
+/// Return `true` if the exception handler's body logs the exception (e.g.,
+/// via `logging.exception(...)` or `logger.error(...)`) before it's
+/// swallowed.
+fn logs_exception(body: &[Stmt]) -> bool {
+    const LOGGING_ATTRS: &[&str] = &["exception", "error", "critical", "warning", "log", "debug"];
+
+    body.iter().any(|stmt| {
+        let StmtKind::Expr { value } = &stmt.node else {
+            return false;
+        };
+        let ExprKind::Call { func, .. } = &value.node else {
+            return false;
+        };
+        matches!(&func.node, ExprKind::Attribute { attr, .. } if LOGGING_ATTRS.contains(&attr.as_str()))
+    })
+}
+
 /// BLE001
 pub fn blind_except(
     checker: &mut Checker,
@@ -21,21 +66,7 @@ pub fn blind_except(
     for exception in ["BaseException", "Exception"] {
         if id == exception && checker.is_builtin(exception) {
             // If the exception is re-raised, don't flag an error.
-            if !body.iter().any(|stmt| {
-                if let StmtKind::Raise { exc, .. } = &stmt.node {
-                    if let Some(exc) = exc {
-                        if let ExprKind::Name { id, .. } = &exc.node {
-                            name.map_or(false, |name| name == id)
-                        } else {
-                            false
-                        }
-                    } else {
-                        true
-                    }
-                } else {
-                    false
-                }
-            }) {
+            if !is_reraised(body, name) {
                 checker.diagnostics.push(Diagnostic::new(
                     violations::BlindExcept(id.to_string()),
                     Range::from_located(type_),
@@ -44,3 +75,50 @@ pub fn blind_except(
         }
     }
 }
+
+/// BLE002
+pub fn blind_except_swallow(
+    checker: &mut Checker,
+    excepthandler: &Excepthandler,
+    type_: Option<&Expr>,
+    body: &[Stmt],
+) {
+    // Bare `except:` clauses only; a typed `except Exception:` is covered by BLE001.
+    if type_.is_some() {
+        return;
+    }
+    if has_raise(body) {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        violations::BlindExceptSwallow,
+        except_range(excepthandler, checker.locator),
+    ));
+}
+
+/// BLE003
+pub fn blind_except_without_logging(
+    checker: &mut Checker,
+    excepthandler: &Excepthandler,
+    type_: Option<&Expr>,
+    body: &[Stmt],
+) {
+    if let Some(type_) = type_ {
+        let ExprKind::Name { id, .. } = &type_.node else {
+            return;
+        };
+        if !(id == "Exception" || id == "BaseException") || !checker.is_builtin(id) {
+            return;
+        }
+    }
+    if has_raise(body) {
+        return;
+    }
+    if logs_exception(body) {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        violations::BlindExceptWithoutLogging,
+        except_range(excepthandler, checker.locator),
+    ));
+}
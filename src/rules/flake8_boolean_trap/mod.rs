@@ -15,6 +15,8 @@ mod tests {
     #[test_case(Rule::BooleanPositionalArgInFunctionDefinition, Path::new("FBT.py"); "FBT001")]
     #[test_case(Rule::BooleanDefaultValueInFunctionDefinition, Path::new("FBT.py"); "FBT002")]
     #[test_case(Rule::BooleanPositionalValueInFunctionCall, Path::new("FBT.py"); "FBT003")]
+    #[test_case(Rule::BooleanPositionalArgInFunctionDefinition, Path::new("FBT_fixable.py"); "FBT001_fixable")]
+    #[test_case(Rule::BooleanDefaultValueInFunctionDefinition, Path::new("FBT_fixable.py"); "FBT002_fixable")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -1,9 +1,10 @@
-use rustpython_ast::{Arguments, ExprKind};
+use rustpython_ast::{Arg, Arguments, ExprKind, Location};
 use rustpython_parser::ast::{Constant, Expr};
 
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
-use crate::registry::{Diagnostic, DiagnosticKind};
+use crate::fix::Fix;
+use crate::registry::{Diagnostic, DiagnosticKind, Rule};
 use crate::violations;
 
 const FUNC_NAME_ALLOWLIST: &[&str] = &[
@@ -55,31 +56,89 @@ fn add_if_boolean(checker: &mut Checker, arg: &Expr, kind: DiagnosticKind) {
     }
 }
 
-pub fn check_positional_boolean_in_def(checker: &mut Checker, arguments: &Arguments) {
-    for arg in arguments.posonlyargs.iter().chain(arguments.args.iter()) {
-        if arg.node.annotation.is_none() {
+// check for both bool (python class) and 'bool' (string annotation)
+fn is_bool_annotation(expr: &Expr) -> bool {
+    match &expr.node {
+        ExprKind::Name { id, .. } => id == "bool",
+        ExprKind::Constant {
+            value: Constant::Str(value),
+            ..
+        } => value == "bool",
+        _ => false,
+    }
+}
+
+/// Return the `Location` at which a `*,` marker should be inserted to convert the first
+/// boolean-trap positional parameter (and every later positional parameter) into a
+/// keyword-only one, if doing so wouldn't conflict with an existing positional-only marker.
+///
+/// Only the first offending parameter is considered: once `*,` is inserted before it, every
+/// later positional parameter becomes keyword-only for free, so a second insertion would
+/// produce a duplicate `*` marker (a syntax error).
+fn keyword_only_insertion(arguments: &Arguments) -> Option<Location> {
+    if arguments.vararg.is_some() || !arguments.kwonlyargs.is_empty() {
+        // The signature already has a `*args` or a `*,`/keyword-only section, so inserting
+        // another `*,` marker would produce a second star and a `SyntaxError`; leave it as a
+        // manual fix.
+        return None;
+    }
+    let positional: Vec<&Arg> = arguments
+        .posonlyargs
+        .iter()
+        .chain(arguments.args.iter())
+        .collect();
+    let default_offset = positional.len() - arguments.defaults.len();
+    for (i, arg) in positional.iter().enumerate() {
+        let is_bool_annotated = arg
+            .node
+            .annotation
+            .as_deref()
+            .map_or(false, is_bool_annotation);
+        let is_bool_defaulted =
+            i >= default_offset && is_boolean_arg(&arguments.defaults[i - default_offset]);
+        if !is_bool_annotated && !is_bool_defaulted {
             continue;
         }
+        if i < arguments.posonlyargs.len() {
+            // The parameter is positional-only, so it can't be moved after a `*,` marker
+            // without also relocating the `/` marker; leave it as a manual fix.
+            return None;
+        }
+        return Some(arg.location);
+    }
+    None
+}
+
+/// Return `true` if inserting `content` at `at` wouldn't push that line past `line-length`.
+/// The `*,` marker fix only ever adds a few characters, but a signature that's already near
+/// the limit can still tip over, which would just trade this violation for an E501.
+fn fits_line_length(checker: &Checker, at: Location, content: &str) -> bool {
+    let line = checker.locator.slice_source_code_at(Location::new(at.row(), 0));
+    let line = line.lines().next().unwrap_or_default();
+    line.len() + content.len() <= checker.settings.line_length
+}
+
+pub fn check_positional_boolean_in_def(checker: &mut Checker, arguments: &Arguments) {
+    let insertion = keyword_only_insertion(arguments);
+    for arg in arguments.posonlyargs.iter().chain(arguments.args.iter()) {
         let Some(expr) = &arg.node.annotation else {
             continue;
         };
-
-        // check for both bool (python class) and 'bool' (string annotation)
-        let hint = match &expr.node {
-            ExprKind::Name { id, .. } => id == "bool",
-            ExprKind::Constant {
-                value: Constant::Str(value),
-                ..
-            } => value == "bool",
-            _ => false,
-        };
-        if !hint {
+        if !is_bool_annotation(expr) {
             continue;
         }
-        checker.diagnostics.push(Diagnostic::new(
+
+        let mut diagnostic = Diagnostic::new(
             violations::BooleanPositionalArgInFunctionDefinition,
             Range::from_located(arg),
-        ));
+        );
+        if insertion == Some(arg.location)
+            && checker.patch(&Rule::BooleanPositionalArgInFunctionDefinition)
+            && fits_line_length(checker, arg.location, "*, ")
+        {
+            diagnostic.amend(Fix::insertion("*, ".to_string(), arg.location));
+        }
+        checker.diagnostics.push(diagnostic);
     }
 }
 
@@ -87,12 +146,30 @@ pub fn check_boolean_default_value_in_function_definition(
     checker: &mut Checker,
     arguments: &Arguments,
 ) {
-    for arg in &arguments.defaults {
-        add_if_boolean(
-            checker,
-            arg,
-            violations::BooleanDefaultValueInFunctionDefinition.into(),
+    let insertion = keyword_only_insertion(arguments);
+    let positional: Vec<&Arg> = arguments
+        .posonlyargs
+        .iter()
+        .chain(arguments.args.iter())
+        .collect();
+    let default_offset = positional.len() - arguments.defaults.len();
+    for (i, default) in arguments.defaults.iter().enumerate() {
+        if !is_boolean_arg(default) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            violations::BooleanDefaultValueInFunctionDefinition,
+            Range::from_located(default),
         );
+        let arg_location = positional[default_offset + i].location;
+        if insertion == Some(arg_location)
+            && checker.patch(&Rule::BooleanDefaultValueInFunctionDefinition)
+            && fits_line_length(checker, arg_location, "*, ")
+        {
+            diagnostic.amend(Fix::insertion("*, ".to_string(), arg_location));
+        }
+        checker.diagnostics.push(diagnostic);
     }
 }
 
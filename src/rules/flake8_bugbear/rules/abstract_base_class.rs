@@ -1,10 +1,10 @@
-use rustpython_ast::{Constant, Expr, ExprKind, Keyword, Stmt, StmtKind};
+use rustpython_ast::{Expr, Keyword, Stmt, StmtKind};
 
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
 use crate::registry::{Diagnostic, Rule};
 use crate::violations;
-use crate::visibility::{is_abstract, is_overload};
+use crate::visibility::{is_abstract, is_overload, is_stub_body};
 
 fn is_abc_class(checker: &Checker, bases: &[Expr], keywords: &[Keyword]) -> bool {
     keywords.iter().any(|keyword| {
@@ -25,19 +25,6 @@ fn is_abc_class(checker: &Checker, bases: &[Expr], keywords: &[Keyword]) -> bool
     })
 }
 
-fn is_empty_body(body: &[Stmt]) -> bool {
-    body.iter().all(|stmt| match &stmt.node {
-        StmtKind::Pass => true,
-        StmtKind::Expr { value } => match &value.node {
-            ExprKind::Constant { value, .. } => {
-                matches!(value, Constant::Str(..) | Constant::Ellipsis)
-            }
-            _ => false,
-        },
-        _ => false,
-    })
-}
-
 pub fn abstract_base_class(
     checker: &mut Checker,
     stmt: &Stmt,
@@ -86,7 +73,7 @@ pub fn abstract_base_class(
             continue;
         }
 
-        if !has_abstract_decorator && is_empty_body(body) && !is_overload(checker, decorator_list) {
+        if !has_abstract_decorator && is_stub_body(body) && !is_overload(checker, decorator_list) {
             checker.diagnostics.push(Diagnostic::new(
                 violations::EmptyMethodWithoutAbstractDecorator(name.to_string()),
                 Range::from_located(stmt),
@@ -1,5 +1,5 @@
 use rustc_hash::FxHashSet;
-use rustpython_ast::{Comprehension, Expr, ExprContext, ExprKind, Stmt, StmtKind};
+use rustpython_ast::{Comprehension, Expr, ExprContext, ExprKind, Keyword, Stmt, StmtKind};
 
 use crate::ast::helpers::collect_arg_names;
 use crate::ast::types::{Node, Range};
@@ -34,6 +34,46 @@ where
     }
 }
 
+/// Return `true` if `lambda` can safely be wrapped in `functools.partial(lambda, *args,
+/// **keywords)`.
+///
+/// Unlike `reduce`/`filter`/`map`, `partial` never calls `lambda` itself — it just returns a
+/// new callable that closes over it, so `lambda` can still observe a later value of any
+/// variable it captures. The one safe case is when every variable `lambda` would otherwise
+/// capture by closure is instead supplied as one of `partial`'s own bound arguments (evaluated
+/// eagerly, when `partial` is called): `functools.partial(lambda x: x, x)` binds the loop
+/// variable's current value into the lambda's own parameter, rather than into its closure.
+fn is_eagerly_bound_by_partial(lambda: &Expr, args: &[Expr], keywords: &[Keyword]) -> bool {
+    let ExprKind::Lambda {
+        args: lambda_args,
+        body,
+    } = &lambda.node
+    else {
+        return false;
+    };
+
+    let mut visitor = LoadedNamesVisitor::default();
+    visitor.visit_expr(body);
+    let mut bound_names = collect_arg_names(lambda_args);
+    bound_names.extend(visitor.stored.iter().map(|(id, ..)| id));
+
+    visitor
+        .loaded
+        .iter()
+        .filter(|(id, ..)| !bound_names.contains(id))
+        .all(|(name, ..)| {
+            args.iter().any(|arg| {
+                !std::ptr::eq(arg, lambda)
+                    && matches!(&arg.node, ExprKind::Name { id, .. } if id.as_str() == *name)
+            }) || keywords.iter().any(|keyword| {
+                matches!(
+                    &keyword.node.value.node,
+                    ExprKind::Name { id, .. } if id.as_str() == *name
+                )
+            })
+        })
+}
+
 #[derive(Default)]
 struct SuspiciousVariablesVisitor<'a> {
     names: Vec<(&'a str, &'a Expr, Range)>,
@@ -84,6 +124,12 @@ where
                 args,
                 keywords,
             } => {
+                if matches!(func.node, ExprKind::Lambda { .. }) {
+                    // Immediately-invoked lambda (e.g. `(lambda: x)()`): the closure
+                    // executes synchronously within this iteration, so it can never
+                    // observe a later value of the loop variable.
+                    self.safe_functions.push(func);
+                }
                 if let ExprKind::Name { id, .. } = &func.node {
                     if id == "filter" || id == "reduce" || id == "map" {
                         for arg in args {
@@ -91,6 +137,14 @@ where
                                 self.safe_functions.push(arg);
                             }
                         }
+                    } else if id == "partial" {
+                        for arg in args {
+                            if matches!(arg.node, ExprKind::Lambda { .. })
+                                && is_eagerly_bound_by_partial(arg, args, keywords)
+                            {
+                                self.safe_functions.push(arg);
+                            }
+                        }
                     }
                 }
                 if let ExprKind::Attribute { value, attr, .. } = &func.node {
@@ -104,6 +158,18 @@ where
                                 }
                             }
                         }
+                    } else if attr == "partial" {
+                        if let ExprKind::Name { id, .. } = &value.node {
+                            if id == "functools" {
+                                for arg in args {
+                                    if matches!(arg.node, ExprKind::Lambda { .. })
+                                        && is_eagerly_bound_by_partial(arg, args, keywords)
+                                    {
+                                        self.safe_functions.push(arg);
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
                 for keyword in keywords {
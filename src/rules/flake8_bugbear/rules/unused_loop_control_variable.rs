@@ -52,8 +52,8 @@ pub fn unused_loop_control_variable(checker: &mut Checker, target: &Expr, body:
     };
 
     for (name, expr) in control_names {
-        // Ignore names that are already underscore-prefixed.
-        if name.starts_with('_') {
+        // Ignore names that already look like intentionally-unused variables.
+        if checker.settings.dummy_variable_rgx.is_match(name) {
             continue;
         }
 
@@ -649,6 +649,27 @@ pub fn fix_unnecessary_list_call(locator: &Locator, expr: &rustpython_ast::Expr)
     ))
 }
 
+/// (C418) Convert `dict({"a": 1})` to `{"a": 1}`.
+pub fn fix_unnecessary_dict_call(locator: &Locator, expr: &rustpython_ast::Expr) -> Result<Fix> {
+    // Expr(Call(Dict|DictComp)))) -> Expr(Dict|DictComp)))
+    let module_text = locator.slice_source_code_range(&Range::from_located(expr));
+    let mut tree = match_module(&module_text)?;
+    let mut body = match_expr(&mut tree)?;
+    let call = match_call(body)?;
+    let arg = match_arg(call)?;
+
+    body.value = arg.value.clone();
+
+    let mut state = CodegenState::default();
+    tree.codegen(&mut state);
+
+    Ok(Fix::replacement(
+        state.to_string(),
+        expr.location,
+        expr.end_location.unwrap(),
+    ))
+}
+
 /// (C413) Convert `list(sorted([2, 3, 1]))` to `sorted([2, 3, 1])`.
 /// (C413) Convert `reversed(sorted([2, 3, 1]))` to `sorted([2, 3, 1],
 /// reverse=True)`.
@@ -801,3 +822,26 @@ pub fn fix_unnecessary_comprehension(
         expr.end_location.unwrap(),
     ))
 }
+
+/// (C420) Convert `{x: 1 for x in foo}` to `dict.fromkeys(foo, 1)`.
+///
+/// Unlike the other fixes in this module, this one isn't built via
+/// `libcst_native`: the replacement is a call to an attribute (`dict.fromkeys`)
+/// rather than a bare name or literal, so it's simplest to splice the already
+/// well-formed `iterable` and `value` source snippets into a template string.
+pub fn fix_unnecessary_dict_comprehension_for_iterable(
+    locator: &Locator,
+    expr: &rustpython_ast::Expr,
+    iterable: &rustpython_ast::Expr,
+    value: &rustpython_ast::Expr,
+) -> Result<Fix> {
+    let iterable_text = locator.slice_source_code_range(&Range::from_located(iterable));
+    let value_text = locator.slice_source_code_range(&Range::from_located(value));
+    let contents = format!("dict.fromkeys({iterable_text}, {value_text})");
+
+    Ok(Fix::replacement(
+        contents,
+        expr.location,
+        expr.end_location.unwrap(),
+    ))
+}
@@ -29,6 +29,8 @@ mod tests {
     #[test_case(Rule::UnnecessarySubscriptReversal, Path::new("C415.py"); "C415")]
     #[test_case(Rule::UnnecessaryComprehension, Path::new("C416.py"); "C416")]
     #[test_case(Rule::UnnecessaryMap, Path::new("C417.py"); "C417")]
+    #[test_case(Rule::UnnecessaryDictCall, Path::new("C418.py"); "C418")]
+    #[test_case(Rule::UnnecessaryDictComprehensionForIterable, Path::new("C420.py"); "C420")]
 
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
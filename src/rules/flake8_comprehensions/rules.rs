@@ -3,6 +3,7 @@ use num_bigint::BigInt;
 use rustpython_ast::{Comprehension, Constant, Expr, ExprKind, Keyword, Unaryop};
 
 use super::fixes;
+use crate::ast::helpers::has_comments_in;
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
 use crate::registry::{Diagnostic, Rule};
@@ -315,7 +316,9 @@ pub fn unnecessary_collection_call(
         violations::UnnecessaryCollectionCall(id.to_string()),
         Range::from_located(expr),
     );
-    if checker.patch(&Rule::UnnecessaryCollectionCall) {
+    if checker.patch(&Rule::UnnecessaryCollectionCall)
+        && !has_comments_in(Range::from_located(expr), checker.locator)
+    {
         match fixes::fix_unnecessary_collection_call(checker.locator, expr) {
             Ok(fix) => {
                 diagnostic.amend(fix);
@@ -685,3 +688,89 @@ pub fn unnecessary_map(checker: &mut Checker, expr: &Expr, func: &Expr, args: &[
         _ => (),
     }
 }
+
+/// C418
+pub fn unnecessary_dict_call(
+    checker: &mut Checker,
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let Some(argument) = exactly_one_argument_with_matching_function("dict", func, args, keywords) else {
+        return;
+    };
+    if !checker.is_builtin("dict") {
+        return;
+    }
+    let kind = match argument {
+        ExprKind::Dict { .. } => "dict",
+        ExprKind::DictComp { .. } => "dict comprehension",
+        _ => return,
+    };
+    let mut diagnostic = Diagnostic::new(
+        violations::UnnecessaryDictCall(kind.to_string()),
+        Range::from_located(expr),
+    );
+    if checker.patch(&Rule::UnnecessaryDictCall) {
+        match fixes::fix_unnecessary_dict_call(checker.locator, expr) {
+            Ok(fix) => {
+                diagnostic.amend(fix);
+            }
+            Err(e) => error!("Failed to generate fix: {e}"),
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// C420
+pub fn unnecessary_dict_comprehension_for_iterable(
+    checker: &mut Checker,
+    expr: &Expr,
+    key: &Expr,
+    value: &Expr,
+    generators: &[Comprehension],
+) {
+    let [generator] = generators else {
+        return;
+    };
+    if !(generator.ifs.is_empty() && generator.is_async == 0) {
+        return;
+    }
+    let Some(key_id) = function_name(key) else {
+        return;
+    };
+    let Some(target_id) = function_name(&generator.target) else {
+        return;
+    };
+    if key_id != target_id {
+        return;
+    }
+    // The value must be a constant: otherwise, it may depend on the loop variable, in which case
+    // `dict.fromkeys()` (which evaluates the value expression once, up-front) wouldn't be
+    // equivalent.
+    if !matches!(value.node, ExprKind::Constant { .. }) {
+        return;
+    }
+    if !checker.is_builtin("dict") {
+        return;
+    }
+    let mut diagnostic = Diagnostic::new(
+        violations::UnnecessaryDictComprehensionForIterable,
+        Range::from_located(expr),
+    );
+    if checker.patch(&Rule::UnnecessaryDictComprehensionForIterable) {
+        match fixes::fix_unnecessary_dict_comprehension_for_iterable(
+            checker.locator,
+            expr,
+            &generator.iter,
+            value,
+        ) {
+            Ok(fix) => {
+                diagnostic.amend(fix);
+            }
+            Err(e) => error!("Failed to generate fix: {e}"),
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
@@ -0,0 +1,57 @@
+use rustpython_ast::{Expr, ExprKind, StmtKind, Withitem};
+
+use crate::checkers::ast::Checker;
+
+/// Return `true` if `call_path` matches one of `checker`'s configured
+/// `exempt_time_freezing_calls`.
+fn is_time_freezing_call(checker: &Checker, func: &Expr) -> bool {
+    checker.resolve_call_path(func).map_or(false, |call_path| {
+        checker
+            .settings
+            .flake8_datetimez
+            .exempt_time_freezing_calls
+            .iter()
+            .any(|target| call_path.as_slice() == target.split('.').collect::<Vec<_>>().as_slice())
+    })
+}
+
+/// Return `true` if any enclosing `with` statement's context managers, or any
+/// enclosing function's decorators, resolve to a configured time-freezing call (e.g.,
+/// `freezegun.freeze_time`). Naive `datetime`/`date` usage within such a scope is
+/// exempt from `flake8-datetimez` rules.
+pub fn in_exempt_time_freezing_context(checker: &Checker) -> bool {
+    if checker
+        .settings
+        .flake8_datetimez
+        .exempt_time_freezing_calls
+        .is_empty()
+    {
+        return false;
+    }
+
+    for stmt in checker.parents.iter().rev() {
+        match &stmt.node {
+            StmtKind::With { items, .. } | StmtKind::AsyncWith { items, .. } => {
+                if items.iter().any(|Withitem { context_expr, .. }| {
+                    matches!(&context_expr.node, ExprKind::Call { func, .. } if is_time_freezing_call(checker, func))
+                }) {
+                    return true;
+                }
+            }
+            StmtKind::FunctionDef { decorator_list, .. }
+            | StmtKind::AsyncFunctionDef { decorator_list, .. } => {
+                if decorator_list.iter().any(|decorator| {
+                    let func = match &decorator.node {
+                        ExprKind::Call { func, .. } => func.as_ref(),
+                        _ => decorator,
+                    };
+                    is_time_freezing_call(checker, func)
+                }) {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
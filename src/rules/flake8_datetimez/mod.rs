@@ -1,5 +1,7 @@
 //! Rules from [flake8-datetimez](https://pypi.org/project/flake8-datetimez/20.10.0/).
+pub(crate) mod helpers;
 pub(crate) mod rules;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -11,6 +13,7 @@ mod tests {
     use crate::linter::test_path;
     use crate::registry::Rule;
     use crate::settings;
+    use crate::settings::Settings;
 
     #[test_case(Rule::CallDatetimeWithoutTzinfo, Path::new("DTZ001.py"); "DTZ001")]
     #[test_case(Rule::CallDatetimeToday, Path::new("DTZ002.py"); "DTZ002")]
@@ -32,4 +35,20 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn exempt_time_freezing_calls() -> Result<()> {
+        let snapshot = "exempt_time_freezing_calls".to_string();
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_datetimez/DTZ_exempt.py"),
+            &Settings {
+                flake8_datetimez: super::settings::Settings {
+                    exempt_time_freezing_calls: vec!["freezegun.freeze_time".to_string()],
+                },
+                ..Settings::for_rules(vec![Rule::CallDatetimeNowWithoutTzinfo])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
 }
@@ -0,0 +1,49 @@
+//! Settings for the `flake8-datetimez` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Default, Serialize, Deserialize, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8DatetimezOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow naive datetime usage inside `freezegun.freeze_time` blocks.
+            exempt-time-freezing-calls = ["freezegun.freeze_time"]
+        "#
+    )]
+    /// Calls that, when used as a decorator or `with` context manager, exempt the
+    /// naive `datetime`/`date` usage within their scope from DTZ rules, e.g., calls
+    /// to a time-freezing helper like `freezegun.freeze_time`.
+    pub exempt_time_freezing_calls: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub exempt_time_freezing_calls: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            exempt_time_freezing_calls: options.exempt_time_freezing_calls.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            exempt_time_freezing_calls: Some(settings.exempt_time_freezing_calls),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Rules from [flake8-debugger](https://pypi.org/project/flake8-debugger/4.1.2/).
 pub(crate) mod rules;
+pub mod settings;
 pub(crate) mod types;
 
 #[cfg(test)]
@@ -11,7 +12,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::Rule;
-    use crate::settings;
+    use crate::settings::Settings;
 
     #[test_case(Rule::Debugger, Path::new("T100.py"); "T100")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
@@ -20,9 +21,24 @@ mod tests {
             Path::new("./resources/test/fixtures/flake8_debugger")
                 .join(path)
                 .as_path(),
-            &settings::Settings::for_rule(rule_code),
+            &Settings::for_rule(rule_code),
         )?;
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn check_extend_banned_calls() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_debugger/T100_extend.py"),
+            &Settings {
+                flake8_debugger: super::settings::Settings {
+                    extend_banned_calls: vec!["icecream.ic".to_string()],
+                },
+                ..Settings::for_rule(Rule::Debugger)
+            },
+        )?;
+        insta::assert_yaml_snapshot!("T100_extend", diagnostics);
+        Ok(())
+    }
 }
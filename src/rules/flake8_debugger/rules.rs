@@ -25,22 +25,39 @@ const DEBUGGERS: &[&[&str]] = &[
     &["", "breakpoint"],
 ];
 
+/// Return `true` if `call_path` matches a built-in debugger, or one of the
+/// user-configured `extend_banned_calls` dotted paths.
+fn is_debugger_call_path(call_path: &[&str], extend_banned_calls: &[String]) -> bool {
+    DEBUGGERS.iter().any(|target| call_path == *target)
+        || extend_banned_calls
+            .iter()
+            .any(|banned| call_path == banned.split('.').collect::<Vec<_>>().as_slice())
+}
+
 /// Checks for the presence of a debugger call.
 pub fn debugger_call(checker: &mut Checker, expr: &Expr, func: &Expr) {
-    if let Some(target) = checker.resolve_call_path(func).and_then(|call_path| {
-        DEBUGGERS
-            .iter()
-            .find(|target| call_path.as_slice() == **target)
+    if let Some(call_path) = checker.resolve_call_path(func).filter(|call_path| {
+        is_debugger_call_path(
+            call_path.as_slice(),
+            &checker.settings.flake8_debugger.extend_banned_calls,
+        )
     }) {
         checker.diagnostics.push(Diagnostic::new(
-            violations::Debugger(DebuggerUsingType::Call(format_call_path(target))),
+            violations::Debugger(DebuggerUsingType::Call(format_call_path(
+                call_path.as_slice(),
+            ))),
             Range::from_located(expr),
         ));
     }
 }
 
 /// Checks for the presence of a debugger import.
-pub fn debugger_import(stmt: &Stmt, module: Option<&str>, name: &str) -> Option<Diagnostic> {
+pub fn debugger_import(
+    stmt: &Stmt,
+    module: Option<&str>,
+    name: &str,
+    extend_banned_calls: &[String],
+) -> Option<Diagnostic> {
     // Special-case: allow `import builtins`, which is far more general than (e.g.)
     // `import celery.contrib.rdb`).
     if module.is_none() && name == "builtins" {
@@ -50,7 +67,7 @@ pub fn debugger_import(stmt: &Stmt, module: Option<&str>, name: &str) -> Option<
     if let Some(module) = module {
         let mut call_path = module.split('.').collect::<Vec<_>>();
         call_path.push(name);
-        if DEBUGGERS.iter().any(|target| call_path == **target) {
+        if is_debugger_call_path(&call_path, extend_banned_calls) {
             return Some(Diagnostic::new(
                 violations::Debugger(DebuggerUsingType::Import(format_call_path(&call_path))),
                 Range::from_located(stmt),
@@ -61,6 +78,10 @@ pub fn debugger_import(stmt: &Stmt, module: Option<&str>, name: &str) -> Option<
         if DEBUGGERS
             .iter()
             .any(|call_path| call_path[..call_path.len() - 1] == parts)
+            || extend_banned_calls.iter().any(|banned| {
+                let banned = banned.split('.').collect::<Vec<_>>();
+                banned[..banned.len().saturating_sub(1)] == parts
+            })
         {
             return Some(Diagnostic::new(
                 violations::Debugger(DebuggerUsingType::Import(name.to_string())),
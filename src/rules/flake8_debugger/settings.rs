@@ -0,0 +1,48 @@
+//! Settings for the `flake8-debugger` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Default, Serialize, Deserialize, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8DebuggerOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Also flag calls to and imports of `icecream.ic`.
+            extend-banned-calls = ["icecream.ic"]
+        "#
+    )]
+    /// Additional callable dotted paths to treat as debugger calls (and
+    /// imports), e.g., `icecream.ic`.
+    pub extend_banned_calls: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub extend_banned_calls: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            extend_banned_calls: options.extend_banned_calls.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            extend_banned_calls: Some(settings.extend_banned_calls),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Rules from [flake8-fixme](https://pypi.org/project/flake8-fixme/1.1.1/).
+pub(crate) mod rules;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::linter::test_path;
+    use crate::registry::Rule;
+    use crate::settings::Settings;
+
+    #[test]
+    fn defaults() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_fixme/FIX.py"),
+            &Settings::for_rules(vec![
+                Rule::LineContainsFixme,
+                Rule::LineContainsTodo,
+                Rule::LineContainsXxx,
+                Rule::LineContainsHack,
+            ]),
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustpython_ast::Location;
+
+use crate::ast::types::Range;
+use crate::registry::{Diagnostic, DiagnosticKind, Rule};
+use crate::settings::Settings;
+use crate::violations;
+
+/// Matches an upper-case `FIXME`, `TODO`, `XXX`, or `HACK` marker anywhere in a comment, as a
+/// whole word.
+static FIXME_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?P<tag>FIXME|TODO|XXX|HACK)\b").unwrap()
+});
+
+/// FIX001, FIX002, FIX003, FIX004
+pub fn fixmes(line: &str, start: Location, end: Location, settings: &Settings) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for captures in FIXME_REGEX.captures_iter(line) {
+        let tag = captures.name("tag").unwrap().as_str().to_uppercase();
+        let (rule, kind): (Rule, DiagnosticKind) = match tag.as_str() {
+            "FIXME" => (Rule::LineContainsFixme, violations::LineContainsFixme.into()),
+            "TODO" => (Rule::LineContainsTodo, violations::LineContainsTodo.into()),
+            "XXX" => (Rule::LineContainsXxx, violations::LineContainsXxx.into()),
+            "HACK" => (Rule::LineContainsHack, violations::LineContainsHack.into()),
+            _ => unreachable!("Regex only matches FIXME, TODO, XXX, or HACK"),
+        };
+        if settings.rules.enabled(&rule) {
+            diagnostics.push(Diagnostic::new(kind, Range::new(start, end)));
+        }
+    }
+
+    diagnostics
+}
@@ -34,6 +34,8 @@ mod tests {
                         ("dask.array".to_string(), "da".to_string()),
                         ("dask.dataframe".to_string(), "dd".to_string()),
                     ])),
+                    banned_aliases: None,
+                    banned_from: None,
                 }
                 .into(),
                 ..Settings::for_rule(Rule::ImportAliasIsNotConventional)
@@ -56,6 +58,8 @@ mod tests {
                         ("seaborn".to_string(), "sns".to_string()),
                     ])),
                     extend_aliases: None,
+                    banned_aliases: None,
+                    banned_from: None,
                 }
                 .into(),
                 ..Settings::for_rule(Rule::ImportAliasIsNotConventional)
@@ -76,6 +80,8 @@ mod tests {
                         "numpy".to_string(),
                         "nmp".to_string(),
                     )])),
+                    banned_aliases: None,
+                    banned_from: None,
                 }
                 .into(),
                 ..Settings::for_rule(Rule::ImportAliasIsNotConventional)
@@ -84,4 +90,45 @@ mod tests {
         insta::assert_yaml_snapshot!("override_default", diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn banned_aliases() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_import_conventions/banned_aliases.py"),
+            &Settings {
+                flake8_import_conventions: super::settings::Options {
+                    aliases: None,
+                    extend_aliases: None,
+                    banned_aliases: Some(FxHashMap::from_iter([
+                        ("pandas".to_string(), vec!["pand".to_string()]),
+                        ("numpy".to_string(), vec!["numpie".to_string()]),
+                    ])),
+                    banned_from: None,
+                }
+                .into(),
+                ..Settings::for_rule(Rule::BannedImportAlias)
+            },
+        )?;
+        insta::assert_yaml_snapshot!("banned_aliases", diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn banned_from() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_import_conventions/banned_from.py"),
+            &Settings {
+                flake8_import_conventions: super::settings::Options {
+                    aliases: None,
+                    extend_aliases: None,
+                    banned_aliases: None,
+                    banned_from: Some(vec!["typing".to_string(), "datetime".to_string()]),
+                }
+                .into(),
+                ..Settings::for_rule(Rule::BannedImportFrom)
+            },
+        )?;
+        insta::assert_yaml_snapshot!("banned_from", diagnostics);
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use rustpython_ast::Stmt;
 
 use crate::ast::types::Range;
@@ -35,3 +35,36 @@ pub fn check_conventional_import(
     }
     None
 }
+
+/// ICN002
+pub fn check_banned_import_alias(
+    import_from: &Stmt,
+    name: &str,
+    asname: Option<&str>,
+    banned_aliases: &FxHashMap<String, Vec<String>>,
+) -> Option<Diagnostic> {
+    let asname = asname?;
+    let banned = banned_aliases.get(name)?;
+    if banned.iter().any(|banned_alias| banned_alias == asname) {
+        return Some(Diagnostic::new(
+            violations::BannedImportAlias(name.to_string(), asname.to_string()),
+            Range::from_located(import_from),
+        ));
+    }
+    None
+}
+
+/// ICN003
+pub fn check_banned_import_from(
+    import_from: &Stmt,
+    name: &str,
+    banned_from: &FxHashSet<String>,
+) -> Option<Diagnostic> {
+    if banned_from.contains(name) {
+        return Some(Diagnostic::new(
+            violations::BannedImportFrom(name.to_string()),
+            Range::from_located(import_from),
+        ));
+    }
+    None
+}
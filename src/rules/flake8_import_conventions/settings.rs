@@ -3,11 +3,11 @@
 use std::hash::Hash;
 
 use ruff_macros::ConfigurationOptions;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::settings::hashable::HashableHashMap;
+use crate::settings::hashable::{HashableHashMap, HashableHashSet};
 
 const CONVENTIONAL_ALIASES: &[(&str, &str)] = &[
     ("altair", "alt"),
@@ -54,11 +54,40 @@ pub struct Options {
     /// A mapping of modules to their conventional import aliases. These aliases
     /// will be added to the `aliases` mapping.
     pub extend_aliases: Option<FxHashMap<String, String>>,
+    #[option(
+        default = r#"{}"#,
+        value_type = "FxHashMap<String, Vec<String>>",
+        example = r#"
+            [tool.ruff.flake8-import-conventions.banned-aliases]
+            # Declare that "pd" is a disallowed alias for "pandas".
+            pandas = ["pd"]
+        "#
+    )]
+    /// A mapping of modules to their banned import aliases, e.g., `{"pandas":
+    /// ["pd"]}` will disallow `import pandas as pd`.
+    pub banned_aliases: Option<FxHashMap<String, Vec<String>>>,
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            [tool.ruff.flake8-import-conventions]
+            # Always import the following modules using `import` statements,
+            # rather than `from ... import` statements.
+            banned-from = ["typing"]
+        "#
+    )]
+    /// A list of modules that should not be imported from using the `from
+    /// ... import` syntax, e.g., `from datetime import datetime` will be
+    /// flagged if `datetime` is included in this list, in favor of `import
+    /// datetime`.
+    pub banned_from: Option<Vec<String>>,
 }
 
 #[derive(Debug, Hash)]
 pub struct Settings {
     pub aliases: HashableHashMap<String, String>,
+    pub banned_aliases: HashableHashMap<String, Vec<String>>,
+    pub banned_from: HashableHashSet<String>,
 }
 
 fn default_aliases() -> FxHashMap<String, String> {
@@ -83,13 +112,18 @@ impl Default for Settings {
     fn default() -> Self {
         Self {
             aliases: default_aliases().into(),
+            banned_aliases: HashableHashMap::default(),
+            banned_from: HashableHashSet::default(),
         }
     }
 }
 
 impl From<Options> for Settings {
-    fn from(options: Options) -> Self {
+    fn from(mut options: Options) -> Self {
         Self {
+            banned_aliases: options.banned_aliases.unwrap_or_default().into(),
+            banned_from: FxHashSet::from_iter(options.banned_from.take().unwrap_or_default())
+                .into(),
             aliases: resolve_aliases(options).into(),
         }
     }
@@ -100,6 +134,8 @@ impl From<Settings> for Options {
         Self {
             aliases: Some(settings.aliases.into()),
             extend_aliases: None,
+            banned_aliases: Some(settings.banned_aliases.into()),
+            banned_from: Some(FxHashSet::from(settings.banned_from).into_iter().collect()),
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Rules from [flake8-no-pep420](https://pypi.org/project/flake8-no-pep420/2.3.0/).
 pub(crate) mod rules;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -10,6 +11,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::Rule;
+    use crate::rules::flake8_no_pep420;
     use crate::settings::Settings;
 
     #[test_case(Path::new("test_pass"); "INP001_0")]
@@ -29,4 +31,24 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn script_directory() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new(
+                "./resources/test/fixtures/flake8_no_pep420/test_script_directory/scripts/example.py",
+            ),
+            &Settings {
+                flake8_no_pep420: flake8_no_pep420::settings::Settings {
+                    script_directories: vec!["**/scripts".to_string()],
+                },
+                ..Settings::for_rules(vec![
+                    Rule::ImplicitNamespacePackage,
+                    Rule::ImplicitNamespacePackageInScriptDirectory,
+                ])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
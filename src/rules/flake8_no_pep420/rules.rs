@@ -1,18 +1,37 @@
 use std::path::Path;
 
+use globset::Glob;
+
 use crate::ast::types::Range;
 use crate::registry::Diagnostic;
 use crate::{fs, violations};
 
-/// INP001
-pub fn implicit_namespace_package(path: &Path) -> Option<Diagnostic> {
-    if let Some(parent) = path.parent() {
-        if !parent.join("__init__.py").as_path().exists() {
-            return Some(Diagnostic::new(
-                violations::ImplicitNamespacePackage(fs::relativize_path(path).to_string()),
-                Range::default(),
-            ));
-        }
+/// INP001, INP002
+pub fn implicit_namespace_package(path: &Path, script_directories: &[String]) -> Option<Diagnostic> {
+    let parent = path.parent()?;
+    if parent.join("__init__.py").as_path().exists() {
+        return None;
+    }
+
+    let filename = fs::relativize_path(path).to_string();
+    if is_script_directory(parent, script_directories) {
+        return Some(Diagnostic::new(
+            violations::ImplicitNamespacePackageInScriptDirectory(filename),
+            Range::default(),
+        ));
     }
-    None
+    Some(Diagnostic::new(
+        violations::ImplicitNamespacePackage(filename),
+        Range::default(),
+    ))
+}
+
+/// Return `true` if `dir` matches one of the user-configured script-directory globs.
+fn is_script_directory(dir: &Path, script_directories: &[String]) -> bool {
+    let relative = fs::relativize_path(dir);
+    script_directories.iter().any(|pattern| {
+        Glob::new(pattern)
+            .map(|glob| glob.compile_matcher().is_match(&relative))
+            .unwrap_or(false)
+    })
 }
@@ -0,0 +1,47 @@
+//! Settings for the `flake8-no-pep420` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8NoPep420Options"
+)]
+pub struct Options {
+    #[option(
+        default = "[]",
+        value_type = "Vec<String>",
+        example = "script-directories = [\"scripts\"]"
+    )]
+    /// Glob patterns for directories that hold standalone, executable
+    /// scripts rather than importable packages. A missing `__init__.py` in
+    /// a directory that matches one of these patterns is reported as
+    /// `INP002`, rather than `INP001`.
+    pub script_directories: Option<Vec<String>>,
+}
+
+#[derive(Debug, Hash, Default)]
+pub struct Settings {
+    pub script_directories: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            script_directories: options.script_directories.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            script_directories: Some(settings.script_directories),
+        }
+    }
+}
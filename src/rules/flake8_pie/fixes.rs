@@ -0,0 +1,42 @@
+use anyhow::{bail, Result};
+use rustpython_ast::{Expr, Location};
+use rustpython_parser::lexer;
+use rustpython_parser::token::Tok;
+
+use crate::ast::types::Range;
+use crate::fix::Fix;
+use crate::source_code::Locator;
+
+/// Return the `Location` of the `**` that precedes `value` within `dict`'s source range.
+///
+/// A `Dict`'s `**spread` entries appear only in `values` (with no matching entry in `keys`), so
+/// there's no AST node spanning the `**` token itself; we scan the token stream between the
+/// dict's opening brace and the value for the last `**` we find.
+fn find_double_star(dict: &Expr, value: &Expr, locator: &Locator) -> Result<Location> {
+    let range = Range::new(dict.location, value.location);
+    let contents = locator.slice_source_code_range(&range);
+
+    let mut double_star = None;
+    for (start, tok, ..) in lexer::make_tokenizer_located(&contents, range.location).flatten() {
+        if matches!(tok, Tok::DoubleStar) {
+            double_star = Some(start);
+        }
+    }
+    double_star.ok_or_else(|| anyhow::anyhow!("Unable to locate `**` preceding spread value"))
+}
+
+/// Remove a `**{...}` spread from `dict`, replacing it with the inner dict's own contents.
+pub fn unnecessary_spread(dict: &Expr, value: &Expr, locator: &Locator) -> Result<Fix> {
+    let double_star = find_double_star(dict, value, locator)?;
+
+    let inner = locator.slice_source_code_range(&Range::from_located(value));
+    let Some(inner) = inner.strip_prefix('{').and_then(|inner| inner.strip_suffix('}')) else {
+        bail!("Expected spread value to be a `dict` literal");
+    };
+
+    Ok(Fix::replacement(
+        inner.trim().to_string(),
+        double_star,
+        value.end_location.unwrap(),
+    ))
+}
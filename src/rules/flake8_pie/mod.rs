@@ -1,4 +1,5 @@
 //! Rules from [flake8-pie](https://pypi.org/project/flake8-pie/0.16.0/).
+mod fixes;
 pub(crate) mod rules;
 
 #[cfg(test)]
@@ -15,7 +16,11 @@ mod tests {
     #[test_case(Rule::NoUnnecessaryPass, Path::new("PIE790.py"); "PIE790")]
     #[test_case(Rule::DupeClassFieldDefinitions, Path::new("PIE794.py"); "PIE794")]
     #[test_case(Rule::PreferUniqueEnums, Path::new("PIE796.py"); "PIE796")]
+    #[test_case(Rule::UnnecessarySpread, Path::new("PIE800.py"); "PIE800")]
+    #[test_case(Rule::UnnecessaryDictKwargs, Path::new("PIE804.py"); "PIE804")]
     #[test_case(Rule::PreferListBuiltin, Path::new("PIE807.py"); "PIE807")]
+    #[test_case(Rule::UnnecessaryRangeStart, Path::new("PIE808.py"); "PIE808")]
+    #[test_case(Rule::MultipleStartsEndsWith, Path::new("PIE810.py"); "PIE810")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
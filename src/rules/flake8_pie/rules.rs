@@ -1,9 +1,14 @@
+use std::iter;
+
+use itertools::Either::{Left, Right};
 use log::error;
-use rustc_hash::FxHashSet;
-use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+use num_bigint::BigInt;
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Boolop, Constant, Expr, ExprContext, ExprKind, Keyword, Stmt, StmtKind};
 
+use super::fixes;
 use crate::ast::comparable::ComparableExpr;
-use crate::ast::helpers::unparse_expr;
+use crate::ast::helpers::{create_expr, unparse_expr};
 use crate::ast::types::{Range, RefEquality};
 use crate::autofix::helpers::delete_stmt;
 use crate::checkers::ast::Checker;
@@ -174,3 +179,247 @@ pub fn prefer_list_builtin(checker: &mut Checker, expr: &Expr) {
         }
     }
 }
+
+/// PIE800
+pub fn unnecessary_spread(checker: &mut Checker, dict: &Expr, keys: &[Expr], values: &[Expr]) {
+    // `values` has one entry per `key`, followed by one entry per `**spread` (in source order).
+    let unpacked = &values[keys.len()..];
+    for value in unpacked {
+        if !matches!(value.node, ExprKind::Dict { .. }) {
+            continue;
+        }
+
+        let mut diagnostic =
+            Diagnostic::new(violations::UnnecessarySpread, Range::from_located(value));
+        if checker.patch(&Rule::UnnecessarySpread) {
+            match fixes::unnecessary_spread(dict, value, checker.locator) {
+                Ok(fix) => {
+                    diagnostic.amend(fix);
+                }
+                Err(err) => {
+                    error!("Failed to remove unnecessary dict spread: {}", err);
+                }
+            }
+        }
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+/// PIE804
+pub fn unnecessary_dict_kwargs(checker: &mut Checker, keywords: &[Keyword]) {
+    for keyword in keywords {
+        // Ensure that we have a `**kwargs` call, and that it's a `dict` literal.
+        if keyword.node.arg.is_some() {
+            continue;
+        }
+        let ExprKind::Dict { keys, values } = &keyword.node.value.node else {
+            continue;
+        };
+        // An empty dict, or a dict containing its own spread (more `values` than `keys`), can't
+        // be safely rewritten as keyword arguments in place.
+        if keys.is_empty() || values.len() != keys.len() {
+            continue;
+        }
+
+        let mut names = Vec::with_capacity(keys.len());
+        for key in keys.iter() {
+            let ExprKind::Constant {
+                value: Constant::Str(name),
+                ..
+            } = &key.node
+            else {
+                names.clear();
+                break;
+            };
+            if !is_identifier(name) {
+                names.clear();
+                break;
+            }
+            names.push(name.as_str());
+        }
+        if names.len() != keys.len() {
+            continue;
+        }
+
+        // Don't rewrite if any of the names collide with an existing keyword argument, or with
+        // one another.
+        let mut seen: FxHashSet<&str> = keywords
+            .iter()
+            .filter_map(|other| other.node.arg.as_deref())
+            .collect();
+        if names.iter().any(|name| !seen.insert(name)) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            violations::UnnecessaryDictKwargs,
+            Range::from_located(keyword),
+        );
+        if checker.patch(&Rule::UnnecessaryDictKwargs) {
+            let content = names
+                .iter()
+                .zip(values.iter())
+                .map(|(name, value)| format!("{name}={}", unparse_expr(value, checker.stylist)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            diagnostic.amend(Fix::replacement(
+                content,
+                keyword.location,
+                keyword.end_location.unwrap(),
+            ));
+        }
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+/// Return `true` if `name` is a valid Python identifier (and not a reserved keyword).
+fn is_identifier(name: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+        "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+        "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield",
+    ];
+    if KEYWORDS.contains(&name) {
+        return false;
+    }
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_alphabetic() || first == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// PIE808
+pub fn unnecessary_range_start(checker: &mut Checker, func: &Expr, args: &[Expr]) {
+    let ExprKind::Name { id, .. } = &func.node else {
+        return;
+    };
+    if id != "range" || !checker.is_builtin(id) {
+        return;
+    }
+    if args.len() != 2 {
+        return;
+    }
+    let ExprKind::Constant {
+        value: Constant::Int(start),
+        ..
+    } = &args[0].node
+    else {
+        return;
+    };
+    if *start != BigInt::from(0) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        violations::UnnecessaryRangeStart,
+        Range::from_located(&args[0]),
+    );
+    if checker.patch(&Rule::UnnecessaryRangeStart) {
+        diagnostic.amend(Fix::deletion(args[0].location, args[1].location));
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// PIE810
+pub fn multiple_starts_ends_with(checker: &mut Checker, expr: &Expr) {
+    let ExprKind::BoolOp {
+        op: Boolop::Or,
+        values,
+    } = &expr.node
+    else {
+        return;
+    };
+
+    // Group `foo.startswith(...)` / `foo.endswith(...)` calls by receiver and method name.
+    let mut duplicates: FxHashMap<(ComparableExpr, &str), Vec<usize>> = FxHashMap::default();
+    for (index, call) in values.iter().enumerate() {
+        let ExprKind::Call {
+            func,
+            args,
+            keywords,
+        } = &call.node
+        else {
+            continue;
+        };
+        if args.len() != 1 || !keywords.is_empty() {
+            continue;
+        }
+        let ExprKind::Attribute { value, attr, .. } = &func.node else {
+            continue;
+        };
+        if attr != "startswith" && attr != "endswith" {
+            continue;
+        }
+        duplicates
+            .entry((ComparableExpr::from(value), attr.as_str()))
+            .or_insert_with(Vec::new)
+            .push(index);
+    }
+
+    for ((_, method), indices) in duplicates {
+        if indices.len() <= 1 {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            violations::MultipleStartsEndsWith(method.to_string()),
+            Range::from_located(expr),
+        );
+        if checker.patch(&Rule::MultipleStartsEndsWith) {
+            let arg_values: Vec<&Expr> = indices
+                .iter()
+                .map(|index| &values[*index])
+                .map(|expr| {
+                    let ExprKind::Call { args, .. } = &expr.node else {
+                        unreachable!("Indices should only contain `startswith`/`endswith` calls");
+                    };
+                    &args[0]
+                })
+                .collect();
+            let ExprKind::Call { func, .. } = &values[indices[0]].node else {
+                unreachable!("Indices should only contain `startswith`/`endswith` calls");
+            };
+
+            let call = create_expr(ExprKind::Call {
+                func: func.clone(),
+                args: vec![create_expr(ExprKind::Tuple {
+                    elts: arg_values
+                        .iter()
+                        .flat_map(|value| {
+                            if let ExprKind::Tuple { elts, .. } = &value.node {
+                                Left(elts.iter())
+                            } else {
+                                Right(iter::once(*value))
+                            }
+                        })
+                        .map(Clone::clone)
+                        .collect(),
+                    ctx: ExprContext::Load,
+                })],
+                keywords: vec![],
+            });
+
+            let bool_op = create_expr(ExprKind::BoolOp {
+                op: Boolop::Or,
+                values: iter::once(call)
+                    .chain(
+                        values
+                            .iter()
+                            .enumerate()
+                            .filter(|(index, _)| !indices.contains(index))
+                            .map(|(_, elt)| elt.clone()),
+                    )
+                    .collect(),
+            });
+
+            diagnostic.amend(Fix::replacement(
+                unparse_expr(&bool_op, checker.stylist),
+                expr.location,
+                expr.end_location.unwrap(),
+            ));
+        }
+        checker.diagnostics.push(diagnostic);
+    }
+}
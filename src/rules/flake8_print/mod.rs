@@ -1,5 +1,6 @@
 //! Rules from [flake8-print](https://pypi.org/project/flake8-print/5.0.0/).
 pub(crate) mod rules;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +11,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::Rule;
-    use crate::settings;
+    use crate::settings::Settings;
 
     #[test_case(Rule::PrintFound, Path::new("T201.py"); "T201")]
     #[test_case(Rule::PPrintFound, Path::new("T203.py"); "T203")]
@@ -20,9 +21,27 @@ mod tests {
             Path::new("./resources/test/fixtures/flake8_print")
                 .join(path)
                 .as_path(),
-            &settings::Settings::for_rule(rule_code),
+            &Settings::for_rule(rule_code),
         )?;
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn check_extend_banned_calls() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_print/T201_extend.py"),
+            &Settings {
+                flake8_print: super::settings::Settings {
+                    extend_banned_calls: vec![
+                        "sys.stdout.write".to_string(),
+                        "sys.stderr.write".to_string(),
+                    ],
+                },
+                ..Settings::for_rule(Rule::PrintFound)
+            },
+        )?;
+        insta::assert_yaml_snapshot!("T201_extend", diagnostics);
+        Ok(())
+    }
 }
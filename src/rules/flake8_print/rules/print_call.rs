@@ -39,6 +39,17 @@ pub fn print_call(checker: &mut Checker, func: &Expr, keywords: &[Keyword]) {
             *call_path.as_slice() == ["pprint", "pprint"]
         }) {
             Diagnostic::new(violations::PPrintFound, Range::from_located(func))
+        } else if call_path.as_ref().map_or(false, |call_path| {
+            checker
+                .settings
+                .flake8_print
+                .extend_banned_calls
+                .iter()
+                .any(|banned| {
+                    call_path.as_slice() == banned.split('.').collect::<Vec<_>>().as_slice()
+                })
+        }) {
+            Diagnostic::new(violations::PrintFound, Range::from_located(func))
         } else {
             return;
         }
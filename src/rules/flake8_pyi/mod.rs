@@ -0,0 +1,23 @@
+//! Rules from [flake8-pyi](https://pypi.org/project/flake8-pyi/22.11.0/).
+pub(crate) mod rules;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::linter::test_path;
+    use crate::registry::Rule;
+    use crate::settings::Settings;
+
+    #[test]
+    fn docstring_in_stub() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_pyi/PYI021.pyi"),
+            &Settings::for_rule(Rule::DocstringInStub),
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+}
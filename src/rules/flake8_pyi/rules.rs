@@ -0,0 +1,14 @@
+use rustpython_ast::Expr;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// PYI021
+pub fn docstring_in_stub(checker: &mut Checker, docstring: &Expr) {
+    checker.diagnostics.push(Diagnostic::new(
+        violations::DocstringInStub,
+        Range::from_located(docstring),
+    ));
+}
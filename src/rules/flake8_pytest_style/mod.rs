@@ -240,6 +240,13 @@ mod tests {
         "PT026";
         "PT026"
     )]
+    #[test_case(
+        Rule::UnittestRaisesAssertion,
+        Path::new("PT027.py"),
+        Settings::default(),
+        "PT027";
+        "PT027"
+    )]
     fn test_pytest_style(
         rule_code: Rule,
         path: &Path,
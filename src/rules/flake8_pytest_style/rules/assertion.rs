@@ -1,10 +1,11 @@
 use rustpython_ast::{
-    Boolop, Excepthandler, ExcepthandlerKind, Expr, ExprKind, Keyword, Stmt, StmtKind, Unaryop,
+    Boolop, Excepthandler, ExcepthandlerKind, Expr, ExprContext, ExprKind, Keyword, Stmt,
+    StmtKind, Unaryop,
 };
 
 use super::helpers::is_falsy_constant;
 use super::unittest_assert::UnittestAssert;
-use crate::ast::helpers::unparse_stmt;
+use crate::ast::helpers::{create_expr, unparse_expr, unparse_stmt};
 use crate::ast::types::Range;
 use crate::ast::visitor;
 use crate::ast::visitor::Visitor;
@@ -121,6 +122,51 @@ pub fn unittest_assertion(
     }
 }
 
+/// PT027
+pub fn unittest_raises_assertion(
+    checker: &Checker,
+    call: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) -> Option<Diagnostic> {
+    let ExprKind::Attribute { attr, .. } = &func.node else {
+        return None;
+    };
+    if attr != "assertRaises" {
+        return None;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        violations::UnittestRaisesAssertion(attr.to_string()),
+        Range::from_located(func),
+    );
+    if checker.patch(diagnostic.kind.rule()) {
+        // Only mechanical when used as a context manager, i.e. called with a
+        // single positional argument (the exception type) and no keywords.
+        if args.len() == 1 && keywords.is_empty() {
+            let raises = create_expr(ExprKind::Call {
+                func: Box::new(create_expr(ExprKind::Attribute {
+                    value: Box::new(create_expr(ExprKind::Name {
+                        id: "pytest".to_string(),
+                        ctx: ExprContext::Load,
+                    })),
+                    attr: "raises".to_string(),
+                    ctx: ExprContext::Load,
+                })),
+                args: args.to_vec(),
+                keywords: vec![],
+            });
+            diagnostic.amend(Fix::replacement(
+                unparse_expr(&raises, checker.stylist),
+                call.location,
+                call.end_location.unwrap(),
+            ));
+        }
+    }
+    Some(diagnostic)
+}
+
 /// PT015
 pub fn assert_falsy(assert_stmt: &Stmt, test_expr: &Expr) -> Option<Diagnostic> {
     if is_falsy_constant(test_expr) {
@@ -1,5 +1,6 @@
 pub use assertion::{
     assert_falsy, assert_in_exception_handler, composite_condition, unittest_assertion,
+    unittest_raises_assertion,
 };
 pub use fail::fail_call;
 pub use fixture::fixture;
@@ -0,0 +1,76 @@
+use anyhow::{bail, Result};
+use rustpython_ast::{Location, Stmt, StmtKind};
+
+use crate::ast::helpers::{elif_else_range, has_multiline_string};
+use crate::ast::types::Range;
+use crate::ast::whitespace::{dedent, has_mixed_indentation, indentation};
+use crate::fix::Fix;
+use crate::source_code::Locator;
+
+/// RET505, RET506, RET507, RET508
+///
+/// Replace a superfluous `elif` with `if`. The preceding branch already exits
+/// unconditionally, so an `if` in its place is syntactically and semantically
+/// identical to the original `elif` — no re-indentation is needed. (Only the
+/// `elif` keyword itself is rewritten, so unlike `remove_else`, this never
+/// touches the body and so can't disturb a multi-line string within it.)
+pub fn convert_elif_to_if(stmt: &Stmt, locator: &Locator) -> Result<Fix> {
+    let Some(range) = elif_else_range(stmt, locator) else {
+        bail!("Unable to locate `elif` keyword");
+    };
+    Ok(Fix::replacement(
+        "if".to_string(),
+        range.location,
+        range.end_location,
+    ))
+}
+
+/// RET505, RET506, RET507, RET508
+///
+/// Remove a superfluous `else` block, dedenting its body (including any leading
+/// comments) to match the enclosing `if`.
+pub fn remove_else(stmt: &Stmt, locator: &Locator) -> Result<Fix> {
+    let StmtKind::If { orelse, .. } = &stmt.node else {
+        bail!("Expected `Stmt::If`");
+    };
+    let Some(first) = orelse.first() else {
+        bail!("Expected `else` block to be non-empty");
+    };
+    let last = orelse.last().unwrap();
+
+    let Some(body_indent) = indentation(locator, first) else {
+        bail!("Unable to determine `else` block indentation");
+    };
+    let Some(outer_indent) = indentation(locator, stmt) else {
+        bail!("Unable to determine `if` indentation");
+    };
+    if body_indent.len() <= outer_indent.len() {
+        bail!("Expected `else` block to be indented further than the `if`");
+    }
+    let width = body_indent.len() - outer_indent.len();
+
+    let Some(else_range) = elif_else_range(stmt, locator) else {
+        bail!("Unable to locate `else` keyword");
+    };
+
+    // Slice everything after the `else:` line through the end of the block, so that any
+    // comments preceding the first statement are preserved (and dedented) too.
+    let body_start = Location::new(else_range.location.row() + 1, 0);
+    let body_range = Range::new(body_start, last.end_location.unwrap());
+    let body = locator.slice_source_code_range(&body_range);
+    if has_mixed_indentation(&body) {
+        bail!("Unable to dedent `else` block with mixed tabs and spaces");
+    }
+    if has_multiline_string(body_range, locator) {
+        // Dedenting is purely textual: it would strip whitespace from the *contents* of a
+        // multi-line string literal along with the code's indentation, changing the value the
+        // program returns rather than just its formatting.
+        bail!("Unable to dedent `else` block containing a multi-line string");
+    }
+
+    Ok(Fix::replacement(
+        dedent(&body, width),
+        Location::new(else_range.location.row(), 0),
+        last.end_location.unwrap(),
+    ))
+}
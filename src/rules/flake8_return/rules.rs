@@ -1,6 +1,8 @@
 use itertools::Itertools;
+use log::error;
 use rustpython_ast::{Constant, Expr, ExprKind, Location, Stmt, StmtKind};
 
+use super::fixes;
 use super::helpers::result_exists;
 use super::visitor::{ReturnVisitor, Stack};
 use crate::ast::helpers::elif_else_range;
@@ -221,6 +223,26 @@ fn unnecessary_assign(checker: &mut Checker, stack: &Stack, expr: &Expr) {
     }
 }
 
+/// Compute the fix for a superfluous `elif`/`else` branch, if the current rule has autofix
+/// enabled for it. Shared across `RET505`-`RET508`, since the fix depends only on whether the
+/// branch is an `elif` or an `else`, not on which trailing statement made it superfluous.
+fn superfluous_else_fix(checker: &Checker, stmt: &Stmt, branch: Branch, rule: &Rule) -> Option<Fix> {
+    if !checker.patch(rule) {
+        return None;
+    }
+    let result = match branch {
+        Branch::Elif => fixes::convert_elif_to_if(stmt, checker.locator),
+        Branch::Else => fixes::remove_else(stmt, checker.locator),
+    };
+    match result {
+        Ok(fix) => Some(fix),
+        Err(e) => {
+            error!("Failed to generate fix: {e}");
+            None
+        }
+    }
+}
+
 /// RET505, RET506, RET507, RET508
 fn superfluous_else_node(checker: &mut Checker, stmt: &Stmt, branch: Branch) -> bool {
     let StmtKind::If { body, .. } = &stmt.node else {
@@ -229,31 +251,49 @@ fn superfluous_else_node(checker: &mut Checker, stmt: &Stmt, branch: Branch) ->
     for child in body {
         if matches!(child.node, StmtKind::Return { .. }) {
             if checker.settings.rules.enabled(&Rule::SuperfluousElseReturn) {
-                checker.diagnostics.push(Diagnostic::new(
+                let mut diagnostic = Diagnostic::new(
                     violations::SuperfluousElseReturn(branch),
                     elif_else_range(stmt, checker.locator)
                         .unwrap_or_else(|| Range::from_located(stmt)),
-                ));
+                );
+                if let Some(fix) =
+                    superfluous_else_fix(checker, stmt, branch, &Rule::SuperfluousElseReturn)
+                {
+                    diagnostic.amend(fix);
+                }
+                checker.diagnostics.push(diagnostic);
             }
             return true;
         }
         if matches!(child.node, StmtKind::Break) {
             if checker.settings.rules.enabled(&Rule::SuperfluousElseBreak) {
-                checker.diagnostics.push(Diagnostic::new(
+                let mut diagnostic = Diagnostic::new(
                     violations::SuperfluousElseBreak(branch),
                     elif_else_range(stmt, checker.locator)
                         .unwrap_or_else(|| Range::from_located(stmt)),
-                ));
+                );
+                if let Some(fix) =
+                    superfluous_else_fix(checker, stmt, branch, &Rule::SuperfluousElseBreak)
+                {
+                    diagnostic.amend(fix);
+                }
+                checker.diagnostics.push(diagnostic);
             }
             return true;
         }
         if matches!(child.node, StmtKind::Raise { .. }) {
             if checker.settings.rules.enabled(&Rule::SuperfluousElseRaise) {
-                checker.diagnostics.push(Diagnostic::new(
+                let mut diagnostic = Diagnostic::new(
                     violations::SuperfluousElseRaise(branch),
                     elif_else_range(stmt, checker.locator)
                         .unwrap_or_else(|| Range::from_located(stmt)),
-                ));
+                );
+                if let Some(fix) =
+                    superfluous_else_fix(checker, stmt, branch, &Rule::SuperfluousElseRaise)
+                {
+                    diagnostic.amend(fix);
+                }
+                checker.diagnostics.push(diagnostic);
             }
             return true;
         }
@@ -263,11 +303,17 @@ fn superfluous_else_node(checker: &mut Checker, stmt: &Stmt, branch: Branch) ->
                 .rules
                 .enabled(&Rule::SuperfluousElseContinue)
             {
-                checker.diagnostics.push(Diagnostic::new(
+                let mut diagnostic = Diagnostic::new(
                     violations::SuperfluousElseContinue(branch),
                     elif_else_range(stmt, checker.locator)
                         .unwrap_or_else(|| Range::from_located(stmt)),
-                ));
+                );
+                if let Some(fix) =
+                    superfluous_else_fix(checker, stmt, branch, &Rule::SuperfluousElseContinue)
+                {
+                    diagnostic.amend(fix);
+                }
+                checker.diagnostics.push(diagnostic);
             }
             return true;
         }
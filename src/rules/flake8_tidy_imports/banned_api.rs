@@ -6,7 +6,8 @@ use serde::{Deserialize, Serialize};
 use crate::ast::types::{CallPath, Range};
 use crate::checkers::ast::Checker;
 use crate::define_violation;
-use crate::registry::Diagnostic;
+use crate::fix::Fix;
+use crate::registry::{Diagnostic, Rule};
 use crate::settings::hashable::HashableHashMap;
 use crate::violation::Violation;
 
@@ -17,41 +18,87 @@ pub type Settings = HashableHashMap<String, ApiBan>;
 pub struct ApiBan {
     /// The message to display when the API is used.
     pub msg: String,
+    /// An importable replacement to suggest in place of the banned API. When
+    /// set, `TID251` is autofixable: the banned name is replaced with this
+    /// value wherever it's referenced.
+    pub replacement: Option<String>,
 }
 
 define_violation!(
     pub struct BannedApi {
         pub name: String,
         pub message: String,
+        pub replacement: Option<String>,
     }
 );
 impl Violation for BannedApi {
     fn message(&self) -> String {
-        let BannedApi { name, message } = self;
+        let BannedApi { name, message, .. } = self;
         format!("`{name}` is banned: {message}")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let BannedApi { replacement, .. } = self;
+        if replacement.is_some() {
+            Some(|BannedApi { name, replacement, .. }| {
+                format!(
+                    "Replace `{name}` with `{}`",
+                    replacement.as_ref().unwrap()
+                )
+            })
+        } else {
+            None
+        }
+    }
+
     fn placeholder() -> Self {
         BannedApi {
             name: "...".to_string(),
             message: "...".to_string(),
+            replacement: None,
+        }
+    }
+}
+
+fn diagnostic_for_ban(
+    checker: &Checker,
+    name: String,
+    ban: &ApiBan,
+    range: Range,
+) -> Diagnostic {
+    let mut diagnostic = Diagnostic::new(
+        BannedApi {
+            name,
+            message: ban.msg.to_string(),
+            replacement: ban.replacement.clone(),
+        },
+        range,
+    );
+    if let Some(replacement) = &ban.replacement {
+        if checker.patch(&Rule::BannedApi) {
+            diagnostic.amend(Fix::replacement(
+                replacement.to_string(),
+                range.location,
+                range.end_location,
+            ));
         }
     }
+    diagnostic
 }
 
 /// TID251
 pub fn name_is_banned(
+    checker: &Checker,
     module: &str,
     name: &Alias,
     api_bans: &FxHashMap<String, ApiBan>,
 ) -> Option<Diagnostic> {
     let full_name = format!("{module}.{}", &name.node.name);
     if let Some(ban) = api_bans.get(&full_name) {
-        return Some(Diagnostic::new(
-            BannedApi {
-                name: full_name,
-                message: ban.msg.to_string(),
-            },
+        return Some(diagnostic_for_ban(
+            checker,
+            full_name,
+            ban,
             Range::from_located(name),
         ));
     }
@@ -60,6 +107,7 @@ pub fn name_is_banned(
 
 /// TID251
 pub fn name_or_parent_is_banned<T>(
+    checker: &Checker,
     located: &Located<T>,
     name: &str,
     api_bans: &FxHashMap<String, ApiBan>,
@@ -67,11 +115,10 @@ pub fn name_or_parent_is_banned<T>(
     let mut name = name;
     loop {
         if let Some(ban) = api_bans.get(name) {
-            return Some(Diagnostic::new(
-                BannedApi {
-                    name: name.to_string(),
-                    message: ban.msg.to_string(),
-                },
+            return Some(diagnostic_for_ban(
+                checker,
+                name.to_string(),
+                ban,
                 Range::from_located(located),
             ));
         }
@@ -94,13 +141,13 @@ pub fn banned_attribute_access(checker: &mut Checker, expr: &Expr) {
             .iter()
             .find(|(banned_path, ..)| call_path == banned_path.split('.').collect::<CallPath>())
     }) {
-        checker.diagnostics.push(Diagnostic::new(
-            BannedApi {
-                name: banned_path.to_string(),
-                message: ban.msg.to_string(),
-            },
+        let diagnostic = diagnostic_for_ban(
+            checker,
+            banned_path.to_string(),
+            ban,
             Range::from_located(expr),
-        ));
+        );
+        checker.diagnostics.push(diagnostic);
     }
 }
 
@@ -127,12 +174,14 @@ mod tests {
                             "cgi".to_string(),
                             ApiBan {
                                 msg: "The cgi module is deprecated.".to_string(),
+                                replacement: None,
                             },
                         ),
                         (
                             "typing.TypedDict".to_string(),
                             ApiBan {
                                 msg: "Use typing_extensions.TypedDict instead.".to_string(),
+                                replacement: None,
                             },
                         ),
                     ])
@@ -145,4 +194,27 @@ mod tests {
         insta::assert_yaml_snapshot!(diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn banned_api_replacement() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_tidy_imports/TID251_replacement.py"),
+            &Settings {
+                flake8_tidy_imports: super::super::Settings {
+                    banned_api: FxHashMap::from_iter([(
+                        "typing.TypedDict".to_string(),
+                        ApiBan {
+                            msg: "Use typing_extensions.TypedDict instead.".to_string(),
+                            replacement: Some("typing_extensions.TypedDict".to_string()),
+                        },
+                    )])
+                    .into(),
+                    ..Default::default()
+                },
+                ..Settings::for_rules(vec![Rule::BannedApi])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
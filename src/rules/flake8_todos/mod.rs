@@ -0,0 +1,24 @@
+//! Rules from [flake8-todos](https://pypi.org/project/flake8-todos/0.1.5/).
+pub(crate) mod rules;
+pub mod settings;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::linter::test_path;
+    use crate::registry::Rule;
+    use crate::settings::Settings;
+
+    #[test]
+    fn defaults() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_todos/TD.py"),
+            &Settings::for_rules(vec![Rule::InvalidTodoTag, Rule::MissingTodoAuthor]),
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+}
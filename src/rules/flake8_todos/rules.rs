@@ -0,0 +1,52 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustpython_ast::Location;
+
+use crate::ast::types::Range;
+use crate::registry::{Diagnostic, Rule};
+use crate::settings::Settings;
+use crate::violations;
+
+/// Matches a leading to-do-style comment, e.g. `# TODO(charlie): fix this` or `# FIXME: ...`.
+static TODO_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^#\s*(?P<tag>[A-Za-z]+)\s*(?:\((?P<author>[^)]*)\))?\s*:").unwrap()
+});
+
+/// TD001, TD002
+pub fn todos(line: &str, start: Location, end: Location, settings: &Settings) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    let Some(captures) = TODO_LINE_REGEX.captures(line.trim_start()) else {
+        return diagnostics;
+    };
+    let tag = captures.name("tag").unwrap().as_str();
+
+    // Only comments whose tag matches one of the configured tags (case-insensitively) are
+    // treated as to-do comments; anything else is an ordinary comment.
+    if !settings
+        .flake8_todos
+        .tags
+        .iter()
+        .any(|valid_tag| valid_tag.eq_ignore_ascii_case(tag))
+    {
+        return diagnostics;
+    }
+
+    if settings.rules.enabled(&Rule::InvalidTodoTag)
+        && !settings.flake8_todos.tags.iter().any(|valid_tag| valid_tag == tag)
+    {
+        diagnostics.push(Diagnostic::new(
+            violations::InvalidTodoTag(tag.to_string()),
+            Range::new(start, end),
+        ));
+    }
+
+    if settings.rules.enabled(&Rule::MissingTodoAuthor) && captures.name("author").is_none() {
+        diagnostics.push(Diagnostic::new(
+            violations::MissingTodoAuthor,
+            Range::new(start, end),
+        ));
+    }
+
+    diagnostics
+}
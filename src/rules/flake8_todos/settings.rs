@@ -0,0 +1,54 @@
+//! Settings for the `flake8-todos` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8TodosOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"["TODO"]"#,
+        value_type = "Vec<String>",
+        example = "tags = [\"TODO\", \"FIXME\"]"
+    )]
+    /// The set of tags considered valid for a "to-do" comment, e.g. `TODO` or `FIXME`.
+    /// Tags are matched case-sensitively; a differently-cased tag (e.g. `todo`) is
+    /// still recognized as a to-do comment, but flagged for its capitalization.
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub tags: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            tags: vec!["TODO".to_string()],
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            tags: options.tags.unwrap_or_else(|| vec!["TODO".to_string()]),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            tags: Some(settings.tags),
+        }
+    }
+}
@@ -39,6 +39,7 @@ mod tests {
             &settings::Settings {
                 flake8_unused_arguments: super::settings::Settings {
                     ignore_variadic_names: true,
+                    ..super::settings::Settings::default()
                 },
                 ..settings::Settings::for_rules(vec![
                     Rule::UnusedFunctionArgument,
@@ -60,6 +61,7 @@ mod tests {
             &settings::Settings {
                 flake8_unused_arguments: super::settings::Settings {
                     ignore_variadic_names: false,
+                    ..super::settings::Settings::default()
                 },
                 ..settings::Settings::for_rules(vec![
                     Rule::UnusedFunctionArgument,
@@ -73,4 +75,36 @@ mod tests {
         insta::assert_yaml_snapshot!(diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn ignore_lambdas() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_unused_arguments/ignore_lambdas.py"),
+            &settings::Settings {
+                flake8_unused_arguments: super::settings::Settings {
+                    ignore_lambdas: true,
+                    ..super::settings::Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::UnusedLambdaArgument)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn enforce_lambdas() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/flake8_unused_arguments/ignore_lambdas.py"),
+            &settings::Settings {
+                flake8_unused_arguments: super::settings::Settings {
+                    ignore_lambdas: false,
+                    ..super::settings::Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::UnusedLambdaArgument)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
@@ -21,6 +21,7 @@ fn function(
     bindings: &[Binding],
     dummy_variable_rgx: &Regex,
     ignore_variadic_names: bool,
+    allow_unused_underscore_args: bool,
 ) -> Vec<Diagnostic> {
     let mut diagnostics: Vec<Diagnostic> = vec![];
     for arg in args
@@ -46,6 +47,7 @@ fn function(
             if binding.used.is_none()
                 && matches!(binding.kind, BindingKind::Argument)
                 && !dummy_variable_rgx.is_match(arg.node.arg.as_str())
+                && !(allow_unused_underscore_args && arg.node.arg.starts_with('_'))
             {
                 diagnostics.push(Diagnostic::new(
                     argumentable.check_for(arg.node.arg.to_string()),
@@ -65,6 +67,7 @@ fn method(
     bindings: &[Binding],
     dummy_variable_rgx: &Regex,
     ignore_variadic_names: bool,
+    allow_unused_underscore_args: bool,
 ) -> Vec<Diagnostic> {
     let mut diagnostics: Vec<Diagnostic> = vec![];
     for arg in args
@@ -91,6 +94,7 @@ fn method(
             if binding.used.is_none()
                 && matches!(binding.kind, BindingKind::Argument)
                 && !dummy_variable_rgx.is_match(arg.node.arg.as_str())
+                && !(allow_unused_underscore_args && arg.node.arg.starts_with('_'))
             {
                 diagnostics.push(Diagnostic::new(
                     argumentable.check_for(arg.node.arg.to_string()),
@@ -142,6 +146,10 @@ pub fn unused_arguments(
                                 .settings
                                 .flake8_unused_arguments
                                 .ignore_variadic_names,
+                            checker
+                                .settings
+                                .flake8_unused_arguments
+                                .allow_unused_underscore_args,
                         )
                     } else {
                         vec![]
@@ -171,6 +179,10 @@ pub fn unused_arguments(
                                 .settings
                                 .flake8_unused_arguments
                                 .ignore_variadic_names,
+                            checker
+                                .settings
+                                .flake8_unused_arguments
+                                .allow_unused_underscore_args,
                         )
                     } else {
                         vec![]
@@ -200,6 +212,10 @@ pub fn unused_arguments(
                                 .settings
                                 .flake8_unused_arguments
                                 .ignore_variadic_names,
+                            checker
+                                .settings
+                                .flake8_unused_arguments
+                                .allow_unused_underscore_args,
                         )
                     } else {
                         vec![]
@@ -229,6 +245,10 @@ pub fn unused_arguments(
                                 .settings
                                 .flake8_unused_arguments
                                 .ignore_variadic_names,
+                            checker
+                                .settings
+                                .flake8_unused_arguments
+                                .allow_unused_underscore_args,
                         )
                     } else {
                         vec![]
@@ -241,6 +261,7 @@ pub fn unused_arguments(
                 .settings
                 .rules
                 .enabled(Argumentable::Lambda.rule_code())
+                && !checker.settings.flake8_unused_arguments.ignore_lambdas
             {
                 function(
                     &Argumentable::Lambda,
@@ -252,6 +273,10 @@ pub fn unused_arguments(
                         .settings
                         .flake8_unused_arguments
                         .ignore_variadic_names,
+                    checker
+                        .settings
+                        .flake8_unused_arguments
+                        .allow_unused_underscore_args,
                 )
             } else {
                 vec![]
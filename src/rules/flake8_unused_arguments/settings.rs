@@ -20,17 +20,40 @@ pub struct Options {
     )]
     /// Whether to allow unused variadic arguments, like `*args` and `**kwargs`.
     pub ignore_variadic_names: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "allow-unused-underscore-args = true"
+    )]
+    /// Whether to allow unused arguments whose names start with an underscore,
+    /// regardless of whether they match `dummy-variable-rgx`.
+    pub allow_unused_underscore_args: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "ignore-lambdas = true"
+    )]
+    /// Whether to skip lambda expressions when checking for unused arguments,
+    /// since callback-style APIs often require accepting arguments that a
+    /// particular callback has no use for.
+    pub ignore_lambdas: Option<bool>,
 }
 
 #[derive(Debug, Default, Hash)]
 pub struct Settings {
     pub ignore_variadic_names: bool,
+    pub allow_unused_underscore_args: bool,
+    pub ignore_lambdas: bool,
 }
 
 impl From<Options> for Settings {
     fn from(options: Options) -> Self {
         Self {
             ignore_variadic_names: options.ignore_variadic_names.unwrap_or_default(),
+            allow_unused_underscore_args: options
+                .allow_unused_underscore_args
+                .unwrap_or_default(),
+            ignore_lambdas: options.ignore_lambdas.unwrap_or_default(),
         }
     }
 }
@@ -39,6 +62,8 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             ignore_variadic_names: Some(settings.ignore_variadic_names),
+            allow_unused_underscore_args: Some(settings.allow_unused_underscore_args),
+            ignore_lambdas: Some(settings.ignore_lambdas),
         }
     }
 }
@@ -6,6 +6,7 @@ use log::debug;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::python::site_packages;
 use crate::python::sys::KNOWN_STANDARD_LIBRARY;
 
 #[derive(
@@ -30,9 +31,12 @@ enum Reason<'a> {
     KnownStandardLibrary,
     SamePackage,
     SourceMatch(&'a Path),
+    InstalledPackage,
     NoMatch,
+    NotInstalled,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn categorize(
     module_base: &str,
     level: Option<&usize>,
@@ -41,6 +45,7 @@ pub fn categorize(
     known_first_party: &BTreeSet<String>,
     known_third_party: &BTreeSet<String>,
     extra_standard_library: &BTreeSet<String>,
+    detect_installed_packages: bool,
 ) -> ImportType {
     let (import_type, reason) = {
         if level.map_or(false, |level| *level > 0) {
@@ -59,6 +64,15 @@ pub fn categorize(
             (ImportType::FirstParty, Reason::SamePackage)
         } else if let Some(src) = match_sources(src, module_base) {
             (ImportType::FirstParty, Reason::SourceMatch(src))
+        } else if let Some(installed) = detect_installed_packages
+            .then(site_packages::detect_virtual_env)
+            .flatten()
+        {
+            if site_packages::installed_packages(&installed).contains(module_base) {
+                (ImportType::ThirdParty, Reason::InstalledPackage)
+            } else {
+                (ImportType::FirstParty, Reason::NotInstalled)
+            }
         } else {
             (ImportType::ThirdParty, Reason::NoMatch)
         }
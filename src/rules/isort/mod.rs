@@ -304,6 +304,7 @@ fn normalize_imports(imports: Vec<AnnotatedImport>, combine_as_imports: bool) ->
     block
 }
 
+#[allow(clippy::too_many_arguments)]
 fn categorize_imports<'a>(
     block: ImportBlock<'a>,
     src: &[PathBuf],
@@ -311,6 +312,7 @@ fn categorize_imports<'a>(
     known_first_party: &BTreeSet<String>,
     known_third_party: &BTreeSet<String>,
     extra_standard_library: &BTreeSet<String>,
+    detect_installed_packages: bool,
 ) -> BTreeMap<ImportType, ImportBlock<'a>> {
     let mut block_by_type: BTreeMap<ImportType, ImportBlock> = BTreeMap::default();
     // Categorize `StmtKind::Import`.
@@ -323,6 +325,7 @@ fn categorize_imports<'a>(
             known_first_party,
             known_third_party,
             extra_standard_library,
+            detect_installed_packages,
         );
         block_by_type
             .entry(import_type)
@@ -340,6 +343,7 @@ fn categorize_imports<'a>(
             known_first_party,
             known_third_party,
             extra_standard_library,
+            detect_installed_packages,
         );
         block_by_type
             .entry(classification)
@@ -357,6 +361,7 @@ fn categorize_imports<'a>(
             known_first_party,
             known_third_party,
             extra_standard_library,
+            detect_installed_packages,
         );
         block_by_type
             .entry(classification)
@@ -374,6 +379,7 @@ fn categorize_imports<'a>(
             known_first_party,
             known_third_party,
             extra_standard_library,
+            detect_installed_packages,
         );
         block_by_type
             .entry(classification)
@@ -562,6 +568,7 @@ pub fn format_imports(
     src: &[PathBuf],
     package: Option<&Path>,
     combine_as_imports: bool,
+    detect_installed_packages: bool,
     extra_standard_library: &BTreeSet<String>,
     force_single_line: bool,
     force_sort_within_sections: bool,
@@ -591,6 +598,7 @@ pub fn format_imports(
         known_first_party,
         known_third_party,
         extra_standard_library,
+        detect_installed_packages,
     );
 
     let mut output = RopeBuilder::new();
@@ -75,6 +75,7 @@ pub fn organize_imports(
         &settings.src,
         package,
         settings.isort.combine_as_imports,
+        settings.isort.detect_installed_packages,
         &settings.isort.extra_standard_library,
         settings.isort.force_single_line,
         settings.isort.force_sort_within_sections,
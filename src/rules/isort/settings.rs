@@ -138,6 +138,27 @@ pub struct Options {
     /// A list of modules to consider third-party, regardless of whether they
     /// can be identified as such via introspection of the local filesystem.
     pub known_third_party: Option<Vec<String>>,
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            detect-installed-packages = true
+        "#
+    )]
+    /// Whether to enumerate the distributions installed in the current
+    /// Python environment (as reported by the `VIRTUAL_ENV` environment
+    /// variable) to more accurately determine which imports are
+    /// third-party.
+    ///
+    /// When disabled (the default), an import that isn't recognized as
+    /// first-party, standard-library, or explicitly configured via
+    /// `known-first-party` or `known-third-party` is assumed to be
+    /// third-party. When enabled, and `VIRTUAL_ENV` points to an environment
+    /// whose `site-packages` directory can be found, such an import is only
+    /// classified as third-party if it matches an installed distribution;
+    /// otherwise, it's assumed to be first-party. Without such an
+    /// environment, enabling the option changes nothing.
+    pub detect_installed_packages: Option<bool>,
     #[option(
         default = r#"[]"#,
         value_type = "Vec<String>",
@@ -226,6 +247,7 @@ pub struct Settings {
     pub force_wrap_aliases: bool,
     pub known_first_party: BTreeSet<String>,
     pub known_third_party: BTreeSet<String>,
+    pub detect_installed_packages: bool,
     pub order_by_type: bool,
     pub relative_imports_order: RelatveImportsOrder,
     pub single_line_exclusions: BTreeSet<String>,
@@ -247,6 +269,7 @@ impl Default for Settings {
             force_wrap_aliases: false,
             known_first_party: BTreeSet::new(),
             known_third_party: BTreeSet::new(),
+            detect_installed_packages: false,
             order_by_type: true,
             relative_imports_order: RelatveImportsOrder::default(),
             single_line_exclusions: BTreeSet::new(),
@@ -272,6 +295,7 @@ impl From<Options> for Settings {
             force_wrap_aliases: options.force_wrap_aliases.unwrap_or(false),
             known_first_party: BTreeSet::from_iter(options.known_first_party.unwrap_or_default()),
             known_third_party: BTreeSet::from_iter(options.known_third_party.unwrap_or_default()),
+            detect_installed_packages: options.detect_installed_packages.unwrap_or(false),
             order_by_type: options.order_by_type.unwrap_or(true),
             relative_imports_order: options.relative_imports_order.unwrap_or_default(),
             single_line_exclusions: BTreeSet::from_iter(
@@ -297,6 +321,7 @@ impl From<Settings> for Options {
             force_wrap_aliases: Some(settings.force_wrap_aliases),
             known_first_party: Some(settings.known_first_party.into_iter().collect()),
             known_third_party: Some(settings.known_third_party.into_iter().collect()),
+            detect_installed_packages: Some(settings.detect_installed_packages),
             order_by_type: Some(settings.order_by_type),
             relative_imports_order: Some(settings.relative_imports_order),
             single_line_exclusions: Some(settings.single_line_exclusions.into_iter().collect()),
@@ -11,16 +11,19 @@ pub mod flake8_comprehensions;
 pub mod flake8_datetimez;
 pub mod flake8_debugger;
 pub mod flake8_errmsg;
+pub mod flake8_fixme;
 pub mod flake8_implicit_str_concat;
 pub mod flake8_import_conventions;
 pub mod flake8_no_pep420;
 pub mod flake8_pie;
 pub mod flake8_print;
+pub mod flake8_pyi;
 pub mod flake8_pytest_style;
 pub mod flake8_quotes;
 pub mod flake8_return;
 pub mod flake8_simplify;
 pub mod flake8_tidy_imports;
+pub mod flake8_todos;
 pub mod flake8_unused_arguments;
 pub mod isort;
 pub mod mccabe;
@@ -1,5 +1,8 @@
 use rustpython_ast::{Expr, ExprKind};
 
+use crate::ast::types::BindingKind;
+use crate::checkers::ast::Checker;
+
 /// Return `true` if an `Expr` _could_ be a `DataFrame`. This rules out
 /// obviously-wrong cases, like constants and literals.
 pub fn is_dataframe_candidate(expr: &Expr) -> bool {
@@ -16,3 +19,47 @@ pub fn is_dataframe_candidate(expr: &Expr) -> bool {
             | ExprKind::GeneratorExp { .. }
     )
 }
+
+/// Return `true` if `value` looks like a `DataFrame`-like receiver, and isn't bound to
+/// something that's obviously unrelated (an import, a class, a function, etc.).
+///
+/// If `pandas_import_only` is `true`, a name bound by an import of `pandas` itself (e.g.,
+/// `import pandas as pd`) is also considered a valid receiver; a name bound by any other
+/// import is rejected either way. This mirrors the (slightly more permissive) heuristic
+/// used by the call-based pandas-vet
+/// rules (e.g., `.isnull()`, `.stack()`), as opposed to the attribute-based rules
+/// (e.g., `.values`, `.at`).
+pub fn is_valid_pandas_receiver(
+    checker: &Checker,
+    value: &Expr,
+    pandas_import_only: bool,
+) -> bool {
+    if !is_dataframe_candidate(value) {
+        return false;
+    }
+
+    let ExprKind::Name { id, .. } = &value.node else {
+        return true;
+    };
+
+    let is_irrelevant_binding = checker.find_binding(id).map_or(true, |binding| {
+        if pandas_import_only {
+            if let BindingKind::Importation(.., module) = &binding.kind {
+                return module != &"pandas";
+            }
+        }
+        matches!(
+            binding.kind,
+            BindingKind::Builtin
+                | BindingKind::ClassDefinition
+                | BindingKind::FunctionDefinition
+                | BindingKind::Export(..)
+                | BindingKind::FutureImportation
+                | BindingKind::StarImportation(..)
+                | BindingKind::Importation(..)
+                | BindingKind::FromImportation(..)
+                | BindingKind::SubmoduleImportation(..)
+        )
+    });
+    !is_irrelevant_binding
+}
@@ -242,6 +242,15 @@ mod tests {
         import pandas as pd
         df = pd.DataFrame()
     "#, &[Rule::DfIsABadVariableName]; "PD901_fail_df_var")]
+    #[test_case(r#"
+        import pandas as pd
+        x = pd.DataFrame()
+        is_boolean = x['col'].nunique() == 2
+    "#, &[Rule::UseOfDotNunique]; "PD101_fail")]
+    #[test_case(r#"
+        import pandas as pd
+        nunique = 1
+    "#, &[]; "PD101_pass_node_name")]
     fn test_pandas_vet(code: &str, expected: &[Rule]) -> Result<()> {
         rule_code(code, expected)?;
         Ok(())
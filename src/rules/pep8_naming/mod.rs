@@ -12,7 +12,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::Rule;
-    use crate::settings;
+    use crate::settings::{self, Settings};
 
     #[test_case(Rule::InvalidClassName, Path::new("N801.py"); "N801")]
     #[test_case(Rule::InvalidFunctionName, Path::new("N802.py"); "N802")]
@@ -40,4 +40,33 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn extend_classmethod_decorators() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pep8_naming/N805.py"),
+            &Settings {
+                pep8_naming: super::settings::Settings {
+                    classmethod_decorators: vec![
+                        "classmethod".to_string(),
+                        "pydantic.validator".to_string(),
+                    ],
+                    ..super::settings::Settings::default()
+                },
+                ..Settings::for_rule(Rule::InvalidFirstArgumentNameForMethod)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_module_name() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pep8_naming/N999/invalid-module-name.py"),
+            &settings::Settings::for_rule(Rule::InvalidModuleName),
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use rustpython_ast::{Arg, Arguments, Expr, ExprKind, Stmt};
 
 use super::helpers;
@@ -301,3 +303,48 @@ pub fn error_suffix_on_exception_name(
         identifier_range(class_def, locator),
     ))
 }
+
+/// N999
+pub fn invalid_module_name(path: &Path, ignore_names: &[String]) -> Option<Diagnostic> {
+    if path.extension().map_or(true, |ext| ext != "py") {
+        return None;
+    }
+
+    let stem = path.file_stem()?.to_str()?;
+
+    // For `__init__.py` and `__main__.py`, the "module" is really the
+    // enclosing package, so validate the directory name instead of the
+    // (fixed) filename.
+    let module_name = if stem == "__init__" || stem == "__main__" {
+        path.parent().and_then(Path::file_name).and_then(std::ffi::OsStr::to_str)?
+    } else {
+        stem
+    };
+
+    if ignore_names.iter().any(|ignore_name| ignore_name == module_name) {
+        return None;
+    }
+
+    if is_valid_module_name(module_name) {
+        return None;
+    }
+
+    Some(Diagnostic::new(
+        violations::InvalidModuleName(module_name.to_string()),
+        Range::default(),
+    ))
+}
+
+/// Return `true` if `module_name` is a valid module name: all-lowercase,
+/// with no hyphens, and a valid Python identifier.
+fn is_valid_module_name(module_name: &str) -> bool {
+    if module_name.is_empty() {
+        return false;
+    }
+    let mut chars = module_name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_lowercase() || first == '_')
+        && chars.all(|c| c.is_lowercase() || c.is_ascii_digit() || c == '_')
+}
@@ -52,7 +52,9 @@ pub struct Options {
     /// A list of decorators that, when applied to a method, indicate that the
     /// method should be treated as a class method. For example, Ruff will
     /// expect that any method decorated by a decorator in this list takes a
-    /// `cls` argument as its first argument.
+    /// `cls` argument as its first argument. This setting is also consulted
+    /// by other rules (e.g. `flake8-annotations`, `pydocstyle`) that need to
+    /// know whether a method is a class method.
     pub classmethod_decorators: Option<Vec<String>>,
     #[option(
         default = r#"["staticmethod"]"#,
@@ -65,7 +67,9 @@ pub struct Options {
     /// A list of decorators that, when applied to a method, indicate that the
     /// method should be treated as a static method. For example, Ruff will
     /// expect that any method decorated by a decorator in this list has no
-    /// `self` or `cls` argument.
+    /// `self` or `cls` argument. This setting is also consulted by other
+    /// rules (e.g. `flake8-annotations`, `pydocstyle`) that need to know
+    /// whether a method is a static method.
     pub staticmethod_decorators: Option<Vec<String>>,
 }
 
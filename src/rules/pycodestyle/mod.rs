@@ -9,15 +9,21 @@ mod tests {
     use anyhow::Result;
     use test_case::test_case;
 
-    use super::settings::Settings;
+    use super::settings::{LineBreakStyle, Settings};
     use crate::linter::test_path;
     use crate::registry::Rule;
     use crate::settings;
 
     #[test_case(Rule::MultipleImportsOnOneLine, Path::new("E40.py"))]
+    #[test_case(Rule::MultipleImportsOnOneLine, Path::new("E401_1.py"))]
     #[test_case(Rule::ModuleImportNotAtTopOfFile, Path::new("E40.py"))]
     #[test_case(Rule::ModuleImportNotAtTopOfFile, Path::new("E402.py"))]
     #[test_case(Rule::LineTooLong, Path::new("E501.py"))]
+    #[test_case(Rule::RedundantBackslash, Path::new("E502.py"))]
+    #[test_case(Rule::MultipleStatementsOnOneLineColon, Path::new("E701.py"))]
+    #[test_case(Rule::MultipleStatementsOnOneLineSemicolon, Path::new("E702.py"))]
+    #[test_case(Rule::UselessSemicolon, Path::new("E703.py"))]
+    #[test_case(Rule::StatementOnOneLineDef, Path::new("E704.py"))]
     #[test_case(Rule::NoneComparison, Path::new("E711.py"))]
     #[test_case(Rule::TrueFalseComparison, Path::new("E712.py"))]
     #[test_case(Rule::NotInTest, Path::new("E713.py"))]
@@ -80,6 +86,42 @@ mod tests {
         Ok(())
     }
 
+    #[test_case(false)]
+    #[test_case(true)]
+    fn ignore_overlong_urls(ignore_overlong_urls: bool) -> Result<()> {
+        let snapshot = format!("ignore_overlong_urls_{ignore_overlong_urls}");
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/E501_2.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    ignore_overlong_urls,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::LineTooLong)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test_case(false)]
+    #[test_case(true)]
+    fn ignore_overlong_noqa(ignore_overlong_noqa: bool) -> Result<()> {
+        let snapshot = format!("ignore_overlong_noqa_{ignore_overlong_noqa}");
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/E501_2.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    ignore_overlong_noqa,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::LineTooLong)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn max_doc_length() -> Result<()> {
         let diagnostics = test_path(
@@ -95,4 +137,50 @@ mod tests {
         insta::assert_yaml_snapshot!(diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn line_break_before_binary_operator() -> Result<()> {
+        // `W503` only fires when the preferred style is to break *after* the operator, since
+        // that's the only way a break-before can be considered a violation.
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/W503_W504.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    line_break_style: LineBreakStyle::After,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::LineBreakBeforeBinaryOperator)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn line_break_after_binary_operator() -> Result<()> {
+        // `W504` fires under the default (and PEP 8-recommended) `line-break-style = "before"`.
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/W503_W504.py"),
+            &settings::Settings::for_rule(Rule::LineBreakAfterBinaryOperator),
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_doc_lines() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/W505.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    max_doc_length: Some(50),
+                    wrap_doc_lines: true,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::DocLineTooLong)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
@@ -4,6 +4,7 @@ use regex::Regex;
 use rustc_hash::FxHashMap;
 use rustpython_ast::{Arguments, Constant, Excepthandler, Location, Stmt, StmtKind, Unaryop};
 use rustpython_parser::ast::{Cmpop, Expr, ExprKind};
+use rustpython_parser::lexer::{LexResult, Tok};
 
 use crate::ast::helpers;
 use crate::ast::helpers::{
@@ -13,24 +14,47 @@ use crate::ast::types::Range;
 use crate::ast::whitespace::leading_space;
 use crate::checkers::ast::Checker;
 use crate::fix::Fix;
+use crate::noqa::{extract_noqa_directive, Directive};
 use crate::registry::Diagnostic;
+use crate::rules::pycodestyle::settings::LineBreakStyle;
 use crate::settings::Settings;
 use crate::source_code::{Generator, Locator, Stylist};
+use crate::str::StrLiteral;
 use crate::violations;
 
 static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^https?://\S+$").unwrap());
 
+/// Return `true` if `line` only overflows `limit` because of a trailing `# noqa` comment,
+/// i.e. the code preceding the comment already fits within the limit on its own.
+fn is_overlong_due_to_noqa(line: &str, limit: usize) -> bool {
+    let (spaces, noqa_start) = match extract_noqa_directive(line) {
+        Directive::None => return false,
+        Directive::All(spaces, noqa_start, _) => (spaces, noqa_start),
+        Directive::Codes(spaces, noqa_start, _, _) => (spaces, noqa_start),
+    };
+    // `spaces` is a char count, but `noqa_start` is a byte offset into `line`; mixing the two
+    // units (e.g. subtracting one from the other) breaks on non-ASCII code preceding `# noqa`.
+    line[..noqa_start].chars().count() - spaces <= limit
+}
+
+#[allow(clippy::too_many_arguments)]
 fn is_overlong(
     line: &str,
     line_length: usize,
     limit: usize,
     ignore_overlong_task_comments: bool,
+    ignore_overlong_urls: bool,
+    ignore_overlong_noqa: bool,
     task_tags: &[String],
 ) -> bool {
     if line_length <= limit {
         return false;
     }
 
+    if ignore_overlong_noqa && is_overlong_due_to_noqa(line, limit) {
+        return false;
+    }
+
     let mut chunks = line.split_whitespace();
     let (Some(first), Some(second)) = (chunks.next(), chunks.next()) else {
         // Single word / no printable chars - no way to make the line shorter
@@ -47,7 +71,7 @@ fn is_overlong(
 
         // Do not enforce the line length for commented lines that end with a URL
         // or contain only a single word.
-        if chunks.last().map_or(true, |c| URL_REGEX.is_match(c)) {
+        if ignore_overlong_urls && chunks.last().map_or(true, |c| URL_REGEX.is_match(c)) {
             return false;
         }
     }
@@ -64,6 +88,8 @@ pub fn line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<D
         line_length,
         limit,
         settings.pycodestyle.ignore_overlong_task_comments,
+        settings.pycodestyle.ignore_overlong_urls,
+        settings.pycodestyle.ignore_overlong_noqa,
         &settings.task_tags,
     ) {
         Some(Diagnostic::new(
@@ -78,8 +104,258 @@ pub fn line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<D
     }
 }
 
+/// E502
+pub fn redundant_backslash(
+    locator: &Locator,
+    tokens: &[LexResult],
+    autofix: bool,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    let mut depth = 0u32;
+    let mut comment_since_newline = false;
+    let mut prev_end: Option<Location> = None;
+
+    for (start, tok, end) in tokens.iter().flatten() {
+        if let Some(prev_end) = prev_end {
+            if start.row() > prev_end.row() && depth > 0 && !comment_since_newline {
+                let line = locator.slice_source_code_range(&Range::new(
+                    Location::new(prev_end.row(), 0),
+                    Location::new(prev_end.row() + 1, 0),
+                ));
+                let trimmed = line.trim_end_matches(['\n', '\r']);
+                if let Some(before_backslash) = trimmed.strip_suffix('\\') {
+                    let backslash_column = before_backslash.chars().count();
+                    let stripped_column = before_backslash.trim_end().chars().count();
+                    let location = Location::new(prev_end.row(), backslash_column);
+                    let end_location = Location::new(prev_end.row(), backslash_column + 1);
+                    let mut diagnostic = Diagnostic::new(
+                        violations::RedundantBackslash,
+                        Range::new(location, end_location),
+                    );
+                    if autofix {
+                        diagnostic.amend(Fix::deletion(
+                            Location::new(prev_end.row(), stripped_column),
+                            end_location,
+                        ));
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        match tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth = depth.saturating_sub(1),
+            Tok::Comment(_) => comment_since_newline = true,
+            Tok::Newline | Tok::NonLogicalNewline => comment_since_newline = false,
+            _ => {}
+        }
+
+        prev_end = Some(*end);
+    }
+
+    diagnostics
+}
+
+/// Returns `true` if `tok` is a keyword that starts a compound statement whose
+/// header ends in a colon (`if`, `for`, `class`, ...). `def` is handled
+/// separately by the caller, since a one-liner `def` is E704 rather than
+/// E701.
+fn starts_compound_statement(tok: &Tok) -> bool {
+    matches!(
+        tok,
+        Tok::If
+            | Tok::Elif
+            | Tok::Else
+            | Tok::While
+            | Tok::For
+            | Tok::Try
+            | Tok::Except
+            | Tok::Finally
+            | Tok::With
+            | Tok::Class
+    )
+}
+
+/// E701, E702, E703, E704
+pub fn compound_statements(
+    locator: &Locator,
+    tokens: &[LexResult],
+    autofix_semicolons: bool,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    let toks: Vec<(Location, &Tok, Location)> = tokens
+        .iter()
+        .flatten()
+        .map(|(start, tok, end)| (*start, tok, *end))
+        .collect();
+
+    let mut depth = 0u32;
+    // Whether the current logical line opened with a keyword that makes a
+    // trailing colon on the same line significant (as opposed to, say, a
+    // slice, dict literal, or variable annotation).
+    let mut compound_kind: Option<bool> = None; // Some(true) => `def`, Some(false) => other keyword.
+    let mut at_line_start = true;
+    let mut colon_seen = false;
+
+    for (i, &(start, tok, end)) in toks.iter().enumerate() {
+        match tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        if depth == 0 {
+            if at_line_start {
+                match tok {
+                    Tok::Indent | Tok::Dedent | Tok::Comment(_) | Tok::NonLogicalNewline => {}
+                    Tok::Async => {
+                        // Wait for the next token to decide between `def` (E704)
+                        // and `for`/`with` (E701).
+                    }
+                    Tok::Def => {
+                        compound_kind = Some(true);
+                        at_line_start = false;
+                        colon_seen = false;
+                    }
+                    _ if starts_compound_statement(tok) => {
+                        compound_kind = Some(false);
+                        at_line_start = false;
+                        colon_seen = false;
+                    }
+                    _ => {
+                        compound_kind = None;
+                        at_line_start = false;
+                        colon_seen = false;
+                    }
+                }
+            } else if let Tok::Colon = tok {
+                if !colon_seen {
+                    if let Some(is_def) = compound_kind {
+                        colon_seen = true;
+                        let has_content_after = toks[i + 1..]
+                            .iter()
+                            .find(|(_, tok, _)| !matches!(tok, Tok::Comment(_)))
+                            .map_or(false, |(_, tok, _)| !matches!(tok, Tok::Newline));
+                        if has_content_after {
+                            // Not autofixed: splitting a compound statement's body onto its own
+                            // line can require re-indenting arbitrary nested logic, which is out
+                            // of scope for a token-level fix.
+                            let diagnostic = if is_def {
+                                Diagnostic::new(
+                                    violations::StatementOnOneLineDef,
+                                    Range::new(start, end),
+                                )
+                            } else {
+                                Diagnostic::new(
+                                    violations::MultipleStatementsOnOneLineColon,
+                                    Range::new(start, end),
+                                )
+                            };
+                            diagnostics.push(diagnostic);
+                        }
+                    }
+                }
+            } else if let Tok::Semi = tok {
+                let next = toks[i + 1..]
+                    .iter()
+                    .find(|(_, tok, _)| !matches!(tok, Tok::Comment(_)));
+                let ends_line = next.map_or(true, |(_, tok, _)| matches!(tok, Tok::Newline));
+                if ends_line {
+                    let mut diagnostic =
+                        Diagnostic::new(violations::UselessSemicolon, Range::new(start, end));
+                    if autofix_semicolons {
+                        diagnostic.amend(Fix::deletion(start, end));
+                    }
+                    diagnostics.push(diagnostic);
+                } else {
+                    let mut diagnostic = Diagnostic::new(
+                        violations::MultipleStatementsOnOneLineSemicolon,
+                        Range::new(start, end),
+                    );
+                    if autofix_semicolons && !colon_seen {
+                        // If a compound statement's colon precedes this semicolon on the same
+                        // line (e.g. `if cond: x = 1; y = 2`), the line's own indentation is the
+                        // *outer* scope's indentation, not the body's. Splitting onto its own
+                        // line would unindent `y = 2` out of the conditional, so leave this case
+                        // to the (declined) colon-branch diagnostic above.
+                        let line = locator.slice_source_code_range(&Range::new(
+                            Location::new(start.row(), 0),
+                            Location::new(start.row() + 1, 0),
+                        ));
+                        let indent = leading_space(&line);
+                        let next_start = next.map_or(end, |&(start, ..)| start);
+                        diagnostic.amend(Fix::replacement(
+                            format!("\n{indent}"),
+                            start,
+                            next_start,
+                        ));
+                    }
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        if matches!(tok, Tok::Newline) {
+            at_line_start = true;
+            compound_kind = None;
+            colon_seen = false;
+        }
+    }
+
+    diagnostics
+}
+
+/// Re-wraps a standalone comment onto two physical lines so that the first fits within `limit`
+/// characters. This is deliberately limited to standalone comments: re-wrapping multi-line
+/// docstrings while preserving list items, code blocks, and section headers is out of scope here.
+fn wrap_standalone_comment(line: &str, limit: usize) -> Option<String> {
+    let indent_end = line.len() - line.trim_start().len();
+    let indent = &line[..indent_end];
+    let text = line[indent_end..].strip_prefix('#')?.trim_start();
+    if text.is_empty() {
+        return None;
+    }
+
+    let prefix = format!("{indent}# ");
+    let budget = limit.saturating_sub(prefix.chars().count());
+    if budget == 0 {
+        return None;
+    }
+
+    let mut words = text.split_whitespace();
+    let mut first_line = words.next()?.to_string();
+    let mut remaining_words = vec![];
+    for word in words {
+        if remaining_words.is_empty()
+            && first_line.chars().count() + 1 + word.chars().count() <= budget
+        {
+            first_line.push(' ');
+            first_line.push_str(word);
+        } else {
+            remaining_words.push(word);
+        }
+    }
+    if remaining_words.is_empty() {
+        // Wrapping wouldn't shorten the flagged line.
+        return None;
+    }
+
+    Some(format!(
+        "{prefix}{first_line}\n{prefix}{}",
+        remaining_words.join(" ")
+    ))
+}
+
 /// W505
-pub fn doc_line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<Diagnostic> {
+pub fn doc_line_too_long(
+    lineno: usize,
+    line: &str,
+    settings: &Settings,
+    autofix: bool,
+) -> Option<Diagnostic> {
     let Some(limit) = settings.pycodestyle.max_doc_length else {
         return None;
     };
@@ -90,15 +366,27 @@ pub fn doc_line_too_long(lineno: usize, line: &str, settings: &Settings) -> Opti
         line_length,
         limit,
         settings.pycodestyle.ignore_overlong_task_comments,
+        settings.pycodestyle.ignore_overlong_urls,
+        settings.pycodestyle.ignore_overlong_noqa,
         &settings.task_tags,
     ) {
-        Some(Diagnostic::new(
+        let mut diagnostic = Diagnostic::new(
             violations::DocLineTooLong(line_length, limit),
             Range::new(
                 Location::new(lineno + 1, limit),
                 Location::new(lineno + 1, line_length),
             ),
-        ))
+        );
+        if autofix && settings.pycodestyle.wrap_doc_lines {
+            if let Some(replacement) = wrap_standalone_comment(line, limit) {
+                diagnostic.amend(Fix::replacement(
+                    replacement,
+                    Location::new(lineno + 1, 0),
+                    Location::new(lineno + 1, line_length),
+                ));
+            }
+        }
+        Some(diagnostic)
     } else {
         None
     }
@@ -563,15 +851,10 @@ const VALID_ESCAPE_SEQUENCES: &[char; 23] = &[
     'N', 'u', 'U',
 ];
 
-/// Return the quotation markers used for a String token.
-fn extract_quote(text: &str) -> &str {
-    for quote in ["'''", "\"\"\"", "'", "\""] {
-        if text.ends_with(quote) {
-            return quote;
-        }
-    }
-
-    panic!("Unable to find quotation mark for String token")
+/// Return `true` if `body` ends with a backslash that isn't itself escaped, which would make
+/// the string a syntax error if it were prefixed with `r`.
+fn ends_with_unescaped_backslash(body: &str) -> bool {
+    body.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1
 }
 
 /// W605
@@ -584,56 +867,152 @@ pub fn invalid_escape_sequence(
     let mut diagnostics = vec![];
 
     let text = locator.slice_source_code_range(&Range::new(start, end));
+    let literal = StrLiteral::new(&text, start);
 
-    // Determine whether the string is single- or triple-quoted.
-    let quote = extract_quote(&text);
-    let quote_pos = text.find(quote).unwrap();
-    let prefix = text[..quote_pos].to_lowercase();
-    let body = &text[(quote_pos + quote.len())..(text.len() - quote.len())];
-
-    if !prefix.contains('r') {
-        for (row_offset, line) in body.lines().enumerate() {
-            let chars: Vec<char> = line.chars().collect();
-            for col_offset in 0..chars.len() {
-                if chars[col_offset] != '\\' {
-                    continue;
-                }
+    if literal.is_raw() {
+        return diagnostics;
+    }
 
-                // If the previous character was also a backslash, skip.
-                if col_offset > 0 && chars[col_offset - 1] == '\\' {
-                    continue;
-                }
+    let mut invalid_escapes = vec![];
+    let mut has_valid_escape = false;
+
+    for (row_offset, line) in literal.body.lines().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        for col_offset in 0..chars.len() {
+            if chars[col_offset] != '\\' {
+                continue;
+            }
+
+            // If the previous character was also a backslash, skip.
+            if col_offset > 0 && chars[col_offset - 1] == '\\' {
+                continue;
+            }
+
+            // If we're at the end of the line, skip.
+            if col_offset == chars.len() - 1 {
+                continue;
+            }
+
+            // If the next character is a valid escape sequence, skip (but remember that the
+            // string has one, since it means the string can't be safely made raw).
+            let next_char = chars[col_offset + 1];
+            if VALID_ESCAPE_SEQUENCES.contains(&next_char) {
+                has_valid_escape = true;
+                continue;
+            }
+
+            // Compute the location of the escape sequence by offsetting the location of the
+            // string token by the characters we've seen thus far.
+            let location = literal.location_at(row_offset, col_offset);
+            let end_location = Location::new(location.row(), location.column() + 2);
+            invalid_escapes.push((next_char, location, end_location));
+        }
+    }
 
-                // If we're at the end of the line, skip.
-                if col_offset == chars.len() - 1 {
-                    continue;
+    // If the string contains no other (valid) escape sequences, marking it as raw doesn't
+    // change its meaning, so prefer that over doubling every invalid backslash individually.
+    // A raw string can't end in an odd number of backslashes, so fall back to doubling in
+    // that case too.
+    let use_raw_prefix = !has_valid_escape && !ends_with_unescaped_backslash(literal.body);
+
+    for (idx, (next_char, location, end_location)) in invalid_escapes.into_iter().enumerate() {
+        let mut diagnostic = Diagnostic::new(
+            violations::InvalidEscapeSequence(next_char),
+            Range::new(location, end_location),
+        );
+        if autofix {
+            if use_raw_prefix {
+                // Only the first invalid escape in a given string carries the fix, since
+                // marking the string as raw fixes every invalid escape in it at once.
+                if idx == 0 {
+                    diagnostic.amend(Fix::insertion(
+                        "r".to_string(),
+                        Location::new(start.row(), start.column() + literal.prefix_len()),
+                    ));
                 }
+            } else {
+                diagnostic.amend(Fix::insertion(r"\".to_string(), location));
+            }
+        }
+        diagnostics.push(diagnostic);
+    }
+
+    diagnostics
+}
+
+/// Returns `true` if `tok` is one of the binary operator tokens this rule looks for.
+///
+/// Limited to the comparison operators for now: unambiguously distinguishing a binary `+`,
+/// `-`, `*`, `**`, or `@` from its unary, unpacking, or decorator counterpart requires
+/// reconstructing the logical line rather than scanning tokens one at a time, which is out of
+/// scope for this first pass.
+fn is_binary_operator(tok: &Tok) -> bool {
+    matches!(
+        tok,
+        Tok::Less
+            | Tok::LessEqual
+            | Tok::Greater
+            | Tok::GreaterEqual
+            | Tok::EqEqual
+            | Tok::NotEqual
+    )
+}
+
+/// W503, W504
+///
+/// Flags a line break immediately before (`W503`) or after (`W504`) a binary operator, within
+/// an implicit continuation inside parentheses, brackets, or braces. The two codes are opposite
+/// opinions about the same style choice, so only the one matching `style` is ever produced:
+/// `LineBreakStyle::Before` (breaking before the operator) enables `W504`, and
+/// `LineBreakStyle::After` enables `W503`.
+pub fn break_around_binary_operator(tokens: &[LexResult], style: LineBreakStyle) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
 
-                // If the next character is a valid escape sequence, skip.
-                let next_char = chars[col_offset + 1];
-                if VALID_ESCAPE_SEQUENCES.contains(&next_char) {
-                    continue;
+    let mut depth = 0u32;
+    let mut prev_end: Option<Location> = None;
+    let mut pending_operator: Option<(Location, Location)> = None;
+
+    for (start, tok, end) in tokens.iter().flatten() {
+        if matches!(tok, Tok::Comment(_) | Tok::NonLogicalNewline) {
+            continue;
+        }
+
+        if depth > 0 {
+            if style == LineBreakStyle::After && is_binary_operator(tok) {
+                if let Some(prev_end) = prev_end {
+                    if start.row() > prev_end.row() {
+                        diagnostics.push(Diagnostic::new(
+                            violations::LineBreakBeforeBinaryOperator,
+                            Range::new(*start, *end),
+                        ));
+                    }
                 }
+            }
 
-                // Compute the location of the escape sequence by offsetting the location of the
-                // string token by the characters we've seen thus far.
-                let col = if row_offset == 0 {
-                    start.column() + prefix.len() + quote.len() + col_offset
-                } else {
-                    col_offset
-                };
-                let location = Location::new(start.row() + row_offset, col);
-                let end_location = Location::new(location.row(), location.column() + 2);
-                let mut diagnostic = Diagnostic::new(
-                    violations::InvalidEscapeSequence(next_char),
-                    Range::new(location, end_location),
-                );
-                if autofix {
-                    diagnostic.amend(Fix::insertion(r"\".to_string(), location));
+            if style == LineBreakStyle::Before {
+                if let Some((op_start, op_end)) = pending_operator.take() {
+                    if start.row() > op_end.row() {
+                        diagnostics.push(Diagnostic::new(
+                            violations::LineBreakAfterBinaryOperator,
+                            Range::new(op_start, op_end),
+                        ));
+                    }
+                }
+                if is_binary_operator(tok) {
+                    pending_operator = Some((*start, *end));
                 }
-                diagnostics.push(diagnostic);
             }
+        } else {
+            pending_operator = None;
         }
+
+        match tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        prev_end = Some(*end);
     }
 
     diagnostics
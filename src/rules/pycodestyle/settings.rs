@@ -4,6 +4,24 @@ use ruff_macros::ConfigurationOptions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub enum LineBreakStyle {
+    /// Prefer breaking a line *before* a binary operator, so the operator
+    /// starts the continuation line. This is the style recommended by
+    /// current PEP 8 guidance and used by Black.
+    Before,
+    /// Prefer breaking a line *after* a binary operator, so the operator
+    /// stays at the end of the line. This matches older style guides.
+    After,
+}
+
+impl Default for LineBreakStyle {
+    fn default() -> Self {
+        Self::Before
+    }
+}
+
 #[derive(
     Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
 )]
@@ -30,12 +48,76 @@ pub struct Options {
     /// comments starting with `task-tags` (by default: ["TODO", "FIXME",
     /// and "XXX"]).
     pub ignore_overlong_task_comments: Option<bool>,
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            ignore-overlong-urls = false
+        "#
+    )]
+    /// Whether or not line-length violations (`E501`) should be triggered for
+    /// comments that consist of a single word ending in a URL. This lets
+    /// long links live on their own line without wrapping.
+    pub ignore_overlong_urls: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            ignore-overlong-noqa = true
+        "#
+    )]
+    /// Whether or not line-length violations (`E501`) should be triggered for
+    /// lines that only exceed the limit because of a trailing `# noqa`
+    /// comment (i.e., the code itself already fits within the limit).
+    pub ignore_overlong_noqa: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            wrap-doc-lines = true
+        "#
+    )]
+    /// Whether to enable an opt-in autofix for `W505` that re-wraps standalone
+    /// comments exceeding `max-doc-length` onto a second line. This only
+    /// applies to standalone comments; multi-line docstrings are not
+    /// currently re-wrapped.
+    pub wrap_doc_lines: Option<bool>,
+    #[option(
+        default = r#""before""#,
+        value_type = "LineBreakStyle",
+        example = r#"
+            line-break-style = "after"
+        "#
+    )]
+    /// The preferred style for line breaks around binary operators, used to
+    /// decide which of `W503` (line break before a binary operator) or `W504`
+    /// (line break after one) is actually enforced when enabled. The two are
+    /// opposite opinions about the same style choice, so only one of them
+    /// is ever reported, regardless of which are selected.
+    pub line_break_style: Option<LineBreakStyle>,
 }
 
-#[derive(Debug, Default, Hash)]
+#[derive(Debug, Hash)]
 pub struct Settings {
     pub max_doc_length: Option<usize>,
     pub ignore_overlong_task_comments: bool,
+    pub ignore_overlong_urls: bool,
+    pub ignore_overlong_noqa: bool,
+    pub wrap_doc_lines: bool,
+    pub line_break_style: LineBreakStyle,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_doc_length: None,
+            ignore_overlong_task_comments: false,
+            ignore_overlong_urls: true,
+            ignore_overlong_noqa: false,
+            wrap_doc_lines: false,
+            line_break_style: LineBreakStyle::default(),
+        }
+    }
 }
 
 impl From<Options> for Settings {
@@ -45,6 +127,10 @@ impl From<Options> for Settings {
             ignore_overlong_task_comments: options
                 .ignore_overlong_task_comments
                 .unwrap_or_default(),
+            ignore_overlong_urls: options.ignore_overlong_urls.unwrap_or(true),
+            ignore_overlong_noqa: options.ignore_overlong_noqa.unwrap_or_default(),
+            wrap_doc_lines: options.wrap_doc_lines.unwrap_or_default(),
+            line_break_style: options.line_break_style.unwrap_or_default(),
         }
     }
 }
@@ -54,6 +140,10 @@ impl From<Settings> for Options {
         Self {
             max_doc_length: settings.max_doc_length,
             ignore_overlong_task_comments: Some(settings.ignore_overlong_task_comments),
+            ignore_overlong_urls: Some(settings.ignore_overlong_urls),
+            ignore_overlong_noqa: Some(settings.ignore_overlong_noqa),
+            wrap_doc_lines: Some(settings.wrap_doc_lines),
+            line_break_style: Some(settings.line_break_style),
         }
     }
 }
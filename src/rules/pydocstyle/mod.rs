@@ -11,7 +11,7 @@ mod tests {
     use test_case::test_case;
 
     use super::settings::{Convention, Settings};
-    use crate::linter::test_path;
+    use crate::linter::{assert_ranges_non_degenerate, test_path};
     use crate::registry::Rule;
     use crate::settings;
 
@@ -20,6 +20,8 @@ mod tests {
     #[test_case(Rule::PublicMethod, Path::new("D.py"); "D102_0")]
     #[test_case(Rule::PublicMethod, Path::new("setter.py"); "D102_1")]
     #[test_case(Rule::PublicFunction, Path::new("D.py"); "D103")]
+    #[test_case(Rule::PublicFunction, Path::new("stub_functions.py"); "D103_stub_functions")]
+    #[test_case(Rule::PublicMethod, Path::new("stub_functions.py"); "D102_stub_functions")]
     #[test_case(Rule::PublicPackage, Path::new("D.py"); "D104")]
     #[test_case(Rule::MagicMethod, Path::new("D.py"); "D105")]
     #[test_case(Rule::PublicNestedClass, Path::new("D.py"); "D106")]
@@ -72,6 +74,11 @@ mod tests {
                 .as_path(),
             &settings::Settings::for_rule(rule_code),
         )?;
+        // `D104/__init__.py` is an empty file: there's no source text to point at, so its
+        // diagnostic is unavoidably zero-width.
+        if path != Path::new("D104/__init__.py") {
+            assert_ranges_non_degenerate(&diagnostics);
+        }
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
@@ -83,7 +90,10 @@ mod tests {
             &settings::Settings {
                 // When inferring the convention, we'll see a few false negatives.
                 // See: https://github.com/PyCQA/pydocstyle/issues/459.
-                pydocstyle: Settings { convention: None },
+                pydocstyle: Settings {
+                    convention: None,
+                    ..Default::default()
+                },
                 ..settings::Settings::for_rule(Rule::DocumentAllArguments)
             },
         )?;
@@ -99,6 +109,7 @@ mod tests {
                 // With explicit Google convention, we should flag every function.
                 pydocstyle: Settings {
                     convention: Some(Convention::Google),
+                    ..Default::default()
                 },
                 ..settings::Settings::for_rule(Rule::DocumentAllArguments)
             },
@@ -115,6 +126,40 @@ mod tests {
                 // With explicit Google convention, we shouldn't flag anything.
                 pydocstyle: Settings {
                     convention: Some(Convention::Numpy),
+                    ..Default::default()
+                },
+                ..settings::Settings::for_rule(Rule::DocumentAllArguments)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_stub_functions() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pydocstyle/stub_functions.py"),
+            &settings::Settings {
+                pydocstyle: Settings {
+                    ignore_stub_functions: true,
+                    ..Default::default()
+                },
+                ..settings::Settings::for_rules(vec![Rule::PublicFunction, Rule::PublicMethod])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn class_docstring_init_args() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pydocstyle/class_docstring_init_args.py"),
+            &settings::Settings {
+                pydocstyle: Settings {
+                    convention: Some(Convention::Google),
+                    class_docstring_init_args: true,
+                    ..Default::default()
                 },
                 ..settings::Settings::for_rule(Rule::DocumentAllArguments)
             },
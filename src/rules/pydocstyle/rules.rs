@@ -3,8 +3,10 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_hash::FxHashSet;
 use rustpython_ast::{Location, StmtKind};
+use rustpython_parser::lexer;
+use rustpython_parser::lexer::Tok;
 
-use super::helpers::{leading_quote, logical_line};
+use super::helpers::{leading_quote, logical_line, raw_contents};
 use super::settings::Convention;
 use crate::ast::helpers::identifier_range;
 use crate::ast::types::Range;
@@ -13,6 +15,7 @@ use crate::ast::{cast, whitespace};
 use crate::checkers::ast::Checker;
 use crate::docstrings::constants;
 use crate::docstrings::definition::{Definition, DefinitionKind, Docstring};
+use crate::docstrings::extraction::docstring_from;
 use crate::docstrings::sections::{section_contexts, SectionContext};
 use crate::docstrings::styles::SectionStyle;
 use crate::fix::Fix;
@@ -22,6 +25,62 @@ use crate::visibility::{
     is_call, is_init, is_magic, is_new, is_overload, is_override, is_staticmethod, Visibility,
 };
 
+/// Return a `Range` covering the first line of the module, for diagnostics (like a missing
+/// module docstring) that aren't tied to any particular AST node. Falls back to a zero-width
+/// range at the very start of the file if the module has no content at all.
+fn module_range(checker: &Checker) -> Range {
+    let end_column = checker
+        .locator
+        .slice_source_code_at(Location::new(1, 0))
+        .lines()
+        .next()
+        .map_or(0, |line| line.chars().count());
+    Range::new(Location::new(1, 0), Location::new(1, end_column))
+}
+
+/// Return the name of the module being checked (the file stem, or the package directory's
+/// name for an `__init__.py`), for use as the `{module}` placeholder in a
+/// `pydocstyle.docstring-template`. Enclosing packages are not included.
+fn module_name(checker: &Checker) -> String {
+    let path = checker.path();
+    if path.ends_with("__init__.py") {
+        path.parent()
+    } else {
+        Some(path)
+    }
+    .and_then(std::path::Path::file_stem)
+    .map_or_else(|| "module".to_string(), |stem| stem.to_string_lossy().into_owned())
+}
+
+/// Generate a fix that inserts a placeholder docstring, rendered from the
+/// user-configured `pydocstyle.docstring-template`, at the top of the file.
+fn docstring_insertion_fix(checker: &Checker) -> Option<Fix> {
+    let template = checker.settings.pydocstyle.docstring_template.as_ref()?;
+    let docstring = template.replace("{module}", &module_name(checker));
+
+    // Skip past any leading comments (e.g. a shebang or coding declaration),
+    // which must precede the module docstring.
+    let mut splice = Location::default();
+    let contents = checker.locator.slice_source_code_at(splice);
+    for (.., tok, end) in lexer::make_tokenizer(&contents).flatten() {
+        if matches!(tok, Tok::Comment(..) | Tok::Newline) {
+            splice = end;
+        } else {
+            break;
+        }
+    }
+
+    let mut contents = String::with_capacity(docstring.len() + 1);
+    if splice > Location::default() {
+        contents.push('\n');
+    }
+    contents.push_str(&docstring);
+    if splice == Location::default() {
+        contents.push('\n');
+    }
+    Some(Fix::insertion(contents, splice))
+}
+
 /// D100, D101, D102, D103, D104, D105, D106, D107
 pub fn not_missing(
     checker: &mut Checker,
@@ -35,19 +94,27 @@ pub fn not_missing(
     match definition.kind {
         DefinitionKind::Module => {
             if checker.settings.rules.enabled(&Rule::PublicModule) {
-                checker.diagnostics.push(Diagnostic::new(
-                    violations::PublicModule,
-                    Range::new(Location::new(1, 0), Location::new(1, 0)),
-                ));
+                let mut diagnostic =
+                    Diagnostic::new(violations::PublicModule, module_range(checker));
+                if checker.patch(&Rule::PublicModule) {
+                    if let Some(fix) = docstring_insertion_fix(checker) {
+                        diagnostic.amend(fix);
+                    }
+                }
+                checker.diagnostics.push(diagnostic);
             }
             false
         }
         DefinitionKind::Package => {
             if checker.settings.rules.enabled(&Rule::PublicPackage) {
-                checker.diagnostics.push(Diagnostic::new(
-                    violations::PublicPackage,
-                    Range::new(Location::new(1, 0), Location::new(1, 0)),
-                ));
+                let mut diagnostic =
+                    Diagnostic::new(violations::PublicPackage, module_range(checker));
+                if checker.patch(&Rule::PublicPackage) {
+                    if let Some(fix) = docstring_insertion_fix(checker) {
+                        diagnostic.amend(fix);
+                    }
+                }
+                checker.diagnostics.push(diagnostic);
             }
             false
         }
@@ -445,7 +512,7 @@ pub fn indent(checker: &mut Checker, docstring: &Docstring) {
                     violations::NoUnderIndentation,
                     Range::new(
                         Location::new(docstring.expr.location.row() + i, 0),
-                        Location::new(docstring.expr.location.row() + i, 0),
+                        Location::new(docstring.expr.location.row() + i, lines[i].chars().count()),
                     ),
                 );
                 if checker.patch(diagnostic.kind.rule()) {
@@ -495,7 +562,10 @@ pub fn indent(checker: &mut Checker, docstring: &Docstring) {
                         violations::NoOverIndentation,
                         Range::new(
                             Location::new(docstring.expr.location.row() + i, 0),
-                            Location::new(docstring.expr.location.row() + i, 0),
+                            Location::new(
+                                docstring.expr.location.row() + i,
+                                lines[i].chars().count(),
+                            ),
                         ),
                     );
                     if checker.patch(diagnostic.kind.rule()) {
@@ -519,7 +589,7 @@ pub fn indent(checker: &mut Checker, docstring: &Docstring) {
                     violations::NoOverIndentation,
                     Range::new(
                         Location::new(docstring.expr.location.row() + i, 0),
-                        Location::new(docstring.expr.location.row() + i, 0),
+                        Location::new(docstring.expr.location.row() + i, lines[i].chars().count()),
                     ),
                 );
                 if checker.patch(diagnostic.kind.rule()) {
@@ -973,34 +1043,81 @@ pub fn sections(checker: &mut Checker, docstring: &Docstring, convention: Option
     let body = docstring.body;
 
     let lines: Vec<&str> = LinesWithTrailingNewline::from(body).collect();
-    if lines.len() < 2 {
-        return;
+    let mut found_args_section = false;
+    if lines.len() >= 2 {
+        match convention {
+            Some(Convention::Google) => {
+                for context in &section_contexts(&lines, &SectionStyle::Google) {
+                    found_args_section |=
+                        is_args_or_parameters_section(context, &SectionStyle::Google);
+                    google_section(checker, docstring, context);
+                }
+            }
+            Some(Convention::Numpy) => {
+                for context in &section_contexts(&lines, &SectionStyle::Numpy) {
+                    found_args_section |=
+                        is_args_or_parameters_section(context, &SectionStyle::Numpy);
+                    numpy_section(checker, docstring, context);
+                }
+            }
+            Some(Convention::Pep257) | None => {
+                // First, interpret as NumPy-style sections.
+                let mut found_numpy_section = false;
+                for context in &section_contexts(&lines, &SectionStyle::Numpy) {
+                    found_numpy_section = true;
+                    found_args_section |=
+                        is_args_or_parameters_section(context, &SectionStyle::Numpy);
+                    numpy_section(checker, docstring, context);
+                }
+
+                // If no such sections were identified, interpret as Google-style sections.
+                if !found_numpy_section {
+                    for context in &section_contexts(&lines, &SectionStyle::Google) {
+                        found_args_section |=
+                            is_args_or_parameters_section(context, &SectionStyle::Google);
+                        google_section(checker, docstring, context);
+                    }
+                }
+            }
+        }
     }
 
+    // If `__init__` documents none of its arguments in its own docstring, fall back to the
+    // enclosing class's docstring (when `class-docstring-init-args` is enabled): NumPy and
+    // Google style codebases commonly document constructor parameters once, on the class
+    // itself, and leave `__init__`'s own docstring bare.
+    if !found_args_section && checker.settings.rules.enabled(&Rule::DocumentAllArguments) {
+        check_class_docstring_init_args(checker, docstring, convention);
+    }
+}
+
+fn check_class_docstring_init_args(
+    checker: &mut Checker,
+    docstring: &Docstring,
+    convention: Option<&Convention>,
+) {
     match convention {
         Some(Convention::Google) => {
-            for context in &section_contexts(&lines, &SectionStyle::Google) {
-                google_section(checker, docstring, context);
+            let args = class_init_docstring_args(checker, docstring, &SectionStyle::Google);
+            if !args.is_empty() {
+                missing_args(checker, docstring, &args);
             }
         }
         Some(Convention::Numpy) => {
-            for context in &section_contexts(&lines, &SectionStyle::Numpy) {
-                numpy_section(checker, docstring, context);
+            let args = class_init_docstring_args(checker, docstring, &SectionStyle::Numpy);
+            if !args.is_empty() {
+                missing_args(checker, docstring, &args);
             }
         }
         Some(Convention::Pep257) | None => {
-            // First, interpret as NumPy-style sections.
-            let mut found_numpy_section = false;
-            for context in &section_contexts(&lines, &SectionStyle::Numpy) {
-                found_numpy_section = true;
-                numpy_section(checker, docstring, context);
+            let numpy_args = class_init_docstring_args(checker, docstring, &SectionStyle::Numpy);
+            if !numpy_args.is_empty() {
+                missing_args(checker, docstring, &numpy_args);
+                return;
             }
-
-            // If no such sections were identified, interpret as Google-style sections.
-            if !found_numpy_section {
-                for context in &section_contexts(&lines, &SectionStyle::Google) {
-                    google_section(checker, docstring, context);
-                }
+            let google_args = class_init_docstring_args(checker, docstring, &SectionStyle::Google);
+            if !google_args.is_empty() {
+                missing_args(checker, docstring, &google_args);
             }
         }
     }
@@ -1315,24 +1432,20 @@ fn common_section(
                     Range::from_located(docstring.expr),
                 );
                 if checker.patch(diagnostic.kind.rule()) {
-                    // Replace the section title with the capitalized variant. This requires
-                    // locating the start and end of the section name.
-                    if let Some(index) = context.line.find(context.section_name) {
-                        // Map from bytes to characters.
-                        let section_name_start = &context.line[..index].chars().count();
-                        let section_name_length = &context.section_name.chars().count();
-                        diagnostic.amend(Fix::replacement(
-                            capitalized_section_name,
-                            Location::new(
-                                docstring.expr.location.row() + context.original_index,
-                                *section_name_start,
-                            ),
-                            Location::new(
-                                docstring.expr.location.row() + context.original_index,
-                                section_name_start + section_name_length,
-                            ),
-                        ));
-                    }
+                    // Replace the section title with the capitalized variant.
+                    let section_name_start = context.section_name_start;
+                    let section_name_length = context.section_name.chars().count();
+                    diagnostic.amend(Fix::replacement(
+                        capitalized_section_name,
+                        Location::new(
+                            docstring.expr.location.row() + context.original_index,
+                            section_name_start,
+                        ),
+                        Location::new(
+                            docstring.expr.location.row() + context.original_index,
+                            section_name_start + section_name_length,
+                        ),
+                    ));
                 }
                 checker.diagnostics.push(diagnostic);
             }
@@ -1443,7 +1556,7 @@ fn common_section(
     blanks_and_section_underline(checker, docstring, context);
 }
 
-fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &FxHashSet<&str>) {
+fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &FxHashSet<String>) {
     let (
         DefinitionKind::Function(parent)
         | DefinitionKind::NestedFunction(parent)
@@ -1478,7 +1591,7 @@ fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &
         )
     {
         let arg_name = arg.node.arg.as_str();
-        if !arg_name.starts_with('_') && !docstrings_args.contains(&arg_name) {
+        if !arg_name.starts_with('_') && !docstrings_args.contains(arg_name) {
             missing_arg_names.insert(arg_name.to_string());
         }
     }
@@ -1489,8 +1602,8 @@ fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &
         let arg_name = arg.node.arg.as_str();
         let starred_arg_name = format!("*{arg_name}");
         if !arg_name.starts_with('_')
-            && !docstrings_args.contains(&arg_name)
-            && !docstrings_args.contains(&starred_arg_name.as_str())
+            && !docstrings_args.contains(arg_name)
+            && !docstrings_args.contains(starred_arg_name.as_str())
         {
             missing_arg_names.insert(starred_arg_name);
         }
@@ -1499,8 +1612,8 @@ fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &
         let arg_name = arg.node.arg.as_str();
         let starred_arg_name = format!("**{arg_name}");
         if !arg_name.starts_with('_')
-            && !docstrings_args.contains(&arg_name)
-            && !docstrings_args.contains(&starred_arg_name.as_str())
+            && !docstrings_args.contains(arg_name)
+            && !docstrings_args.contains(starred_arg_name.as_str())
         {
             missing_arg_names.insert(starred_arg_name);
         }
@@ -1519,17 +1632,17 @@ fn missing_args(checker: &mut Checker, docstring: &Docstring, docstrings_args: &
 static GOOGLE_ARGS_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^\s*(\*?\*?\w+)\s*(\(.*?\))?\s*:\n?\s*.+").unwrap());
 
-fn args_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
-    if context.following_lines.is_empty() {
-        missing_args(checker, docstring, &FxHashSet::default());
-        return;
+/// Extract the set of argument names documented in a Google-style `Args`/`Arguments` section,
+/// given the lines following the section header.
+fn google_docstring_args(following_lines: &[&str]) -> FxHashSet<String> {
+    if following_lines.is_empty() {
+        return FxHashSet::default();
     }
 
     // Normalize leading whitespace, by removing any lines with less indentation
     // than the first.
-    let leading_space = whitespace::leading_space(context.following_lines[0]);
-    let relevant_lines = context
-        .following_lines
+    let leading_space = whitespace::leading_space(following_lines[0]);
+    let relevant_lines = following_lines
         .iter()
         .filter(|line| line.starts_with(leading_space) || line.is_empty())
         .join("\n");
@@ -1555,27 +1668,27 @@ fn args_section(checker: &mut Checker, docstring: &Docstring, context: &SectionC
     }
 
     // Extract the argument name from each section.
-    let mut matches = Vec::new();
-    for section in &args_sections {
-        if let Some(captures) = GOOGLE_ARGS_REGEX.captures(section) {
-            matches.push(captures);
-        }
-    }
-    let docstrings_args = matches
+    args_sections
         .iter()
-        .filter_map(|captures| captures.get(1).map(|arg_name| arg_name.as_str()))
-        .collect();
+        .filter_map(|section| GOOGLE_ARGS_REGEX.captures(section))
+        .filter_map(|captures| captures.get(1).map(|arg_name| arg_name.as_str().to_string()))
+        .collect()
+}
 
+fn args_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
+    let mut docstrings_args = google_docstring_args(context.following_lines);
+    docstrings_args.extend(class_init_docstring_args(checker, docstring, &SectionStyle::Google));
     missing_args(checker, docstring, &docstrings_args);
 }
 
-fn parameters_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
-    // Collect the list of arguments documented in the docstring.
-    let mut docstring_args: FxHashSet<&str> = FxHashSet::default();
-    let section_level_indent = whitespace::leading_space(context.line);
+/// Extract the set of parameter names documented in a NumPy-style `Parameters` section, given
+/// the section header line and the lines following it.
+fn numpy_docstring_args(section_line: &str, following_lines: &[&str]) -> FxHashSet<String> {
+    let mut docstring_args: FxHashSet<String> = FxHashSet::default();
+    let section_level_indent = whitespace::leading_space(section_line);
 
     // Join line continuations, then resplit by line.
-    let adjusted_following_lines = context.following_lines.join("\n").replace("\\\n", "");
+    let adjusted_following_lines = following_lines.join("\n").replace("\\\n", "");
     let lines: Vec<&str> = LinesWithTrailingNewline::from(&adjusted_following_lines).collect();
 
     for i in 1..lines.len() {
@@ -1596,14 +1709,86 @@ fn parameters_section(checker: &mut Checker, docstring: &Docstring, context: &Se
             // Notably, NumPy lets you put multiple parameters of the same type on the same
             // line.
             for parameter in parameters.split(',') {
-                docstring_args.insert(parameter.trim());
+                docstring_args.insert(parameter.trim().to_string());
             }
         }
     }
+    docstring_args
+}
+
+fn parameters_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
+    // Collect the list of arguments documented in the docstring.
+    let mut docstring_args = numpy_docstring_args(context.line, context.following_lines);
+    docstring_args.extend(class_init_docstring_args(checker, docstring, &SectionStyle::Numpy));
     // Validate that all arguments were documented.
     missing_args(checker, docstring, &docstring_args);
 }
 
+/// If `class-docstring-init-args` is enabled and `docstring` belongs to an `__init__` method,
+/// return the argument names documented in the enclosing class's own docstring (parsed for the
+/// given `style`), so that NumPy/Google-style codebases that document constructor parameters on
+/// the class itself (rather than on `__init__`) are still recognized by `D417`.
+fn class_init_docstring_args(
+    checker: &Checker,
+    docstring: &Docstring,
+    style: &SectionStyle,
+) -> FxHashSet<String> {
+    if !checker.settings.pydocstyle.class_docstring_init_args {
+        return FxHashSet::default();
+    }
+    let DefinitionKind::Method(parent) = docstring.kind else {
+        return FxHashSet::default();
+    };
+    let (StmtKind::FunctionDef { name, .. } | StmtKind::AsyncFunctionDef { name, .. }) =
+        &parent.node else {
+        return FxHashSet::default();
+    };
+    if !is_init(name) {
+        return FxHashSet::default();
+    }
+
+    let Some(body) = checker.parents.iter().rev().find_map(|stmt| {
+        let StmtKind::ClassDef { body, .. } = &stmt.node else {
+            return None;
+        };
+        let expr = docstring_from(body)?;
+        let content = checker
+            .locator
+            .slice_source_code_range(&Range::from_located(expr));
+        Some(raw_contents(&content).to_string())
+    }) else {
+        return FxHashSet::default();
+    };
+
+    let lines: Vec<&str> = LinesWithTrailingNewline::from(&body).collect();
+    if lines.len() < 2 {
+        return FxHashSet::default();
+    }
+    for context in &section_contexts(&lines, style) {
+        if is_args_or_parameters_section(context, style) {
+            return match style {
+                SectionStyle::Google => google_docstring_args(context.following_lines),
+                SectionStyle::Numpy => {
+                    numpy_docstring_args(context.line, context.following_lines)
+                }
+            };
+        }
+    }
+    FxHashSet::default()
+}
+
+/// Return `true` if `context` is a Google-style `Args`/`Arguments` section, or a NumPy-style
+/// `Parameters` section, per `style`.
+fn is_args_or_parameters_section(context: &SectionContext, style: &SectionStyle) -> bool {
+    let capitalized_section_name = titlecase::titlecase(context.section_name);
+    match style {
+        SectionStyle::Google => {
+            capitalized_section_name == "Args" || capitalized_section_name == "Arguments"
+        }
+        SectionStyle::Numpy => capitalized_section_name == "Parameters",
+    }
+}
+
 fn numpy_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
     common_section(checker, docstring, context, &SectionStyle::Numpy);
 
@@ -1623,24 +1808,20 @@ fn numpy_section(checker: &mut Checker, docstring: &Docstring, context: &Section
                 Range::from_located(docstring.expr),
             );
             if checker.patch(diagnostic.kind.rule()) {
-                // Delete the suffix. This requires locating the end of the section name.
-                if let Some(index) = context.line.find(context.section_name) {
-                    // Map from bytes to characters.
-                    let suffix_start = &context.line[..index + context.section_name.len()]
-                        .chars()
-                        .count();
-                    let suffix_length = suffix.chars().count();
-                    diagnostic.amend(Fix::deletion(
-                        Location::new(
-                            docstring.expr.location.row() + context.original_index,
-                            *suffix_start,
-                        ),
-                        Location::new(
-                            docstring.expr.location.row() + context.original_index,
-                            suffix_start + suffix_length,
-                        ),
-                    ));
-                }
+                // Delete the suffix.
+                let suffix_start =
+                    context.section_name_start + context.section_name.chars().count();
+                let suffix_length = suffix.chars().count();
+                diagnostic.amend(Fix::deletion(
+                    Location::new(
+                        docstring.expr.location.row() + context.original_index,
+                        suffix_start,
+                    ),
+                    Location::new(
+                        docstring.expr.location.row() + context.original_index,
+                        suffix_start + suffix_length,
+                    ),
+                ));
             }
             checker.diagnostics.push(diagnostic);
         }
@@ -1673,25 +1854,21 @@ fn google_section(checker: &mut Checker, docstring: &Docstring, context: &Sectio
                 Range::from_located(docstring.expr),
             );
             if checker.patch(diagnostic.kind.rule()) {
-                // Replace the suffix. This requires locating the end of the section name.
-                if let Some(index) = context.line.find(context.section_name) {
-                    // Map from bytes to characters.
-                    let suffix_start = &context.line[..index + context.section_name.len()]
-                        .chars()
-                        .count();
-                    let suffix_length = suffix.chars().count();
-                    diagnostic.amend(Fix::replacement(
-                        ":".to_string(),
-                        Location::new(
-                            docstring.expr.location.row() + context.original_index,
-                            *suffix_start,
-                        ),
-                        Location::new(
-                            docstring.expr.location.row() + context.original_index,
-                            suffix_start + suffix_length,
-                        ),
-                    ));
-                }
+                // Replace the suffix.
+                let suffix_start =
+                    context.section_name_start + context.section_name.chars().count();
+                let suffix_length = suffix.chars().count();
+                diagnostic.amend(Fix::replacement(
+                    ":".to_string(),
+                    Location::new(
+                        docstring.expr.location.row() + context.original_index,
+                        suffix_start,
+                    ),
+                    Location::new(
+                        docstring.expr.location.row() + context.original_index,
+                        suffix_start + suffix_length,
+                    ),
+                ));
             }
             checker.diagnostics.push(diagnostic);
         }
@@ -88,17 +88,73 @@ pub struct Options {
     /// Whether to use Google-style or NumPy-style conventions or the PEP257
     /// defaults when analyzing docstring sections.
     pub convention: Option<Convention>,
+    #[option(
+        default = r#"None"#,
+        value_type = "String",
+        example = r#"
+            # Auto-generate a placeholder docstring for modules and packages
+            # that are missing one.
+            docstring-template = "\"\"\"{module}.\"\"\""
+        "#
+    )]
+    /// An opt-in template used to autofix `D100` and `D104` by inserting a
+    /// placeholder docstring at the top of the module or package. The
+    /// template may include a `{module}` placeholder, which is replaced
+    /// with the module's own name (its file name without the extension, or
+    /// the package directory's name for an `__init__.py`); enclosing
+    /// packages are not included. Unset by default, since the ideal
+    /// docstring content can't be inferred automatically; `D100` and `D104`
+    /// are only autofixable once this is configured.
+    pub docstring_template: Option<String>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            # Don't require docstrings on stub functions and methods (those whose
+            # body is just `pass`, `...`, or a docstring).
+            ignore-stub-functions = true
+        "#
+    )]
+    /// Whether to ignore missing-docstring rules (`D1xx`) for functions and
+    /// methods whose body is a stub: made up only of `pass` statements, `...`
+    /// (`Ellipsis`) expressions, and string literals such as a docstring. Useful for
+    /// `Protocol` classes and other declaration-only interfaces, where such
+    /// stubs are typically left undocumented in favor of the docstring on
+    /// the concrete implementation.
+    pub ignore_stub_functions: bool,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            # Allow `__init__` parameters to be documented on the class docstring,
+            # as is common in the NumPy and Google conventions.
+            class-docstring-init-args = true
+        "#
+    )]
+    /// Whether `D417` (`document-all-arguments`) should also accept an
+    /// `__init__` method's parameters being documented in the *class*
+    /// docstring's `Args`/`Arguments`/`Parameters` section, rather than
+    /// requiring them in `__init__`'s own docstring. Common in NumPy- and
+    /// Google-style codebases that document constructor parameters once, on
+    /// the class itself.
+    pub class_docstring_init_args: bool,
 }
 
 #[derive(Debug, Default, Hash)]
 pub struct Settings {
     pub convention: Option<Convention>,
+    pub docstring_template: Option<String>,
+    pub ignore_stub_functions: bool,
+    pub class_docstring_init_args: bool,
 }
 
 impl From<Options> for Settings {
     fn from(options: Options) -> Self {
         Self {
             convention: options.convention,
+            docstring_template: options.docstring_template,
+            ignore_stub_functions: options.ignore_stub_functions,
+            class_docstring_init_args: options.class_docstring_init_args,
         }
     }
 }
@@ -107,6 +163,9 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             convention: settings.convention,
+            docstring_template: settings.docstring_template,
+            ignore_stub_functions: settings.ignore_stub_functions,
+            class_docstring_init_args: settings.class_docstring_init_args,
         }
     }
 }
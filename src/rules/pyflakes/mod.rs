@@ -28,6 +28,7 @@ mod tests {
     #[test_case(Rule::UnusedImport, Path::new("F401_5.py"); "F401_5")]
     #[test_case(Rule::UnusedImport, Path::new("F401_6.py"); "F401_6")]
     #[test_case(Rule::UnusedImport, Path::new("F401_7.py"); "F401_7")]
+    #[test_case(Rule::UnusedImport, Path::new("F401_8.py"); "F401_8")]
     #[test_case(Rule::ImportShadowedByLoopVar, Path::new("F402.py"); "F402")]
     #[test_case(Rule::ImportStarUsed, Path::new("F403.py"); "F403")]
     #[test_case(Rule::LateFutureImport, Path::new("F404.py"); "F404")]
@@ -87,6 +88,7 @@ mod tests {
     #[test_case(Rule::RedefinedWhileUnused, Path::new("F811_18.py"); "F811_18")]
     #[test_case(Rule::RedefinedWhileUnused, Path::new("F811_19.py"); "F811_19")]
     #[test_case(Rule::RedefinedWhileUnused, Path::new("F811_20.py"); "F811_20")]
+    #[test_case(Rule::RedefinedWhileUnused, Path::new("F811_21.py"); "F811_21")]
     #[test_case(Rule::UndefinedName, Path::new("F821_0.py"); "F821_0")]
     #[test_case(Rule::UndefinedName, Path::new("F821_1.py"); "F821_1")]
     #[test_case(Rule::UndefinedName, Path::new("F821_2.py"); "F821_2")]
@@ -1,18 +1,42 @@
-use rustpython_ast::{Expr, ExprKind, Stmt};
+use rustpython_ast::{Expr, ExprKind, Location, Stmt, StmtKind};
 
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
-use crate::registry::Diagnostic;
+use crate::fix::Fix;
+use crate::registry::{Diagnostic, Rule};
+use crate::source_code::Generator;
 use crate::violations;
 
 /// F631
 pub fn assert_tuple(checker: &mut Checker, stmt: &Stmt, test: &Expr) {
-    if let ExprKind::Tuple { elts, .. } = &test.node {
-        if !elts.is_empty() {
-            checker.diagnostics.push(Diagnostic::new(
-                violations::AssertTuple,
-                Range::from_located(stmt),
-            ));
-        }
+    let ExprKind::Tuple { elts, .. } = &test.node else {
+        return;
+    };
+    if elts.is_empty() {
+        return;
     }
+
+    // If the tuple has exactly two elements, the assertion was almost certainly
+    // meant to be `assert <test>, <msg>`, so it's safe to autofix.
+    let fixable = elts.len() == 2;
+    let mut diagnostic =
+        Diagnostic::new(violations::AssertTuple(fixable), Range::from_located(stmt));
+    if fixable && checker.patch(&Rule::AssertTuple) {
+        let assert_stmt = Stmt::new(
+            Location::default(),
+            Location::default(),
+            StmtKind::Assert {
+                test: Box::new(elts[0].clone()),
+                msg: Some(Box::new(elts[1].clone())),
+            },
+        );
+        let mut generator: Generator = checker.stylist.into();
+        generator.unparse_stmt(&assert_stmt);
+        diagnostic.amend(Fix::replacement(
+            generator.generate(),
+            stmt.location,
+            stmt.end_location.unwrap(),
+        ));
+    }
+    checker.diagnostics.push(diagnostic);
 }
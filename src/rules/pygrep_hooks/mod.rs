@@ -1,5 +1,6 @@
 //! Rules from [pygrep-hooks](https://github.com/pre-commit/pygrep-hooks).
 pub(crate) mod rules;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -18,6 +19,7 @@ mod tests {
     #[test_case(Rule::DeprecatedLogWarn, Path::new("PGH002_1.py"); "PGH002_1")]
     #[test_case(Rule::BlanketTypeIgnore, Path::new("PGH003_0.py"); "PGH003_0")]
     #[test_case(Rule::BlanketNOQA, Path::new("PGH004_0.py"); "PGH004_0")]
+    #[test_case(Rule::TypeIgnoreMissingCode, Path::new("PGH005_0.py"); "PGH005_0")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -29,4 +31,19 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, diagnostics);
         Ok(())
     }
+
+    #[test]
+    fn type_ignore_missing_code_with_default_code() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/pygrep-hooks/PGH005_1.py"),
+            &settings::Settings {
+                pygrep_hooks: super::settings::Settings {
+                    default_type_ignore_code: Some("unused-ignore".to_string()),
+                },
+                ..settings::Settings::for_rule(Rule::TypeIgnoreMissingCode)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
 }
@@ -2,8 +2,10 @@ pub use blanket_noqa::blanket_noqa;
 pub use blanket_type_ignore::blanket_type_ignore;
 pub use deprecated_log_warn::deprecated_log_warn;
 pub use no_eval::no_eval;
+pub use type_ignore_missing_code::type_ignore_missing_code;
 
 mod blanket_noqa;
 mod blanket_type_ignore;
 mod deprecated_log_warn;
 mod no_eval;
+mod type_ignore_missing_code;
@@ -0,0 +1,51 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustpython_ast::Location;
+
+use crate::ast::types::Range;
+use crate::fix::Fix;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+static TYPE_IGNORE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?P<ignore># type:? *ignore)(?P<code>\[[^\]]*\])?($|\s)").unwrap());
+
+/// PGH005 - use of `# type: ignore` without a bracketed error code
+pub fn type_ignore_missing_code(
+    lineno: usize,
+    line: &str,
+    default_code: Option<&str>,
+    autofix: bool,
+) -> Option<Diagnostic> {
+    let captures = TYPE_IGNORE_REGEX.captures(line)?;
+    let ignore = captures.name("ignore").unwrap();
+    let code = captures.name("code");
+
+    // A non-empty bracketed code (e.g., `# type: ignore[arg-type]`) is exactly what
+    // we're asking for; only a missing or empty bracket is a violation.
+    if code.map_or(false, |code| code.as_str() != "[]") {
+        return None;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        violations::TypeIgnoreMissingCode,
+        Range::new(
+            Location::new(lineno + 1, ignore.start()),
+            Location::new(lineno + 1, code.map_or(ignore.end(), |code| code.end())),
+        ),
+    );
+    if autofix {
+        if let Some(default_code) = default_code {
+            let content = format!("[{default_code}]");
+            diagnostic.amend(match code {
+                Some(code) => Fix::replacement(
+                    content,
+                    Location::new(lineno + 1, code.start()),
+                    Location::new(lineno + 1, code.end()),
+                ),
+                None => Fix::insertion(content, Location::new(lineno + 1, ignore.end())),
+            });
+        }
+    }
+    Some(diagnostic)
+}
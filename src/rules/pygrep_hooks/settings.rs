@@ -0,0 +1,42 @@
+//! Settings for the `pygrep-hooks`-derived rules.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", rename = "PygrepHooksOptions")]
+pub struct Options {
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"default-type-ignore-code = "unused-ignore""#
+    )]
+    /// The error code to insert into a bare `# type: ignore` comment when
+    /// fixing `PGH005`. If unset, `PGH005` is still reported, but no fix is
+    /// offered.
+    pub default_type_ignore_code: Option<String>,
+}
+
+#[derive(Debug, Hash, Default)]
+pub struct Settings {
+    pub default_type_ignore_code: Option<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            default_type_ignore_code: options.default_type_ignore_code,
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            default_type_ignore_code: settings.default_type_ignore_code,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// PLW0129
+pub fn assert_on_string_literal(checker: &mut Checker, stmt: &Stmt, test: &Expr) {
+    if let ExprKind::Constant {
+        value: Constant::Str(value),
+        ..
+    } = &test.node
+    {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::AssertOnStringLiteral(value.is_empty()),
+            Range::from_located(stmt),
+        ));
+    }
+}
@@ -1,8 +1,13 @@
+pub use assert_on_string_literal::assert_on_string_literal;
 pub use await_outside_async::await_outside_async;
 pub use constant_comparison::constant_comparison;
 pub use magic_value_comparison::magic_value_comparison;
 pub use merge_isinstance::merge_isinstance;
 pub use property_with_parameters::property_with_parameters;
+pub use self_assigning_variable::self_assigning_variable;
+pub use single_string_slots::single_string_slots;
+pub use too_many_public_methods::too_many_public_methods;
+pub use unexpected_special_method_signature::unexpected_special_method_signature;
 pub use unnecessary_direct_lambda_call::unnecessary_direct_lambda_call;
 pub use use_from_import::use_from_import;
 pub use use_sys_exit::use_sys_exit;
@@ -10,11 +15,16 @@ pub use used_prior_global_declaration::used_prior_global_declaration;
 pub use useless_else_on_loop::useless_else_on_loop;
 pub use useless_import_alias::useless_import_alias;
 
+mod assert_on_string_literal;
 mod await_outside_async;
 mod constant_comparison;
 mod magic_value_comparison;
 mod merge_isinstance;
 mod property_with_parameters;
+mod self_assigning_variable;
+mod single_string_slots;
+mod too_many_public_methods;
+mod unexpected_special_method_signature;
 mod unnecessary_direct_lambda_call;
 mod use_from_import;
 mod use_sys_exit;
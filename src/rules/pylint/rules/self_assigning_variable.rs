@@ -0,0 +1,54 @@
+use rustpython_ast::{Expr, ExprKind, Stmt};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// Return the `(target, value)` name pairs assigned by a (possibly tuple/list-unpacking)
+/// assignment, if both sides are name-only. Assignments that mix in attributes, subscripts, or
+/// starred targets are left alone, since a self-assignment there isn't a no-op the way it is for
+/// bare names.
+fn name_pairs<'a>(target: &'a Expr, value: &'a Expr) -> Option<Vec<(&'a str, &'a str)>> {
+    match (&target.node, &value.node) {
+        (ExprKind::Name { id: t, .. }, ExprKind::Name { id: v, .. }) => {
+            Some(vec![(t.as_str(), v.as_str())])
+        }
+        (ExprKind::Tuple { elts: t_elts, .. }, ExprKind::Tuple { elts: v_elts, .. })
+        | (ExprKind::List { elts: t_elts, .. }, ExprKind::List { elts: v_elts, .. }) => {
+            if t_elts.len() != v_elts.len() {
+                return None;
+            }
+            t_elts
+                .iter()
+                .zip(v_elts.iter())
+                .map(|(t, v)| match (&t.node, &v.node) {
+                    (ExprKind::Name { id: t, .. }, ExprKind::Name { id: v, .. }) => {
+                        Some((t.as_str(), v.as_str()))
+                    }
+                    _ => None,
+                })
+                .collect()
+        }
+        _ => None,
+    }
+}
+
+/// PLW0127
+pub fn self_assigning_variable(checker: &mut Checker, stmt: &Stmt, targets: &[Expr], value: &Expr) {
+    let [target] = targets else {
+        return;
+    };
+    let Some(pairs) = name_pairs(target, value) else {
+        return;
+    };
+
+    for (target, value) in pairs {
+        if target == value {
+            checker.diagnostics.push(Diagnostic::new(
+                violations::SelfAssigningVariable(target.to_string()),
+                Range::from_located(stmt),
+            ));
+        }
+    }
+}
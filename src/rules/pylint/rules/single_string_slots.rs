@@ -0,0 +1,41 @@
+use rustpython_ast::{Constant, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// PLC0205
+pub fn single_string_slots(checker: &mut Checker, body: &[Stmt]) {
+    for stmt in body {
+        let (targets, value) = match &stmt.node {
+            StmtKind::Assign { targets, value, .. } => (targets.as_slice(), value),
+            StmtKind::AnnAssign {
+                target,
+                value: Some(value),
+                ..
+            } => (std::slice::from_ref(target), value),
+            _ => continue,
+        };
+
+        if !targets
+            .iter()
+            .any(|target| matches!(&target.node, ExprKind::Name { id, .. } if id == "__slots__"))
+        {
+            continue;
+        }
+
+        if matches!(
+            &value.node,
+            ExprKind::Constant {
+                value: Constant::Str(..),
+                ..
+            }
+        ) {
+            checker.diagnostics.push(Diagnostic::new(
+                violations::SingleStringSlots,
+                Range::from_located(stmt),
+            ));
+        }
+    }
+}
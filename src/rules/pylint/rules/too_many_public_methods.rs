@@ -0,0 +1,31 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+use crate::visibility::{method_visibility, Visibility};
+
+/// PLR0904
+pub fn too_many_public_methods(
+    checker: &mut Checker,
+    stmt: &Stmt,
+    body: &[Stmt],
+    max_public_methods: usize,
+) {
+    let public_methods = body
+        .iter()
+        .filter(|stmt| {
+            matches!(
+                stmt.node,
+                StmtKind::FunctionDef { .. } | StmtKind::AsyncFunctionDef { .. }
+            ) && matches!(method_visibility(stmt), Visibility::Public)
+        })
+        .count();
+    if public_methods > max_public_methods {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::TooManyPublicMethods(public_methods, max_public_methods),
+            Range::from_located(stmt),
+        ));
+    }
+}
@@ -0,0 +1,94 @@
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+use rustpython_ast::{Arguments, Expr, ExprKind, Stmt};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Diagnostic;
+use crate::violations;
+
+/// The number of arguments (beyond `self`) that each of these special methods is expected to
+/// take. Deliberately limited to methods with a fixed arity in the data model; methods like
+/// `__init__`, `__new__`, and `__call__` are excluded, since they're commonly defined with a
+/// variable signature.
+static SPECIAL_METHOD_ARITY: Lazy<FxHashMap<&'static str, usize>> = Lazy::new(|| {
+    FxHashMap::from_iter([
+        ("__repr__", 0),
+        ("__str__", 0),
+        ("__bytes__", 0),
+        ("__hash__", 0),
+        ("__bool__", 0),
+        ("__len__", 0),
+        ("__length_hint__", 0),
+        ("__iter__", 0),
+        ("__next__", 0),
+        ("__reversed__", 0),
+        ("__neg__", 0),
+        ("__pos__", 0),
+        ("__abs__", 0),
+        ("__invert__", 0),
+        ("__index__", 0),
+        ("__enter__", 0),
+        ("__del__", 0),
+        ("__eq__", 1),
+        ("__ne__", 1),
+        ("__lt__", 1),
+        ("__le__", 1),
+        ("__gt__", 1),
+        ("__ge__", 1),
+        ("__getitem__", 1),
+        ("__delitem__", 1),
+        ("__contains__", 1),
+        ("__setitem__", 2),
+        ("__exit__", 3),
+    ])
+});
+
+/// Return `true` if `decorator_list` includes `@staticmethod` or `@classmethod`, either of which
+/// changes how the first parameter is bound and is therefore out of scope for this check.
+fn is_static_or_class_method(decorator_list: &[Expr]) -> bool {
+    decorator_list.iter().any(|decorator| {
+        matches!(
+            &decorator.node,
+            ExprKind::Name { id, .. } if id == "staticmethod" || id == "classmethod"
+        )
+    })
+}
+
+/// PLE0302
+pub fn unexpected_special_method_signature(
+    checker: &mut Checker,
+    stmt: &Stmt,
+    name: &str,
+    decorator_list: &[Expr],
+    args: &Arguments,
+) {
+    let Some(&expected) = SPECIAL_METHOD_ARITY.get(name) else {
+        return;
+    };
+
+    // `staticmethod`/`classmethod` special methods don't bind `self` the same way; skip them
+    // rather than risk a false positive.
+    if is_static_or_class_method(decorator_list) {
+        return;
+    }
+
+    // A variadic signature (`*args`, `**kwargs`) can satisfy any arity; skip rather than guess.
+    if args.vararg.is_some() || args.kwarg.is_some() || !args.kwonlyargs.is_empty() {
+        return;
+    }
+
+    let positional = args.posonlyargs.len() + args.args.len();
+    // The first positional parameter is `self`; special methods always take at least that.
+    let Some(actual) = positional.checked_sub(1) else {
+        return;
+    };
+    let required = actual.saturating_sub(args.defaults.len());
+
+    if expected < required || expected > actual {
+        checker.diagnostics.push(Diagnostic::new(
+            violations::UnexpectedSpecialMethodSignature(name.to_string(), expected, actual),
+            Range::from_located(stmt),
+        ));
+    }
+}
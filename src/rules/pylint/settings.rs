@@ -53,17 +53,29 @@ pub struct Options {
     )]
     /// Constant types to ignore when used as "magic values".
     pub allow_magic_value_types: Option<Vec<ConstantType>>,
+    #[option(
+        default = "20",
+        value_type = "usize",
+        example = r#"
+            max-public-methods = 30
+        "#
+    )]
+    /// Maximum number of public methods a class can have, after which
+    /// `PLR0904` is triggered.
+    pub max_public_methods: Option<usize>,
 }
 
 #[derive(Debug, Hash)]
 pub struct Settings {
     pub allow_magic_value_types: Vec<ConstantType>,
+    pub max_public_methods: usize,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             allow_magic_value_types: vec![ConstantType::Str],
+            max_public_methods: 20,
         }
     }
 }
@@ -74,6 +86,7 @@ impl From<Options> for Settings {
             allow_magic_value_types: options
                 .allow_magic_value_types
                 .unwrap_or_else(|| vec![ConstantType::Str]),
+            max_public_methods: options.max_public_methods.unwrap_or(20),
         }
     }
 }
@@ -82,6 +95,7 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             allow_magic_value_types: Some(settings.allow_magic_value_types),
+            max_public_methods: Some(settings.max_public_methods),
         }
     }
 }
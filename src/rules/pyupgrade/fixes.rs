@@ -5,9 +5,21 @@ use rustpython_ast::{Expr, Keyword, Location};
 use rustpython_parser::lexer;
 use rustpython_parser::lexer::Tok;
 
+use crate::ast::helpers::unparse_expr;
 use crate::ast::types::Range;
 use crate::fix::Fix;
-use crate::source_code::Locator;
+use crate::source_code::{Locator, Stylist};
+
+/// Generate a fix to replace a decorator expression (e.g., the `foo(...)` in
+/// `@foo(...)`) with `replacement`, preserving the leading `@` and any
+/// wrapping whitespace or line breaks around the decorator itself.
+pub fn replace_decorator(stylist: &Stylist, expr: &Expr, replacement: &Expr) -> Fix {
+    Fix::replacement(
+        unparse_expr(replacement, stylist),
+        expr.location,
+        expr.end_location.unwrap(),
+    )
+}
 
 /// Generate a fix to remove a base from a `ClassDef` statement.
 pub fn remove_class_def_base(
@@ -54,6 +54,12 @@ mod tests {
     #[test_case(Rule::FormatLiterals, Path::new("UP030_1.py"); "UP030_1")]
     #[test_case(Rule::FString, Path::new("UP032.py"); "UP032")]
     #[test_case(Rule::FunctoolsCache, Path::new("UP033.py"); "UP033")]
+    #[test_case(Rule::InvalidEncodingDeclaration, Path::new("UP034_0.py"); "UP034_0")]
+    #[test_case(Rule::InvalidEncodingDeclaration, Path::new("UP034_1.py"); "UP034_1")]
+    #[test_case(Rule::OutdatedVersionBlock, Path::new("UP036_0.py"); "UP036_0")]
+    #[test_case(Rule::OutdatedVersionBlock, Path::new("UP036_1.py"); "UP036_1")]
+    #[test_case(Rule::OutdatedVersionBlock, Path::new("UP036_2.py"); "UP036_2")]
+    #[test_case(Rule::OutdatedVersionBlock, Path::new("UP036_3.py"); "UP036_3")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
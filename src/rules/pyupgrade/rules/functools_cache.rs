@@ -1,10 +1,10 @@
 use rustpython_ast::{Constant, ExprKind, KeywordData};
 use rustpython_parser::ast::Expr;
 
-use crate::ast::helpers::{create_expr, unparse_expr};
+use super::super::fixes::replace_decorator;
+use crate::ast::helpers::create_expr;
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
-use crate::fix::Fix;
 use crate::registry::{Diagnostic, Rule};
 use crate::violations;
 
@@ -42,18 +42,12 @@ pub fn functools_cache(checker: &mut Checker, decorator_list: &[Expr]) {
                 );
                 if checker.patch(&Rule::FunctoolsCache) {
                     if let ExprKind::Attribute { value, ctx, .. } = &func.node {
-                        diagnostic.amend(Fix::replacement(
-                            unparse_expr(
-                                &create_expr(ExprKind::Attribute {
-                                    value: value.clone(),
-                                    attr: "cache".to_string(),
-                                    ctx: ctx.clone(),
-                                }),
-                                checker.stylist,
-                            ),
-                            expr.location,
-                            expr.end_location.unwrap(),
-                        ));
+                        let replacement = create_expr(ExprKind::Attribute {
+                            value: value.clone(),
+                            attr: "cache".to_string(),
+                            ctx: ctx.clone(),
+                        });
+                        diagnostic.amend(replace_decorator(checker.stylist, expr, &replacement));
                     }
                 }
                 checker.diagnostics.push(diagnostic);
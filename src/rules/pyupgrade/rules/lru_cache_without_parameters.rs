@@ -1,10 +1,9 @@
 use rustpython_ast::ExprKind;
 use rustpython_parser::ast::Expr;
 
-use crate::ast::helpers::unparse_expr;
+use super::super::fixes::replace_decorator;
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
-use crate::fix::Fix;
 use crate::registry::{Diagnostic, Rule};
 use crate::violations;
 
@@ -31,11 +30,7 @@ pub fn lru_cache_without_parameters(checker: &mut Checker, decorator_list: &[Exp
                 Range::new(func.end_location.unwrap(), expr.end_location.unwrap()),
             );
             if checker.patch(&Rule::LRUCacheWithoutParameters) {
-                diagnostic.amend(Fix::replacement(
-                    unparse_expr(func, checker.stylist),
-                    expr.location,
-                    expr.end_location.unwrap(),
-                ));
+                diagnostic.amend(replace_decorator(checker.stylist, expr, func));
             }
             checker.diagnostics.push(diagnostic);
         }
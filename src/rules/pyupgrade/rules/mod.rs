@@ -10,6 +10,7 @@ pub(crate) use native_literals::native_literals;
 use once_cell::sync::Lazy;
 pub(crate) use open_alias::open_alias;
 pub(crate) use os_error_alias::os_error_alias;
+pub(crate) use outdated_version_block::outdated_version_block;
 pub(crate) use redundant_open_modes::redundant_open_modes;
 use regex::Regex;
 pub(crate) use remove_six_compat::remove_six_compat;
@@ -50,6 +51,7 @@ mod lru_cache_without_parameters;
 mod native_literals;
 mod open_alias;
 mod os_error_alias;
+mod outdated_version_block;
 mod redundant_open_modes;
 mod remove_six_compat;
 mod replace_stdout_stderr;
@@ -165,3 +167,50 @@ pub fn unnecessary_coding_comment(lineno: usize, line: &str, autofix: bool) -> O
         None
     }
 }
+
+// Regex from PEP263, capturing the declared encoding name.
+static CODING_COMMENT_ENCODING_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[ \t\f]*#.*?coding[:=][ \t]*([-\w.]+)").unwrap());
+
+/// UP034
+pub fn invalid_encoding_declaration(lineno: usize, line: &str, autofix: bool) -> Option<Diagnostic> {
+    let capture = CODING_COMMENT_ENCODING_REGEX.captures(line)?;
+    let encoding = capture.get(1)?.as_str();
+
+    // Handled separately by `PEP3120UnnecessaryCodingComment`.
+    if encoding.eq_ignore_ascii_case("utf-8") || encoding.eq_ignore_ascii_case("utf8") {
+        return None;
+    }
+
+    // Ruff always reads source files as UTF-8, so a declared encoding other than
+    // UTF-8 can never be honored and is safe to remove.
+    let mut diagnostic = Diagnostic::new(
+        violations::InvalidEncodingDeclaration(violations::Encoding::NonUtf8(
+            encoding.to_string(),
+        )),
+        Range::new(Location::new(lineno + 1, 0), Location::new(lineno + 2, 0)),
+    );
+    if autofix {
+        diagnostic.amend(Fix::deletion(
+            Location::new(lineno + 1, 0),
+            Location::new(lineno + 2, 0),
+        ));
+    }
+    Some(diagnostic)
+}
+
+/// UP034
+pub fn utf8_bom(contents: &str, autofix: bool) -> Option<Diagnostic> {
+    if !contents.starts_with('\u{feff}') {
+        return None;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        violations::InvalidEncodingDeclaration(violations::Encoding::Utf8Bom),
+        Range::new(Location::new(1, 0), Location::new(1, 1)),
+    );
+    if autofix {
+        diagnostic.amend(Fix::deletion(Location::new(1, 0), Location::new(1, 1)));
+    }
+    Some(diagnostic)
+}
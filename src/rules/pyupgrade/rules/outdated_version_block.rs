@@ -0,0 +1,127 @@
+use anyhow::{bail, Result};
+use log::error;
+use rustpython_ast::{Expr, ExprKind, Located, Location, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::ast::version::{compare_version, int_tuple, is_sys_version_info};
+use crate::ast::whitespace::{dedent, has_mixed_indentation, indentation};
+use crate::autofix::helpers::delete_stmt;
+use crate::checkers::ast::Checker;
+use crate::fix::Fix;
+use crate::registry::Diagnostic;
+use crate::source_code::Locator;
+use crate::violations;
+
+/// Returns `true` if `stmt` is the `elif` clause of some enclosing `if`. An `elif`'s source
+/// text begins with the literal keyword `elif`, so replacing its full range with a dedented
+/// block (as we do for a plain `if`/`else`) would leave behind a dangling `else` with nothing
+/// to attach to.
+fn is_elif(stmt: &Stmt, parent: Option<&Stmt>) -> bool {
+    let Some(parent) = parent else {
+        return false;
+    };
+    let StmtKind::If { orelse, .. } = &parent.node else {
+        return false;
+    };
+    matches!(orelse.first(), Some(first) if std::ptr::eq(first, stmt))
+}
+
+/// Replace the entire `if` statement with `block`, dedented to the `if`'s own indentation.
+fn replace_with_dedented_block(stmt: &Stmt, block: &[Stmt], locator: &Locator) -> Result<Fix> {
+    let Some(first) = block.first() else {
+        bail!("Expected non-empty block");
+    };
+    let last = block.last().unwrap();
+
+    let Some(body_indent) = indentation(locator, first) else {
+        bail!("Unable to determine block indentation");
+    };
+    let Some(outer_indent) = indentation(locator, stmt) else {
+        bail!("Unable to determine `if` indentation");
+    };
+    if body_indent.len() <= outer_indent.len() {
+        bail!("Expected block to be indented further than the `if`");
+    }
+    let width = body_indent.len() - outer_indent.len();
+
+    let text = locator.slice_source_code_range(&Range::new(
+        Location::new(first.location.row(), 0),
+        last.end_location.unwrap(),
+    ));
+    if has_mixed_indentation(&text) {
+        bail!("Unable to dedent block with mixed tabs and spaces");
+    }
+
+    Ok(Fix::replacement(
+        dedent(&text, width),
+        stmt.location,
+        stmt.end_location.unwrap(),
+    ))
+}
+
+/// UP036
+pub fn outdated_version_block(
+    checker: &mut Checker,
+    stmt: &Stmt,
+    test: &Expr,
+    body: &[Stmt],
+    orelse: &[Stmt],
+) {
+    let ExprKind::Compare { left, ops, comparators } = &test.node else {
+        return;
+    };
+    let ([op], [comparator]) = (ops.as_slice(), comparators.as_slice()) else {
+        return;
+    };
+    if !is_sys_version_info(checker, left) {
+        return;
+    }
+    let Some(version) = int_tuple(comparator) else {
+        return;
+    };
+    let Some(always_true) = compare_version(checker.target_version, op, &version) else {
+        return;
+    };
+
+    let mut diagnostic =
+        Diagnostic::new(violations::OutdatedVersionBlock, Range::from_located(stmt));
+    if checker.patch(diagnostic.kind.rule()) {
+        let parent = checker.current_stmt_parent().map(Into::into);
+        if !is_elif(stmt, parent) {
+            let result = if always_true {
+                replace_with_dedented_block(stmt, body, checker.locator)
+            } else if orelse.is_empty() {
+                let deleted: Vec<&Stmt> = checker
+                    .deletions
+                    .iter()
+                    .map(std::convert::Into::into)
+                    .collect();
+                delete_stmt(stmt, parent, &deleted, checker.locator, checker.indexer)
+            } else if matches!(
+                orelse,
+                [Located {
+                    node: StmtKind::If { .. },
+                    ..
+                }]
+            ) {
+                // An `elif` chain hanging off the dead branch: leave it for a human, since
+                // splicing it in would require re-checking whether *it* still applies.
+                Err(anyhow::anyhow!(
+                    "Cannot autofix an outdated version block with an `elif`"
+                ))
+            } else {
+                replace_with_dedented_block(stmt, orelse, checker.locator)
+            };
+            match result {
+                Ok(fix) => {
+                    if fix.content.is_empty() || fix.content == "pass" {
+                        checker.deletions.insert(checker.current_stmt().clone());
+                    }
+                    diagnostic.amend(fix);
+                }
+                Err(e) => error!("Failed to fix outdated version block: {e}"),
+            }
+        }
+    }
+    checker.diagnostics.push(diagnostic);
+}
@@ -34,7 +34,7 @@ const PY37_PLUS_REMOVE_FUTURES: &[&str] = &[
 
 /// UP010
 pub fn unnecessary_future_import(checker: &mut Checker, stmt: &Stmt, names: &[Located<AliasData>]) {
-    let target_version = checker.settings.target_version;
+    let target_version = checker.target_version;
 
     let mut unused_imports: Vec<&Alias> = vec![];
     for alias in names {
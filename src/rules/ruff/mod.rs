@@ -1,6 +1,7 @@
 //! Ruff-specific rules.
 
 pub(crate) mod rules;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -12,8 +13,19 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::Rule;
+    use crate::rules::ruff;
     use crate::settings;
+    use crate::settings::types::PythonVersion;
     #[test_case(Rule::KeywordArgumentBeforeStarArgument, Path::new("RUF004.py"); "RUF004")]
+    #[test_case(Rule::CollectionLiteralConcatenation, Path::new("RUF006.py"); "RUF006")]
+    #[test_case(Rule::AsyncioDanglingTask, Path::new("RUF007.py"); "RUF007")]
+    #[test_case(Rule::QuotedAnnotation, Path::new("RUF008.py"); "RUF008")]
+    #[test_case(Rule::SyntaxErrorInDoctest, Path::new("RUF009.py"); "RUF009")]
+    #[test_case(Rule::MutableClassDefault, Path::new("RUF010.py"); "RUF010")]
+    #[test_case(Rule::FStringStrCall, Path::new("RUF011.py"); "RUF011")]
+    #[test_case(Rule::ImplicitOptional, Path::new("RUF013_0.py"); "RUF013_0")]
+    #[test_case(Rule::UndocumentedException, Path::new("RUF014.py"); "RUF014")]
+    #[test_case(Rule::UnusedPrivateModuleFunction, Path::new("RUF015.py"); "RUF015")]
     fn rules(rule_code: Rule, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", rule_code.code(), path.to_string_lossy());
         let diagnostics = test_path(
@@ -43,6 +55,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn confusables_locale() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff/confusables_locale.py"),
+            &settings::Settings {
+                allowed_locales: FxHashSet::from_iter(["ru".to_string()]).into(),
+                ..settings::Settings::for_rules(vec![
+                    Rule::AmbiguousUnicodeCharacterString,
+                    Rule::AmbiguousUnicodeCharacterDocstring,
+                    Rule::AmbiguousUnicodeCharacterComment,
+                ])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn confusables_threshold() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff/confusables_threshold.py"),
+            &settings::Settings {
+                max_confusables_per_token: Some(3),
+                ..settings::Settings::for_rules(vec![
+                    Rule::AmbiguousUnicodeCharacterString,
+                    Rule::AmbiguousUnicodeCharacterDocstring,
+                    Rule::AmbiguousUnicodeCharacterComment,
+                ])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn unused_private_module_function_ignore_names() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff/RUF015.py"),
+            &settings::Settings {
+                ruff: ruff::settings::Settings {
+                    ignore_names: vec!["_unused".to_string()],
+                    ..ruff::settings::Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::UnusedPrivateModuleFunction)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn ruf100_0() -> Result<()> {
         let diagnostics = test_path(
@@ -88,6 +150,54 @@ mod tests {
         Ok(())
     }
 
+    #[test_case(Path::new("RUF005_0.py"); "no_shebang")]
+    #[test_case(Path::new("RUF005_1.py"); "shebang_only")]
+    #[test_case(Path::new("RUF005_2.py"); "existing_notice")]
+    fn missing_copyright_notice(path: &Path) -> Result<()> {
+        let snapshot = format!("RUF005_{}", path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff")
+                .join(path)
+                .as_path(),
+            &settings::Settings {
+                ruff: ruff::settings::Settings {
+                    copyright_year: Some(2020),
+                    copyright_project: Some("Acme Corp".to_string()),
+                    ..ruff::settings::Settings::default()
+                },
+                ..settings::Settings::for_rule(Rule::MissingCopyrightNotice)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn implicit_optional_py37_with_import() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff/RUF013_1.py"),
+            &settings::Settings {
+                target_version: PythonVersion::Py37,
+                ..settings::Settings::for_rule(Rule::ImplicitOptional)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
+    #[test]
+    fn implicit_optional_py37_without_import() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("./resources/test/fixtures/ruff/RUF013_2.py"),
+            &settings::Settings {
+                target_version: PythonVersion::Py37,
+                ..settings::Settings::for_rule(Rule::ImplicitOptional)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn redirects() -> Result<()> {
         let diagnostics = test_path(
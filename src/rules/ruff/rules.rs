@@ -1,12 +1,27 @@
+use chrono::Datelike;
 use once_cell::sync::Lazy;
-use rustc_hash::FxHashMap;
-use rustpython_ast::{Expr, ExprKind, Keyword, KeywordData, Location};
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{
+    Arguments, Constant, ConversionFlag, ExcepthandlerKind, Expr, ExprContext, ExprKind, Keyword,
+    KeywordData, Location, Operator, Stmt, StmtKind,
+};
+use rustpython_parser::parser;
 
+use crate::ast::helpers::create_expr;
 use crate::ast::types::Range;
+use crate::ast::whitespace::LinesWithTrailingNewline;
+use crate::checkers::ast::Checker;
+use crate::docstrings::definition::{DefinitionKind, Docstring};
+use crate::docstrings::sections::section_contexts;
+use crate::docstrings::styles::SectionStyle;
 use crate::fix::Fix;
-use crate::registry::{Diagnostic, DiagnosticKind};
+use crate::registry::{Diagnostic, DiagnosticKind, Rule};
+use crate::settings::hashable::HashableHashSet;
+use crate::settings::types::PythonVersion;
 use crate::settings::{flags, Settings};
-use crate::source_code::Locator;
+use crate::source_code::{Generator, Locator};
+use crate::str::StrLiteral;
 use crate::violations;
 
 /// See: <https://github.com/microsoft/vscode/blob/095ddabc52b82498ee7f718a34f9dd11d59099a8/src/vs/base/common/strings.ts#L1094>
@@ -1597,6 +1612,34 @@ static CONFUSABLES: Lazy<FxHashMap<u32, u32>> = Lazy::new(|| {
     ])
 });
 
+/// A curated (non-exhaustive) mapping from locale code to the Unicode block(s) that make up
+/// that locale's native script. Used to allow an entire script through `RUF001`, `RUF002`, and
+/// `RUF003` for codebases that intentionally write comments, docstrings, or strings in a
+/// non-Latin script, rather than requiring every individual character to be allow-listed via
+/// `allowed-confusables`.
+static LOCALE_SCRIPTS: Lazy<FxHashMap<&'static str, &'static [(u32, u32)]>> = Lazy::new(|| {
+    FxHashMap::from_iter([
+        ("ru", [(0x0400, 0x04FF)].as_slice()),
+        ("uk", [(0x0400, 0x04FF)].as_slice()),
+        ("el", [(0x0370, 0x03FF)].as_slice()),
+        ("hy", [(0x0530, 0x058F)].as_slice()),
+        ("he", [(0x0590, 0x05FF)].as_slice()),
+        ("ar", [(0x0600, 0x06FF)].as_slice()),
+    ])
+});
+
+/// Return `true` if `current_char` falls within the native script of one of `locales`.
+fn is_allowed_by_locale(current_char: char, locales: &HashableHashSet<String>) -> bool {
+    let current_char = current_char as u32;
+    locales.iter().any(|locale| {
+        LOCALE_SCRIPTS
+            .get(locale.as_str())
+            .into_iter()
+            .flat_map(|ranges| ranges.iter())
+            .any(|(low, high)| (*low..=*high).contains(&current_char))
+    })
+}
+
 #[derive(Clone, Copy)]
 pub enum Context {
     String,
@@ -1621,7 +1664,9 @@ pub fn ambiguous_unicode_character(
     for current_char in text.chars() {
         // Search for confusing characters.
         if let Some(representant) = CONFUSABLES.get(&(current_char as u32)) {
-            if !settings.allowed_confusables.contains(&current_char) {
+            if !settings.allowed_confusables.contains(&current_char)
+                && !is_allowed_by_locale(current_char, &settings.allowed_locales)
+            {
                 if let Some(representant) = char::from_u32(*representant) {
                     let col = if row_offset == 0 {
                         start.column() + col_offset
@@ -1675,6 +1720,15 @@ pub fn ambiguous_unicode_character(
         }
     }
 
+    // If this token contains more confusables than the configured threshold, treat them as
+    // intentional (e.g., a block of non-English text) rather than a smuggled-in ambiguous
+    // character, and don't flag (or fix) any of them.
+    if let Some(max_confusables_per_token) = settings.max_confusables_per_token {
+        if diagnostics.len() > max_confusables_per_token {
+            return vec![];
+        }
+    }
+
     diagnostics
 }
 
@@ -1702,3 +1756,676 @@ pub fn keyword_argument_before_star_argument(
     }
     diagnostics
 }
+
+// A deliberately loose match: pinning down what counts as a "real" notice isn't the
+// point of this rule, since we're only trying to avoid inserting a second one next to
+// a copyright notice the project has already written in its own words.
+static COPYRIGHT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)copyright").unwrap());
+
+// Regex from PEP263, reused here to avoid inserting a notice between the encoding
+// declaration and the code it needs to precede.
+static ENCODING_COMMENT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[ \t\f]*#.*?coding[:=][ \t]*[-\w.]+").unwrap());
+
+fn render_copyright_notice(settings: &Settings) -> String {
+    let year = settings
+        .ruff
+        .copyright_year
+        .unwrap_or_else(|| i64::from(chrono::Utc::now().year()));
+    let project = settings.ruff.copyright_project.as_deref().unwrap_or("");
+    settings
+        .ruff
+        .copyright_notice
+        .replace("{year}", &year.to_string())
+        .replace("{project}", project)
+}
+
+/// RUF005
+pub fn missing_copyright_notice(
+    contents: &str,
+    settings: &Settings,
+    autofix: bool,
+) -> Option<Diagnostic> {
+    let mut lines = contents.lines().enumerate().peekable();
+
+    // A shebang and/or PEP 263 encoding declaration must remain the first line(s) of
+    // the file, so skip past them before searching for (or inserting) the notice.
+    let mut insertion_row = 1;
+    if let Some(&(_, line)) = lines.peek() {
+        if line.starts_with("#!") {
+            lines.next();
+            insertion_row += 1;
+        }
+    }
+    for _ in 0..2 {
+        if let Some(&(_, line)) = lines.peek() {
+            if ENCODING_COMMENT_REGEX.is_match(line) {
+                lines.next();
+                insertion_row += 1;
+                continue;
+            }
+        }
+        break;
+    }
+
+    if lines
+        .take(settings.ruff.copyright_check_lines)
+        .any(|(_, line)| COPYRIGHT_REGEX.is_match(line))
+    {
+        return None;
+    }
+
+    let location = Location::new(insertion_row, 0);
+    let mut diagnostic = Diagnostic::new(
+        violations::MissingCopyrightNotice,
+        Range::new(location, location),
+    );
+    if autofix {
+        let notice = render_copyright_notice(settings);
+        diagnostic.amend(Fix::insertion(format!("# {notice}\n"), location));
+    }
+    Some(diagnostic)
+}
+
+/// RUF006
+pub fn collection_literal_concatenation(
+    checker: &mut Checker,
+    expr: &Expr,
+    left: &Expr,
+    right: &Expr,
+) {
+    // Rewriting the concatenation as a single literal with an unpacked operand relies
+    // on starred expressions being allowed inside list displays, which is a PEP 448
+    // (Python 3.5+) feature.
+    if checker.target_version < PythonVersion::Py35 {
+        return;
+    }
+
+    // Restrict to list literals: unlike `[1, 2]`, a bare tuple like `1, 2` doesn't
+    // require parentheses, so we can't safely reconstruct its source text by trimming
+    // a leading/trailing bracket.
+    let element = |operand: &Expr| -> Option<String> {
+        let ExprKind::List { elts, .. } = &operand.node else {
+            return None;
+        };
+        if elts.is_empty() {
+            return Some(String::new());
+        }
+        let text = checker
+            .locator
+            .slice_source_code_range(&Range::from_located(operand));
+        Some(text[1..text.len() - 1].trim().to_string())
+    };
+
+    let mut parts = vec![];
+    match (element(left), element(right)) {
+        (Some(inner), None) => {
+            let other_text = checker
+                .locator
+                .slice_source_code_range(&Range::from_located(right));
+            if !inner.is_empty() {
+                parts.push(inner);
+            }
+            parts.push(format!("*{other_text}"));
+        }
+        (None, Some(inner)) => {
+            let other_text = checker
+                .locator
+                .slice_source_code_range(&Range::from_located(left));
+            parts.push(format!("*{other_text}"));
+            if !inner.is_empty() {
+                parts.push(inner);
+            }
+        }
+        _ => return,
+    }
+    let suggestion = format!("[{}]", parts.join(", "));
+
+    let mut diagnostic = Diagnostic::new(
+        violations::CollectionLiteralConcatenation(suggestion.clone()),
+        Range::from_located(expr),
+    );
+    if checker.patch(&Rule::CollectionLiteralConcatenation) {
+        diagnostic.amend(Fix::replacement(
+            suggestion,
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// RUF007
+pub fn asyncio_dangling_task(checker: &mut Checker, value: &Expr) {
+    let ExprKind::Call { func, .. } = &value.node else {
+        return;
+    };
+    if !checker.resolve_call_path(func).map_or(false, |call_path| {
+        call_path.as_slice() == ["asyncio", "create_task"]
+            || call_path.as_slice() == ["asyncio", "ensure_future"]
+    }) {
+        return;
+    }
+    checker.diagnostics.push(Diagnostic::new(
+        violations::AsyncioDanglingTask,
+        Range::from_located(value),
+    ));
+}
+
+/// RUF008
+pub fn quoted_annotation(checker: &mut Checker, expr: &Expr, value: &str) {
+    // With `from __future__ import annotations`, the annotation is never evaluated at
+    // runtime, so manually quoting it (e.g., to work around a forward reference) is
+    // always redundant.
+    let range = Range::from_located(expr);
+    let text = checker.locator.slice_source_code_range(&range);
+
+    // Only offer a fix if the quoted text round-trips exactly to `value`: a mismatch
+    // means the string uses a prefix (e.g., `r"..."`) or an escape sequence, and we
+    // can't safely reconstruct the unquoted annotation by trimming a leading and
+    // trailing character.
+    let mut chars = text.chars();
+    let can_fix = match (chars.next(), chars.next_back()) {
+        (Some(first), Some(last)) if first == last && (first == '\'' || first == '"') => {
+            &text[1..text.len() - 1] == value
+        }
+        _ => false,
+    };
+
+    let mut diagnostic = Diagnostic::new(
+        violations::QuotedAnnotation(value.to_string()),
+        Range::from_located(expr),
+    );
+    if can_fix && checker.patch(&Rule::QuotedAnnotation) {
+        diagnostic.amend(Fix::replacement(
+            value.to_string(),
+            range.location,
+            range.end_location,
+        ));
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// RUF009
+///
+/// Extract any `>>>`/`...`-prefixed doctest examples from `docstring`, and flag any
+/// that don't parse as valid Python. This is intentionally limited to a syntax check:
+/// running the full rule set against extracted doctests would require re-entering the
+/// checker with a different (and partially fictitious) source file, which is out of
+/// scope for now.
+pub fn doctest_syntax_error(checker: &mut Checker, docstring: &Docstring) {
+    let literal = StrLiteral::new(docstring.contents, docstring.expr.location);
+
+    // Rows (relative to `literal.body`) that were folded into the current example's
+    // reconstructed source, in the order they were appended. Used to map a parse
+    // error's line back to the docstring line that produced it.
+    let mut rows: Vec<usize> = vec![];
+    let mut indent_len = 0;
+    let mut source = String::new();
+
+    for (row, line) in literal.body.lines().enumerate() {
+        let stripped = line.trim_start();
+        let this_indent = line.len() - stripped.len();
+        if let Some(rest) = stripped
+            .strip_prefix(">>> ")
+            .or_else(|| stripped.strip_prefix(">>>"))
+        {
+            check_doctest(checker, &literal, &rows, indent_len, &source);
+            rows = vec![row];
+            indent_len = this_indent;
+            source = rest.to_string();
+        } else if !rows.is_empty() && this_indent == indent_len {
+            if let Some(rest) = stripped
+                .strip_prefix("... ")
+                .or_else(|| stripped.strip_prefix("..."))
+            {
+                rows.push(row);
+                source.push('\n');
+                source.push_str(rest);
+            } else {
+                check_doctest(checker, &literal, &rows, indent_len, &source);
+                rows.clear();
+                source.clear();
+            }
+        } else {
+            check_doctest(checker, &literal, &rows, indent_len, &source);
+            rows.clear();
+            source.clear();
+        }
+    }
+    check_doctest(checker, &literal, &rows, indent_len, &source);
+}
+
+/// Parse a single reconstructed doctest example, and flag it if it isn't valid Python.
+fn check_doctest(
+    checker: &mut Checker,
+    literal: &StrLiteral,
+    rows: &[usize],
+    indent_len: usize,
+    source: &str,
+) {
+    let Some(&first_row) = rows.first() else {
+        return;
+    };
+    let Err(parse_error) = parser::parse_program(source, "<doctest>") else {
+        return;
+    };
+    let error_row = rows
+        .get(parse_error.location.row().saturating_sub(1))
+        .copied()
+        .unwrap_or(first_row);
+    let location = literal.location_at(error_row, indent_len);
+    checker.diagnostics.push(Diagnostic::new(
+        violations::SyntaxErrorInDoctest(parse_error.error.to_string()),
+        Range::new(location, location),
+    ));
+}
+
+const MUTABLE_CLASS_DEFAULT_FUNCS: &[&[&str]] = &[
+    &["", "dict"],
+    &["", "list"],
+    &["", "set"],
+    &["collections", "Counter"],
+    &["collections", "OrderedDict"],
+    &["collections", "defaultdict"],
+    &["collections", "deque"],
+];
+
+fn is_mutable_class_default(checker: &Checker, expr: &Expr) -> bool {
+    match &expr.node {
+        ExprKind::List { .. }
+        | ExprKind::Dict { .. }
+        | ExprKind::Set { .. }
+        | ExprKind::ListComp { .. }
+        | ExprKind::DictComp { .. }
+        | ExprKind::SetComp { .. } => true,
+        ExprKind::Call { func, .. } => checker.resolve_call_path(func).map_or(false, |call_path| {
+            MUTABLE_CLASS_DEFAULT_FUNCS
+                .iter()
+                .any(|target| call_path.as_slice() == *target)
+        }),
+        _ => false,
+    }
+}
+
+fn is_dataclass(checker: &Checker, decorator_list: &[Expr]) -> bool {
+    decorator_list.iter().any(|expr| {
+        let func = match &expr.node {
+            ExprKind::Call { func, .. } => func.as_ref(),
+            _ => expr,
+        };
+        checker.resolve_call_path(func).map_or(false, |call_path| {
+            call_path.as_slice() == ["dataclasses", "dataclass"]
+        })
+    })
+}
+
+fn is_class_var(checker: &Checker, annotation: &Expr) -> bool {
+    let target = match &annotation.node {
+        ExprKind::Subscript { value, .. } => value.as_ref(),
+        _ => annotation,
+    };
+    checker.resolve_call_path(target).map_or(false, |call_path| {
+        call_path.as_slice() == ["typing", "ClassVar"]
+    })
+}
+
+fn is_dataclass_field_call(checker: &Checker, expr: &Expr) -> bool {
+    let ExprKind::Call { func, .. } = &expr.node else {
+        return false;
+    };
+    checker.resolve_call_path(func).map_or(false, |call_path| {
+        call_path.as_slice() == ["dataclasses", "field"]
+    })
+}
+
+/// RUF010
+///
+/// Flag mutable default values (list/dict/set literals or comprehensions, and calls to
+/// well-known mutable constructors) assigned directly to a class body attribute, whether
+/// on a `@dataclasses.dataclass`-decorated class or a plain class. This is distinct from
+/// `B006`, which only covers function argument defaults.
+///
+/// This is a detection-only rule: on a dataclass, the correct fix is to wrap the default
+/// in `field(default_factory=...)`, which requires inserting an import if `field` isn't
+/// already in scope. Ruff has no general-purpose "add an import" fix helper yet, so no
+/// autofix is offered here.
+pub fn mutable_class_default(checker: &mut Checker, body: &[Stmt], decorator_list: &[Expr]) {
+    let in_dataclass = is_dataclass(checker, decorator_list);
+    for stmt in body {
+        let value = match &stmt.node {
+            StmtKind::AnnAssign {
+                annotation, value: Some(value), ..
+            } => {
+                if is_class_var(checker, annotation) {
+                    continue;
+                }
+                value
+            }
+            StmtKind::Assign { value, .. } => value,
+            _ => continue,
+        };
+        if is_dataclass_field_call(checker, value) {
+            continue;
+        }
+        if is_mutable_class_default(checker, value) {
+            checker.diagnostics.push(Diagnostic::new(
+                violations::MutableClassDefault(in_dataclass),
+                Range::from_located(value),
+            ));
+        }
+    }
+}
+
+/// RUF011
+///
+/// Flag `str(...)` calls used as the value of an f-string replacement field, e.g.
+/// `f"{str(x)}"`, which can always be replaced by the equivalent `!s` conversion
+/// (`f"{x!s}"`). Limited to f-strings, since rewriting `"{}".format(str(x))` would
+/// require locating and editing the matching replacement field within the format
+/// string itself, which ruff has no general-purpose infrastructure for yet.
+pub fn f_string_str_call(checker: &mut Checker, value: &Expr, conversion: usize) {
+    if conversion != ConversionFlag::None as usize {
+        return;
+    }
+    let ExprKind::Call {
+        func,
+        args,
+        keywords,
+    } = &value.node
+    else {
+        return;
+    };
+    if args.len() != 1 || !keywords.is_empty() {
+        return;
+    }
+    let ExprKind::Name { id, .. } = &func.node else {
+        return;
+    };
+    if id != "str" || !checker.is_builtin(id) {
+        return;
+    }
+
+    let mut diagnostic =
+        Diagnostic::new(violations::FStringStrCall, Range::from_located(value));
+    if checker.patch(&Rule::FStringStrCall) {
+        let arg_text = checker
+            .locator
+            .slice_source_code_range(&Range::from_located(&args[0]));
+        diagnostic.amend(Fix::replacement(
+            format!("{arg_text}!s"),
+            value.location,
+            value.end_location.unwrap(),
+        ));
+    }
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Returns `true` if `annotation` already admits `None`, whether directly (`Optional[X]`,
+/// `typing.Union[X, None]`, `X | None`) or because it's already `None` or `Any`.
+fn allows_none(checker: &Checker, annotation: &Expr) -> bool {
+    match &annotation.node {
+        ExprKind::Constant {
+            value: Constant::None,
+            ..
+        } => true,
+        ExprKind::BinOp {
+            left,
+            op: Operator::BitOr,
+            right,
+        } => allows_none(checker, left) || allows_none(checker, right),
+        ExprKind::Subscript { value, slice, .. } => {
+            checker.resolve_call_path(value).map_or(false, |call_path| {
+                if checker.match_typing_call_path(&call_path, "Optional") {
+                    return true;
+                }
+                if checker.match_typing_call_path(&call_path, "Union") {
+                    if let ExprKind::Tuple { elts, .. } = &slice.node {
+                        return elts.iter().any(|elt| allows_none(checker, elt));
+                    }
+                }
+                call_path.as_slice() == ["typing", "Any"]
+            })
+        }
+        ExprKind::Name { .. } | ExprKind::Attribute { .. } => checker
+            .resolve_call_path(annotation)
+            .map_or(false, |call_path| call_path.as_slice() == ["typing", "Any"]),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the bare name `target` is already bound to `typing.${target}` in the
+/// current scope, so a fix can reference it without adding a new import.
+fn typing_name_in_scope(checker: &Checker, target: &str) -> bool {
+    checker.match_typing_expr(
+        &create_expr(ExprKind::Name {
+            id: target.to_string(),
+            ctx: ExprContext::Load,
+        }),
+        target,
+    )
+}
+
+/// RUF013
+pub fn implicit_optional(checker: &mut Checker, arguments: &Arguments) {
+    // Scan in reverse order to right-align defaults with their arguments.
+    for (arg, default) in arguments
+        .kwonlyargs
+        .iter()
+        .rev()
+        .zip(arguments.kw_defaults.iter().rev())
+        .chain(
+            arguments
+                .args
+                .iter()
+                .rev()
+                .chain(arguments.posonlyargs.iter().rev())
+                .zip(arguments.defaults.iter().rev()),
+        )
+    {
+        let Some(annotation) = &arg.node.annotation else {
+            continue;
+        };
+        if !matches!(
+            default.node,
+            ExprKind::Constant {
+                value: Constant::None,
+                ..
+            }
+        ) {
+            continue;
+        }
+        // A string annotation is a forward reference; rewriting it textually risks producing
+        // invalid syntax, so leave it for a human.
+        if matches!(
+            annotation.node,
+            ExprKind::Constant {
+                value: Constant::Str(_),
+                ..
+            }
+        ) {
+            continue;
+        }
+        if allows_none(checker, annotation) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(
+            violations::ImplicitOptional,
+            Range::from_located(annotation),
+        );
+        if checker.patch(&Rule::ImplicitOptional) {
+            if checker.target_version >= PythonVersion::Py310 {
+                let mut generator: Generator = checker.stylist.into();
+                generator.unparse_expr(
+                    &create_expr(ExprKind::BinOp {
+                        left: annotation.clone(),
+                        op: Operator::BitOr,
+                        right: Box::new(create_expr(ExprKind::Constant {
+                            value: Constant::None,
+                            kind: None,
+                        })),
+                    }),
+                    0,
+                );
+                diagnostic.amend(Fix::replacement(
+                    generator.generate(),
+                    annotation.location,
+                    annotation.end_location.unwrap(),
+                ));
+            } else if typing_name_in_scope(checker, "Optional") {
+                let mut generator: Generator = checker.stylist.into();
+                generator.unparse_expr(
+                    &create_expr(ExprKind::Subscript {
+                        value: Box::new(create_expr(ExprKind::Name {
+                            id: "Optional".to_string(),
+                            ctx: ExprContext::Load,
+                        })),
+                        slice: annotation.clone(),
+                        ctx: ExprContext::Load,
+                    }),
+                    0,
+                );
+                diagnostic.amend(Fix::replacement(
+                    generator.generate(),
+                    annotation.location,
+                    annotation.end_location.unwrap(),
+                ));
+            }
+            // Otherwise, `Optional` isn't already imported and we have no machinery to add an
+            // import as part of a fix, so leave this diagnostic-only.
+        }
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+// See: `GOOGLE_ARGS_REGEX` in `pydocstyle/checker.py`. The `Raises` section uses the same
+// `name: description` shape as `Args`, just with an exception type in place of a parameter name.
+static GOOGLE_RAISES_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*([\w.]+)\s*:\n?\s*.+").unwrap());
+
+/// Return the set of exception names documented in a Google-style `Raises` section, given the
+/// lines following the section header.
+fn google_docstring_exceptions(following_lines: &[&str]) -> FxHashSet<String> {
+    let mut docstring_exceptions: FxHashSet<String> = FxHashSet::default();
+    for line in following_lines {
+        if let Some(captures) = GOOGLE_RAISES_REGEX.captures(line) {
+            docstring_exceptions.insert(captures[1].to_string());
+        }
+    }
+    docstring_exceptions
+}
+
+/// Return the name of the exception type raised by `exc` (e.g., `ValueError` for
+/// `raise ValueError("...")`), if it can be determined statically. For an attribute, only the
+/// final component is returned (`Error` for `raise pkg.Error`).
+fn exception_name(exc: &Expr) -> Option<String> {
+    let type_ = match &exc.node {
+        ExprKind::Call { func, .. } => func,
+        ExprKind::Name { .. } | ExprKind::Attribute { .. } => exc,
+        _ => return None,
+    };
+    match &type_.node {
+        ExprKind::Name { id, .. } => Some(id.clone()),
+        ExprKind::Attribute { attr, .. } => Some(attr.clone()),
+        _ => None,
+    }
+}
+
+/// Recursively collect the names of exceptions raised directly within `body`, not descending
+/// into nested function or class definitions (whose own `raise` statements are their own
+/// docstring's concern, not the enclosing function's).
+fn raised_exceptions(body: &[Stmt], names: &mut FxHashSet<String>) {
+    for stmt in body {
+        match &stmt.node {
+            StmtKind::Raise { exc: Some(exc), .. } => {
+                if let Some(name) = exception_name(exc) {
+                    names.insert(name);
+                }
+            }
+            StmtKind::FunctionDef { .. }
+            | StmtKind::AsyncFunctionDef { .. }
+            | StmtKind::ClassDef { .. } => {}
+            StmtKind::If { body, orelse, .. }
+            | StmtKind::While { body, orelse, .. }
+            | StmtKind::For { body, orelse, .. }
+            | StmtKind::AsyncFor { body, orelse, .. } => {
+                raised_exceptions(body, names);
+                raised_exceptions(orelse, names);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+                ..
+            } => {
+                raised_exceptions(body, names);
+                for handler in handlers {
+                    let ExcepthandlerKind::ExceptHandler { body, .. } = &handler.node;
+                    raised_exceptions(body, names);
+                }
+                raised_exceptions(orelse, names);
+                raised_exceptions(finalbody, names);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                raised_exceptions(body, names);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// RUF014
+///
+/// Check that every exception explicitly raised in a function's body is documented in its
+/// Google-style `Raises` section, if one is present. Scoped deliberately narrowly: only the
+/// `Raises` section already present in the docstring under check is consulted (a function
+/// without one is left alone, consistent with how `D417` treats missing `Args` sections), only
+/// statically-named exception types are considered (a re-raised or dynamically constructed
+/// exception is skipped), and `Returns`/`Yields` consistency is out of scope.
+pub fn undocumented_raises(checker: &mut Checker, docstring: &Docstring) {
+    let (
+        DefinitionKind::Function(parent)
+        | DefinitionKind::NestedFunction(parent)
+        | DefinitionKind::Method(parent)
+    ) = docstring.kind else {
+        return;
+    };
+    let (StmtKind::FunctionDef { body, .. } | StmtKind::AsyncFunctionDef { body, .. }) =
+        &parent.node else {
+        return;
+    };
+
+    let lines: Vec<&str> = LinesWithTrailingNewline::from(docstring.body).collect();
+    if lines.len() < 2 {
+        return;
+    }
+
+    let mut documented_exceptions = FxHashSet::default();
+    let mut found_raises_section = false;
+    for context in &section_contexts(&lines, &SectionStyle::Google) {
+        if titlecase::titlecase(context.section_name) == "Raises" {
+            found_raises_section = true;
+            documented_exceptions = google_docstring_exceptions(context.following_lines);
+        }
+    }
+    if !found_raises_section {
+        return;
+    }
+
+    let mut raised = FxHashSet::default();
+    raised_exceptions(body, &mut raised);
+
+    let mut missing: Vec<String> = raised
+        .into_iter()
+        .filter(|name| !documented_exceptions.contains(name))
+        .collect();
+    if !missing.is_empty() {
+        missing.sort();
+        checker.diagnostics.push(Diagnostic::new(
+            violations::UndocumentedException(missing),
+            Range::from_located(parent),
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+//! Settings for the `Ruff`-specific rules.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", rename = "RuffOptions")]
+pub struct Options {
+    #[option(
+        default = "\"Copyright (c) {year} {project}\"",
+        value_type = "str",
+        example = r#"copyright-notice = "Copyright (c) {year} {project}. All rights reserved.""#
+    )]
+    /// The template used to insert a missing copyright notice (`RUF005`).
+    /// Supports the `{year}` and `{project}` placeholders, which are resolved
+    /// from `copyright-year` (defaulting to the current year) and
+    /// `copyright-project` (defaulting to an empty string) respectively.
+    pub copyright_notice: Option<String>,
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"copyright-project = "Acme Corp""#
+    )]
+    /// The project name to substitute for `{project}` in `copyright-notice`.
+    pub copyright_project: Option<String>,
+    #[option(default = "None", value_type = "int", example = r#"copyright-year = 2020"#)]
+    /// The year to substitute for `{year}` in `copyright-notice`. Defaults to
+    /// the current year.
+    pub copyright_year: Option<i64>,
+    #[option(
+        default = "4",
+        value_type = "usize",
+        example = r#"copyright-check-lines = 8"#
+    )]
+    /// The number of lines to inspect at the top of the file, after any
+    /// shebang and encoding declaration, when looking for an existing
+    /// copyright notice.
+    pub copyright_check_lines: Option<usize>,
+    #[option(
+        default = "[]",
+        value_type = "Vec<String>",
+        example = r#"ignore-names = ["_main"]"#
+    )]
+    /// A list of module-level function names to exempt from
+    /// `unused-private-module-function` (`RUF015`), for functions that are
+    /// invoked indirectly (e.g. by name, via a plugin or dispatch table)
+    /// rather than through a direct reference that the checker can resolve.
+    pub ignore_names: Option<Vec<String>>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub copyright_notice: String,
+    pub copyright_project: Option<String>,
+    pub copyright_year: Option<i64>,
+    pub copyright_check_lines: usize,
+    pub ignore_names: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            copyright_notice: "Copyright (c) {year} {project}".to_string(),
+            copyright_project: None,
+            copyright_year: None,
+            copyright_check_lines: 4,
+            ignore_names: Vec::new(),
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            copyright_notice: options
+                .copyright_notice
+                .unwrap_or_else(|| "Copyright (c) {year} {project}".to_string()),
+            copyright_project: options.copyright_project,
+            copyright_year: options.copyright_year,
+            copyright_check_lines: options.copyright_check_lines.unwrap_or(4),
+            ignore_names: options.ignore_names.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            copyright_notice: Some(settings.copyright_notice),
+            copyright_project: settings.copyright_project,
+            copyright_year: settings.copyright_year,
+            copyright_check_lines: Some(settings.copyright_check_lines),
+            ignore_names: Some(settings.ignore_names),
+        }
+    }
+}
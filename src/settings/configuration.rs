@@ -15,11 +15,12 @@ use shellexpand::LookupError;
 use crate::fs;
 use crate::registry::RuleCodePrefix;
 use crate::rules::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pylint, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_datetimez, flake8_debugger,
+    flake8_errmsg, flake8_import_conventions, flake8_no_pep420, flake8_print, flake8_pytest_style,
+    flake8_quotes, flake8_tidy_imports, flake8_todos, flake8_unused_arguments, isort, mccabe,
+    pep8_naming, pycodestyle, pydocstyle, pygrep_hooks, pylint, pyupgrade, ruff,
 };
-use crate::settings::options::Options;
+use crate::settings::options::{Options, Override as OverrideOptions};
 use crate::settings::pyproject::load_options;
 use crate::settings::types::{
     FilePattern, PerFileIgnore, PythonVersion, SerializationFormat, Version,
@@ -28,6 +29,8 @@ use crate::settings::types::{
 #[derive(Debug, Default)]
 pub struct Configuration {
     pub allowed_confusables: Option<Vec<char>>,
+    pub allowed_locales: Option<Vec<String>>,
+    pub max_confusables_per_token: Option<usize>,
     pub builtins: Option<Vec<String>>,
     pub cache_dir: Option<PathBuf>,
     pub dummy_variable_rgx: Option<Regex>,
@@ -35,6 +38,7 @@ pub struct Configuration {
     pub extend: Option<PathBuf>,
     pub extend_exclude: Vec<FilePattern>,
     pub extend_ignore: Vec<Vec<RuleCodePrefix>>,
+    pub extend_include: Vec<FilePattern>,
     pub extend_select: Vec<Vec<RuleCodePrefix>>,
     pub external: Option<Vec<String>>,
     pub fix: Option<bool>,
@@ -45,7 +49,9 @@ pub struct Configuration {
     pub ignore: Option<Vec<RuleCodePrefix>>,
     pub ignore_init_module_imports: Option<bool>,
     pub line_length: Option<usize>,
+    pub max_file_size: Option<usize>,
     pub namespace_packages: Option<Vec<PathBuf>>,
+    pub overrides: Vec<Override>,
     pub per_file_ignores: Option<Vec<PerFileIgnore>>,
     pub required_version: Option<Version>,
     pub respect_gitignore: Option<bool>,
@@ -61,19 +67,37 @@ pub struct Configuration {
     pub flake8_annotations: Option<flake8_annotations::settings::Options>,
     pub flake8_bandit: Option<flake8_bandit::settings::Options>,
     pub flake8_bugbear: Option<flake8_bugbear::settings::Options>,
+    pub flake8_datetimez: Option<flake8_datetimez::settings::Options>,
+    pub flake8_debugger: Option<flake8_debugger::settings::Options>,
     pub flake8_errmsg: Option<flake8_errmsg::settings::Options>,
     pub flake8_import_conventions: Option<flake8_import_conventions::settings::Options>,
+    pub flake8_no_pep420: Option<flake8_no_pep420::settings::Options>,
+    pub flake8_print: Option<flake8_print::settings::Options>,
     pub flake8_pytest_style: Option<flake8_pytest_style::settings::Options>,
     pub flake8_quotes: Option<flake8_quotes::settings::Options>,
     pub flake8_tidy_imports: Option<flake8_tidy_imports::options::Options>,
+    pub flake8_todos: Option<flake8_todos::settings::Options>,
     pub flake8_unused_arguments: Option<flake8_unused_arguments::settings::Options>,
     pub isort: Option<isort::settings::Options>,
     pub mccabe: Option<mccabe::settings::Options>,
     pub pep8_naming: Option<pep8_naming::settings::Options>,
     pub pycodestyle: Option<pycodestyle::settings::Options>,
     pub pydocstyle: Option<pydocstyle::settings::Options>,
+    pub pygrep_hooks: Option<pygrep_hooks::settings::Options>,
     pub pylint: Option<pylint::settings::Options>,
     pub pyupgrade: Option<pyupgrade::settings::Options>,
+    pub ruff: Option<ruff::settings::Options>,
+}
+
+/// A resolved `[[tool.ruff.overrides]]` block: a set of file patterns paired
+/// with the `select`/`ignore`/`target-version` values to apply, in place of
+/// the top-level configuration's, to files that match one of the patterns.
+#[derive(Debug, Default, Clone)]
+pub struct Override {
+    pub patterns: Vec<FilePattern>,
+    pub select: Option<Vec<RuleCodePrefix>>,
+    pub ignore: Option<Vec<RuleCodePrefix>>,
+    pub target_version: Option<PythonVersion>,
 }
 
 impl Configuration {
@@ -84,6 +108,8 @@ impl Configuration {
     pub fn from_options(options: Options, project_root: &Path) -> Result<Self> {
         Ok(Configuration {
             allowed_confusables: options.allowed_confusables,
+            allowed_locales: options.allowed_locales,
+            max_confusables_per_token: options.max_confusables_per_token,
             builtins: options.builtins,
             cache_dir: options
                 .cache_dir
@@ -128,6 +154,18 @@ impl Configuration {
                 })
                 .unwrap_or_default(),
             extend_ignore: vec![options.extend_ignore.unwrap_or_default()],
+            extend_include: options
+                .extend_include
+                .map(|paths| {
+                    paths
+                        .into_iter()
+                        .map(|pattern| {
+                            let absolute = fs::normalize_path_to(Path::new(&pattern), project_root);
+                            FilePattern::User(pattern, absolute)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
             extend_select: vec![options.extend_select.unwrap_or_default()],
             external: options.external,
             fix: options.fix,
@@ -138,10 +176,30 @@ impl Configuration {
             ignore: options.ignore,
             ignore_init_module_imports: options.ignore_init_module_imports,
             line_length: options.line_length,
+            max_file_size: options.max_file_size,
             namespace_packages: options
                 .namespace_packages
                 .map(|namespace_package| resolve_src(&namespace_package, project_root))
                 .transpose()?,
+            overrides: options
+                .overrides
+                .unwrap_or_default()
+                .into_iter()
+                .map(|over: OverrideOptions| Override {
+                    patterns: over
+                        .files
+                        .into_iter()
+                        .map(|pattern| {
+                            let absolute =
+                                fs::normalize_path_to(Path::new(&pattern), project_root);
+                            FilePattern::User(pattern, absolute)
+                        })
+                        .collect(),
+                    select: over.select,
+                    ignore: over.ignore,
+                    target_version: over.target_version,
+                })
+                .collect(),
             per_file_ignores: options.per_file_ignores.map(|per_file_ignores| {
                 per_file_ignores
                     .into_iter()
@@ -168,19 +226,26 @@ impl Configuration {
             flake8_annotations: options.flake8_annotations,
             flake8_bandit: options.flake8_bandit,
             flake8_bugbear: options.flake8_bugbear,
+            flake8_datetimez: options.flake8_datetimez,
+            flake8_debugger: options.flake8_debugger,
             flake8_errmsg: options.flake8_errmsg,
             flake8_import_conventions: options.flake8_import_conventions,
+            flake8_no_pep420: options.flake8_no_pep420,
+            flake8_print: options.flake8_print,
             flake8_pytest_style: options.flake8_pytest_style,
             flake8_quotes: options.flake8_quotes,
             flake8_tidy_imports: options.flake8_tidy_imports,
+            flake8_todos: options.flake8_todos,
             flake8_unused_arguments: options.flake8_unused_arguments,
             isort: options.isort,
             mccabe: options.mccabe,
             pep8_naming: options.pep8_naming,
             pycodestyle: options.pycodestyle,
             pydocstyle: options.pydocstyle,
+            pygrep_hooks: options.pygrep_hooks,
             pylint: options.pylint,
             pyupgrade: options.pyupgrade,
+            ruff: options.ruff,
         })
     }
 
@@ -188,6 +253,10 @@ impl Configuration {
     pub fn combine(self, config: Configuration) -> Self {
         Self {
             allowed_confusables: self.allowed_confusables.or(config.allowed_confusables),
+            allowed_locales: self.allowed_locales.or(config.allowed_locales),
+            max_confusables_per_token: self
+                .max_confusables_per_token
+                .or(config.max_confusables_per_token),
             builtins: self.builtins.or(config.builtins),
             cache_dir: self.cache_dir.or(config.cache_dir),
             dummy_variable_rgx: self.dummy_variable_rgx.or(config.dummy_variable_rgx),
@@ -203,6 +272,11 @@ impl Configuration {
                 .into_iter()
                 .chain(self.extend_ignore.into_iter())
                 .collect(),
+            extend_include: config
+                .extend_include
+                .into_iter()
+                .chain(self.extend_include.into_iter())
+                .collect(),
             extend_select: config
                 .extend_select
                 .into_iter()
@@ -219,7 +293,13 @@ impl Configuration {
                 .ignore_init_module_imports
                 .or(config.ignore_init_module_imports),
             line_length: self.line_length.or(config.line_length),
+            max_file_size: self.max_file_size.or(config.max_file_size),
             namespace_packages: self.namespace_packages.or(config.namespace_packages),
+            overrides: config
+                .overrides
+                .into_iter()
+                .chain(self.overrides.into_iter())
+                .collect(),
             per_file_ignores: self.per_file_ignores.or(config.per_file_ignores),
             required_version: self.required_version.or(config.required_version),
             respect_gitignore: self.respect_gitignore.or(config.respect_gitignore),
@@ -235,13 +315,18 @@ impl Configuration {
             flake8_annotations: self.flake8_annotations.or(config.flake8_annotations),
             flake8_bandit: self.flake8_bandit.or(config.flake8_bandit),
             flake8_bugbear: self.flake8_bugbear.or(config.flake8_bugbear),
+            flake8_datetimez: self.flake8_datetimez.or(config.flake8_datetimez),
+            flake8_debugger: self.flake8_debugger.or(config.flake8_debugger),
             flake8_errmsg: self.flake8_errmsg.or(config.flake8_errmsg),
             flake8_import_conventions: self
                 .flake8_import_conventions
                 .or(config.flake8_import_conventions),
+            flake8_no_pep420: self.flake8_no_pep420.or(config.flake8_no_pep420),
+            flake8_print: self.flake8_print.or(config.flake8_print),
             flake8_pytest_style: self.flake8_pytest_style.or(config.flake8_pytest_style),
             flake8_quotes: self.flake8_quotes.or(config.flake8_quotes),
             flake8_tidy_imports: self.flake8_tidy_imports.or(config.flake8_tidy_imports),
+            flake8_todos: self.flake8_todos.or(config.flake8_todos),
             flake8_unused_arguments: self
                 .flake8_unused_arguments
                 .or(config.flake8_unused_arguments),
@@ -250,8 +335,10 @@ impl Configuration {
             pep8_naming: self.pep8_naming.or(config.pep8_naming),
             pycodestyle: self.pycodestyle.or(config.pycodestyle),
             pydocstyle: self.pydocstyle.or(config.pydocstyle),
+            pygrep_hooks: self.pygrep_hooks.or(config.pygrep_hooks),
             pylint: self.pylint.or(config.pylint),
             pyupgrade: self.pyupgrade.or(config.pyupgrade),
+            ruff: self.ruff.or(config.ruff),
         }
     }
 }
@@ -9,8 +9,9 @@ use super::Settings;
 use crate::registry::RuleCodePrefix;
 use crate::rules::{
     flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pylint, pyupgrade,
+    flake8_no_pep420, flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_todos,
+    flake8_unused_arguments, isort, mccabe, pep8_naming, pycodestyle, pydocstyle, pygrep_hooks,
+    pylint, pyupgrade, ruff,
 };
 
 pub const PREFIXES: &[RuleCodePrefix] = &[RuleCodePrefix::E, RuleCodePrefix::F];
@@ -53,16 +54,21 @@ impl Default for Settings {
         Self {
             rules: PREFIXES.iter().flat_map(RuleCodePrefix::codes).into(),
             allowed_confusables: FxHashSet::from_iter([]).into(),
+            allowed_locales: FxHashSet::from_iter([]).into(),
+            max_confusables_per_token: None,
             builtins: vec![],
             dummy_variable_rgx: DUMMY_VARIABLE_RGX.clone().into(),
             exclude: HashableGlobSet::new(EXCLUDE.clone()).unwrap(),
             extend_exclude: HashableGlobSet::empty(),
+            extend_include: HashableGlobSet::empty(),
             external: HashableHashSet::default(),
             force_exclude: false,
             ignore_init_module_imports: false,
             line_length: LINE_LENGTH,
+            max_file_size: None,
             namespace_packages: vec![],
             per_file_ignores: vec![],
+            overrides: vec![],
             required_version: None,
             respect_gitignore: true,
             show_source: false,
@@ -75,17 +81,21 @@ impl Default for Settings {
             flake8_bugbear: flake8_bugbear::settings::Settings::default(),
             flake8_errmsg: flake8_errmsg::settings::Settings::default(),
             flake8_import_conventions: flake8_import_conventions::settings::Settings::default(),
+            flake8_no_pep420: flake8_no_pep420::settings::Settings::default(),
             flake8_pytest_style: flake8_pytest_style::settings::Settings::default(),
             flake8_quotes: flake8_quotes::settings::Settings::default(),
             flake8_tidy_imports: flake8_tidy_imports::Settings::default(),
+            flake8_todos: flake8_todos::settings::Settings::default(),
             flake8_unused_arguments: flake8_unused_arguments::settings::Settings::default(),
             isort: isort::settings::Settings::default(),
             mccabe: mccabe::settings::Settings::default(),
             pep8_naming: pep8_naming::settings::Settings::default(),
             pycodestyle: pycodestyle::settings::Settings::default(),
             pydocstyle: pydocstyle::settings::Settings::default(),
+            pygrep_hooks: pygrep_hooks::settings::Settings::default(),
             pylint: pylint::settings::Settings::default(),
             pyupgrade: pyupgrade::settings::Settings::default(),
+            ruff: ruff::settings::Settings::default(),
         }
     }
 }
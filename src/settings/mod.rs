@@ -16,11 +16,12 @@ use self::rule_table::RuleTable;
 use crate::cache::cache_dir;
 use crate::registry::{Rule, RuleCodePrefix, SuffixLength, CATEGORIES, INCOMPATIBLE_CODES};
 use crate::rules::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pylint, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_datetimez, flake8_debugger,
+    flake8_errmsg, flake8_import_conventions, flake8_no_pep420, flake8_print, flake8_pytest_style,
+    flake8_quotes, flake8_tidy_imports, flake8_todos, flake8_unused_arguments, isort, mccabe,
+    pep8_naming, pycodestyle, pydocstyle, pygrep_hooks, pylint, pyupgrade, ruff,
 };
-use crate::settings::configuration::Configuration;
+use crate::settings::configuration::{Configuration, Override as OverrideConfiguration};
 use crate::settings::types::{PerFileIgnore, PythonVersion, SerializationFormat, Version};
 use crate::warn_user_once;
 
@@ -80,6 +81,7 @@ pub struct Settings {
         HashableGlobMatcher,
         HashableHashSet<Rule>,
     )>,
+    pub overrides: Vec<Override>,
 
     pub show_source: bool,
     pub target_version: PythonVersion,
@@ -87,17 +89,21 @@ pub struct Settings {
     // Resolver settings
     pub exclude: HashableGlobSet,
     pub extend_exclude: HashableGlobSet,
+    pub extend_include: HashableGlobSet,
     pub force_exclude: bool,
     pub respect_gitignore: bool,
     pub required_version: Option<Version>,
 
     // Rule-specific settings
     pub allowed_confusables: HashableHashSet<char>,
+    pub allowed_locales: HashableHashSet<String>,
+    pub max_confusables_per_token: Option<usize>,
     pub builtins: Vec<String>,
     pub dummy_variable_rgx: HashableRegex,
     pub external: HashableHashSet<String>,
     pub ignore_init_module_imports: bool,
     pub line_length: usize,
+    pub max_file_size: Option<usize>,
     pub namespace_packages: Vec<PathBuf>,
     pub src: Vec<PathBuf>,
     pub task_tags: Vec<String>,
@@ -106,38 +112,76 @@ pub struct Settings {
     pub flake8_annotations: flake8_annotations::settings::Settings,
     pub flake8_bandit: flake8_bandit::settings::Settings,
     pub flake8_bugbear: flake8_bugbear::settings::Settings,
+    pub flake8_datetimez: flake8_datetimez::settings::Settings,
+    pub flake8_debugger: flake8_debugger::settings::Settings,
     pub flake8_errmsg: flake8_errmsg::settings::Settings,
     pub flake8_import_conventions: flake8_import_conventions::settings::Settings,
+    pub flake8_no_pep420: flake8_no_pep420::settings::Settings,
+    pub flake8_print: flake8_print::settings::Settings,
     pub flake8_pytest_style: flake8_pytest_style::settings::Settings,
     pub flake8_quotes: flake8_quotes::settings::Settings,
     pub flake8_tidy_imports: flake8_tidy_imports::Settings,
+    pub flake8_todos: flake8_todos::settings::Settings,
     pub flake8_unused_arguments: flake8_unused_arguments::settings::Settings,
     pub isort: isort::settings::Settings,
     pub mccabe: mccabe::settings::Settings,
     pub pep8_naming: pep8_naming::settings::Settings,
     pub pycodestyle: pycodestyle::settings::Settings,
     pub pydocstyle: pydocstyle::settings::Settings,
+    pub pygrep_hooks: pygrep_hooks::settings::Settings,
     pub pylint: pylint::settings::Settings,
     pub pyupgrade: pyupgrade::settings::Settings,
+    pub ruff: ruff::settings::Settings,
+}
+
+/// A glob-scoped subset of `select`/`ignore`/`target-version`, resolved from
+/// a `[[tool.ruff.overrides]]` block. For files matched by `include`, the
+/// override's `rules` and `target_version` take the place of the base
+/// configuration's.
+#[derive(Debug, Hash)]
+pub struct Override {
+    pub include: HashableGlobSet,
+    pub rules: RuleTable,
+    pub target_version: PythonVersion,
 }
 
 impl Settings {
     pub fn from_configuration(config: Configuration, project_root: &Path) -> Result<Self> {
+        let overrides = resolve_overrides(&config)?;
+
+        let mut rules = build_rule_table(
+            config.fixable.clone(),
+            config.unfixable.clone(),
+            config.select.clone(),
+            config.ignore.clone(),
+            &config.extend_select,
+            &config.extend_ignore,
+            &config.pydocstyle,
+        );
+        // Ensure that any rule enabled by an override is available to run, even for
+        // files that wouldn't otherwise enable it under the base configuration.
+        for over in &overrides {
+            for code in over.rules.iter_enabled() {
+                if !rules.enabled(code) {
+                    rules.enable(code.clone(), over.rules.should_fix(code));
+                }
+            }
+        }
+
         Ok(Self {
-            rules: build_rule_table(
-                config.fixable,
-                config.unfixable,
-                config.select,
-                config.ignore,
-                &config.extend_select,
-                &config.extend_ignore,
-                &config.pydocstyle,
-            ),
+            rules,
+            overrides,
             allowed_confusables: config
                 .allowed_confusables
                 .map(FxHashSet::from_iter)
                 .unwrap_or_default()
                 .into(),
+            allowed_locales: config
+                .allowed_locales
+                .map(FxHashSet::from_iter)
+                .unwrap_or_default()
+                .into(),
+            max_confusables_per_token: config.max_confusables_per_token,
             builtins: config.builtins.unwrap_or_default(),
             dummy_variable_rgx: config
                 .dummy_variable_rgx
@@ -147,12 +191,14 @@ impl Settings {
                 config.exclude.unwrap_or_else(|| defaults::EXCLUDE.clone()),
             )?,
             extend_exclude: HashableGlobSet::new(config.extend_exclude)?,
+            extend_include: HashableGlobSet::new(config.extend_include)?,
             external: FxHashSet::from_iter(config.external.unwrap_or_default()).into(),
 
             force_exclude: config.force_exclude.unwrap_or(false),
 
             ignore_init_module_imports: config.ignore_init_module_imports.unwrap_or_default(),
             line_length: config.line_length.unwrap_or(defaults::LINE_LENGTH),
+            max_file_size: config.max_file_size,
             namespace_packages: config.namespace_packages.unwrap_or_default(),
             per_file_ignores: resolve_per_file_ignores(
                 config.per_file_ignores.unwrap_or_default(),
@@ -178,11 +224,15 @@ impl Settings {
                 .unwrap_or_default(),
             flake8_bandit: config.flake8_bandit.map(Into::into).unwrap_or_default(),
             flake8_bugbear: config.flake8_bugbear.map(Into::into).unwrap_or_default(),
+            flake8_datetimez: config.flake8_datetimez.map(Into::into).unwrap_or_default(),
+            flake8_debugger: config.flake8_debugger.map(Into::into).unwrap_or_default(),
             flake8_errmsg: config.flake8_errmsg.map(Into::into).unwrap_or_default(),
             flake8_import_conventions: config
                 .flake8_import_conventions
                 .map(Into::into)
                 .unwrap_or_default(),
+            flake8_no_pep420: config.flake8_no_pep420.map(Into::into).unwrap_or_default(),
+            flake8_print: config.flake8_print.map(Into::into).unwrap_or_default(),
             flake8_pytest_style: config
                 .flake8_pytest_style
                 .map(Into::into)
@@ -192,6 +242,7 @@ impl Settings {
                 .flake8_tidy_imports
                 .map(Into::into)
                 .unwrap_or_default(),
+            flake8_todos: config.flake8_todos.map(Into::into).unwrap_or_default(),
             flake8_unused_arguments: config
                 .flake8_unused_arguments
                 .map(Into::into)
@@ -201,8 +252,10 @@ impl Settings {
             pep8_naming: config.pep8_naming.map(Into::into).unwrap_or_default(),
             pycodestyle: config.pycodestyle.map(Into::into).unwrap_or_default(),
             pydocstyle: config.pydocstyle.map(Into::into).unwrap_or_default(),
+            pygrep_hooks: config.pygrep_hooks.map(Into::into).unwrap_or_default(),
             pylint: config.pylint.map(Into::into).unwrap_or_default(),
             pyupgrade: config.pyupgrade.map(Into::into).unwrap_or_default(),
+            ruff: config.ruff.map(Into::into).unwrap_or_default(),
         })
     }
 
@@ -252,7 +305,7 @@ fn build_rule_table(
         ignore: &unfixable.unwrap_or_default(),
     }]);
 
-    for code in validate_enabled(resolve_codes(
+    for code in resolve_incompatible_codes(resolve_codes(
         [RuleCodeSpec {
             select: &select.unwrap_or_else(|| defaults::PREFIXES.to_vec()),
             ignore: &ignore.unwrap_or_default(),
@@ -311,6 +364,36 @@ pub fn resolve_per_file_ignores(
         .collect()
 }
 
+/// Resolve the `[[tool.ruff.overrides]]` blocks in a `Configuration` into
+/// glob-scoped `RuleTable`s and target versions, each inheriting whatever
+/// `select`/`ignore`/`target-version` is left unset from the base
+/// configuration.
+fn resolve_overrides(config: &Configuration) -> Result<Vec<Override>> {
+    config
+        .overrides
+        .iter()
+        .map(|over: &OverrideConfiguration| {
+            let rules = build_rule_table(
+                config.fixable.clone(),
+                config.unfixable.clone(),
+                over.select.clone().or_else(|| config.select.clone()),
+                over.ignore.clone().or_else(|| config.ignore.clone()),
+                &config.extend_select,
+                &config.extend_ignore,
+                &config.pydocstyle,
+            );
+            Ok(Override {
+                include: HashableGlobSet::new(over.patterns.clone())?,
+                rules,
+                target_version: over
+                    .target_version
+                    .or(config.target_version)
+                    .unwrap_or(defaults::TARGET_VERSION),
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 struct RuleCodeSpec<'a> {
     select: &'a [RuleCodePrefix],
@@ -347,11 +430,13 @@ fn resolve_codes<'a>(specs: impl IntoIterator<Item = RuleCodeSpec<'a>>) -> FxHas
     codes
 }
 
-/// Warn if the set of enabled codes contains any incompatibilities.
-fn validate_enabled(enabled: FxHashSet<Rule>) -> FxHashSet<Rule> {
-    for (a, b, message) in INCOMPATIBLE_CODES {
-        if enabled.contains(a) && enabled.contains(b) {
+/// Drop the alternative half of any known-incompatible pair of enabled codes, preferring the
+/// convention-consistent code, and warn about the resolution.
+fn resolve_incompatible_codes(mut enabled: FxHashSet<Rule>) -> FxHashSet<Rule> {
+    for (preferred, alternative, message) in INCOMPATIBLE_CODES {
+        if enabled.contains(preferred) && enabled.contains(alternative) {
             warn_user_once!("{}", message);
+            enabled.remove(alternative);
         }
     }
     enabled
@@ -359,10 +444,14 @@ fn validate_enabled(enabled: FxHashSet<Rule>) -> FxHashSet<Rule> {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use rustc_hash::FxHashSet;
 
     use crate::registry::{Rule, RuleCodePrefix};
-    use crate::settings::{resolve_codes, RuleCodeSpec};
+    use crate::settings::configuration::{Configuration, Override as ConfigurationOverride};
+    use crate::settings::types::FilePattern;
+    use crate::settings::{resolve_codes, resolve_overrides, RuleCodeSpec};
 
     #[test]
     fn rule_codes() {
@@ -428,4 +517,61 @@ mod tests {
         let expected = FxHashSet::from_iter([Rule::NoNewLineAtEndOfFile]);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn overrides_inherit_unset_base_fields() {
+        let config = Configuration {
+            select: Some(vec![RuleCodePrefix::E]),
+            overrides: vec![ConfigurationOverride {
+                patterns: vec![FilePattern::User(
+                    "tests/**/*.py".to_string(),
+                    PathBuf::from("/project/tests/**/*.py"),
+                )],
+                select: None,
+                ignore: Some(vec![RuleCodePrefix::E501]),
+                ..ConfigurationOverride::default()
+            }],
+            ..Configuration::default()
+        };
+
+        let overrides = resolve_overrides(&config).unwrap();
+        assert_eq!(overrides.len(), 1);
+        // The override didn't specify a `select`, so it inherits the base `select`...
+        assert!(overrides[0].rules.enabled(&Rule::MultipleImportsOnOneLine));
+        // ...minus whatever the override's own `ignore` excludes.
+        assert!(!overrides[0].rules.enabled(&Rule::LineTooLong));
+    }
+
+    #[test]
+    fn override_target_version_falls_back_to_base() {
+        use crate::settings::types::PythonVersion;
+
+        let config = Configuration {
+            target_version: Some(PythonVersion::Py37),
+            overrides: vec![
+                ConfigurationOverride {
+                    patterns: vec![FilePattern::User(
+                        "services/**/*.py".to_string(),
+                        PathBuf::from("/project/services/**/*.py"),
+                    )],
+                    target_version: Some(PythonVersion::Py311),
+                    ..ConfigurationOverride::default()
+                },
+                ConfigurationOverride {
+                    patterns: vec![FilePattern::User(
+                        "lib/**/*.py".to_string(),
+                        PathBuf::from("/project/lib/**/*.py"),
+                    )],
+                    ..ConfigurationOverride::default()
+                },
+            ],
+            ..Configuration::default()
+        };
+
+        let overrides = resolve_overrides(&config).unwrap();
+        assert_eq!(overrides[0].target_version, PythonVersion::Py311);
+        // The second override didn't specify its own `target-version`, so it
+        // falls back to the base configuration's.
+        assert_eq!(overrides[1].target_version, PythonVersion::Py37);
+    }
 }
@@ -7,9 +7,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::registry::RuleCodePrefix;
 use crate::rules::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pylint, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_datetimez, flake8_debugger,
+    flake8_errmsg, flake8_import_conventions, flake8_no_pep420, flake8_print, flake8_pytest_style,
+    flake8_quotes, flake8_tidy_imports, flake8_todos, flake8_unused_arguments, isort, mccabe,
+    pep8_naming, pycodestyle, pydocstyle, pygrep_hooks, pylint, pyupgrade, ruff,
 };
 use crate::settings::types::{PythonVersion, SerializationFormat, Version};
 
@@ -30,6 +31,37 @@ pub struct Options {
     /// A list of allowed "confusable" Unicode characters to ignore when
     /// enforcing `RUF001`, `RUF002`, and `RUF003`.
     pub allowed_confusables: Option<Vec<char>>,
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow Cyrillic characters (e.g., in comments and docstrings) for a
+            # Russian-language codebase.
+            allowed-locales = ["ru"]
+        "#
+    )]
+    /// A list of locales (e.g., `"ru"` for Russian, `"el"` for Greek) whose
+    /// native scripts should be exempted from `RUF001`, `RUF002`, and
+    /// `RUF003`. Useful for codebases that intentionally write comments,
+    /// docstrings, or strings in a non-Latin script.
+    pub allowed_locales: Option<Vec<String>>,
+    #[option(
+        default = "None",
+        value_type = "usize",
+        example = r#"
+            # Don't flag confusable characters in a string, docstring, or comment
+            # that contains more than 3 of them, since that's a signal that the
+            # text is intentionally written in a non-Latin script rather than
+            # smuggling in a single ambiguous character.
+            max-confusables-per-token = 3
+        "#
+    )]
+    /// The maximum number of "confusable" Unicode characters that Ruff will
+    /// tolerate within a single string, docstring, or comment before
+    /// concluding that they're intentional (e.g., non-English text) and
+    /// skipping `RUF001`, `RUF002`, and `RUF003` for that token entirely.
+    /// Unset by default (no threshold; every confusable is flagged).
+    pub max_confusables_per_token: Option<usize>,
     #[option(
         default = r#"[]"#,
         value_type = "Vec<String>",
@@ -150,6 +182,23 @@ pub struct Options {
     /// recommended to only use `extend-ignore` when extending a
     /// `pyproject.toml` file via `extend`.
     pub extend_ignore: Option<Vec<RuleCodePrefix>>,
+    #[option(
+        default = "[]",
+        value_type = "Vec<String>",
+        example = r#"
+            # Also lint `.pyw` scripts and `.py.tpl` templates.
+            extend-include = ["*.pyw", "*.py.tpl"]
+        "#
+    )]
+    /// A list of file patterns to lint in addition to the default set of
+    /// `.py`/`.pyi` files, e.g., to opt in to linting `.pyw` scripts or
+    /// Bazel `.bzl` files.
+    ///
+    /// Inclusions are based on globs, and should generally be file paths, or
+    /// globs that map to a single file, like `*.pyw` or `*.py.tpl`. For
+    /// directory patterns, prefer `exclude`/`extend-exclude` to omit
+    /// unwanted directories instead.
+    pub extend_include: Option<Vec<String>>,
     #[option(
         default = "[]",
         value_type = "Vec<RuleCodePrefix>",
@@ -270,6 +319,23 @@ pub struct Options {
     /// The line length to use when enforcing long-lines violations (like
     /// `E501`).
     pub line_length: Option<usize>,
+    #[option(
+        default = "None",
+        value_type = "usize",
+        example = r#"
+            # Ignore files larger than 500 KB.
+            max-file-size = 500000
+        "#
+    )]
+    /// The maximum size (in bytes) that a file can be before Ruff skips it
+    /// entirely instead of parsing and linting it. A skipped file is reported
+    /// with an `E902` diagnostic if that rule is enabled, and silently
+    /// otherwise.
+    /// The size is checked against file metadata before the file is read,
+    /// so an oversized file is never loaded into memory. Only applies to
+    /// files read from disk; content piped in via stdin is not size-checked.
+    /// Unset by default (no limit).
+    pub max_file_size: Option<usize>,
     #[option(
         default = "None",
         value_type = "String",
@@ -429,6 +495,12 @@ pub struct Options {
     /// Options for the `flake8-bugbear` plugin.
     pub flake8_bugbear: Option<flake8_bugbear::settings::Options>,
     #[option_group]
+    /// Options for the `flake8-datetimez` plugin.
+    pub flake8_datetimez: Option<flake8_datetimez::settings::Options>,
+    #[option_group]
+    /// Options for the `flake8-debugger` plugin.
+    pub flake8_debugger: Option<flake8_debugger::settings::Options>,
+    #[option_group]
     /// Options for the `flake8-errmsg` plugin.
     pub flake8_errmsg: Option<flake8_errmsg::settings::Options>,
     #[option_group]
@@ -441,9 +513,18 @@ pub struct Options {
     /// Options for the `flake8-import-conventions` plugin.
     pub flake8_import_conventions: Option<flake8_import_conventions::settings::Options>,
     #[option_group]
+    /// Options for the `flake8-no-pep420` plugin.
+    pub flake8_no_pep420: Option<flake8_no_pep420::settings::Options>,
+    #[option_group]
+    /// Options for the `flake8-print` plugin.
+    pub flake8_print: Option<flake8_print::settings::Options>,
+    #[option_group]
     /// Options for the `flake8-pytest-style` plugin.
     pub flake8_pytest_style: Option<flake8_pytest_style::settings::Options>,
     #[option_group]
+    /// Options for the `flake8-todos` plugin.
+    pub flake8_todos: Option<flake8_todos::settings::Options>,
+    #[option_group]
     /// Options for the `flake8-unused-arguments` plugin.
     pub flake8_unused_arguments: Option<flake8_unused_arguments::settings::Options>,
     #[option_group]
@@ -462,11 +543,17 @@ pub struct Options {
     /// Options for the `pydocstyle` plugin.
     pub pydocstyle: Option<pydocstyle::settings::Options>,
     #[option_group]
+    /// Options for the `pygrep-hooks` plugin.
+    pub pygrep_hooks: Option<pygrep_hooks::settings::Options>,
+    #[option_group]
     /// Options for the `pylint` plugin.
     pub pylint: Option<pylint::settings::Options>,
     #[option_group]
     /// Options for the `pyupgrade` plugin.
     pub pyupgrade: Option<pyupgrade::settings::Options>,
+    #[option_group]
+    /// Options for the `Ruff`-specific rules.
+    pub ruff: Option<ruff::settings::Options>,
     // Tables are required to go last.
     #[option(
         default = "{}",
@@ -481,4 +568,62 @@ pub struct Options {
     /// A list of mappings from file pattern to rule codes or prefixes to
     /// exclude, when considering any matching files.
     pub per_file_ignores: Option<FxHashMap<String, Vec<RuleCodePrefix>>>,
+    #[option(
+        default = "[]",
+        value_type = "Vec<Override>",
+        example = r#"
+            # Relax the rule set for tests.
+            [[tool.ruff.overrides]]
+            files = ["tests/**/*.py"]
+            ignore = ["E501"]
+        "#
+    )]
+    /// A list of `[[tool.ruff.overrides]]` blocks, each pairing a list of
+    /// file patterns with a `select`/`ignore`/`target-version` override to
+    /// apply to any matching files, in place of the top-level settings of
+    /// the same name.
+    pub overrides: Option<Vec<Override>>,
+}
+
+#[derive(
+    Debug, PartialEq, Eq, Default, Serialize, Deserialize, ConfigurationOptions, JsonSchema,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Override {
+    #[option(
+        default = "[]",
+        value_type = "Vec<String>",
+        example = r#"files = ["tests/**/*.py"]"#
+    )]
+    /// A list of file patterns to match against, relative to the project
+    /// root, that determine which files this override applies to. Uses the
+    /// same glob syntax as `include`/`exclude`.
+    pub files: Vec<String>,
+    #[option(
+        default = "None",
+        value_type = "Vec<RuleCodePrefix>",
+        example = r#"select = ["E", "F"]"#
+    )]
+    /// A list of rule codes or prefixes to enable, in place of the top-level
+    /// `select`, for files matched by `files`. Leave unset to keep the
+    /// top-level `select`.
+    pub select: Option<Vec<RuleCodePrefix>>,
+    #[option(
+        default = "None",
+        value_type = "Vec<RuleCodePrefix>",
+        example = r#"ignore = ["E501"]"#
+    )]
+    /// A list of rule codes or prefixes to ignore, in place of the top-level
+    /// `ignore`, for files matched by `files`. Leave unset to keep the
+    /// top-level `ignore`.
+    pub ignore: Option<Vec<RuleCodePrefix>>,
+    #[option(
+        default = "None",
+        value_type = "PythonVersion",
+        example = r#"target-version = "py37""#
+    )]
+    /// The Python version to target, in place of the top-level
+    /// `target-version`, for files matched by `files`. Leave unset to keep
+    /// the top-level `target-version`.
+    pub target_version: Option<PythonVersion>,
 }
@@ -134,8 +134,8 @@ mod tests {
     use crate::rules::flake8_tidy_imports::banned_api::ApiBan;
     use crate::rules::flake8_tidy_imports::relative_imports::Strictness;
     use crate::rules::{
-        flake8_bugbear, flake8_errmsg, flake8_import_conventions, flake8_pytest_style,
-        flake8_quotes, flake8_tidy_imports, mccabe, pep8_naming,
+        flake8_bugbear, flake8_datetimez, flake8_errmsg, flake8_import_conventions,
+        flake8_pytest_style, flake8_quotes, flake8_tidy_imports, mccabe, pep8_naming,
     };
     use crate::settings::pyproject::{
         find_settings_toml, parse_pyproject_toml, Options, Pyproject, Tools,
@@ -165,6 +165,8 @@ mod tests {
             Some(Tools {
                 ruff: Some(Options {
                     allowed_confusables: None,
+                    allowed_locales: None,
+                    max_confusables_per_token: None,
                     builtins: None,
                     cache_dir: None,
                     dummy_variable_rgx: None,
@@ -182,8 +184,11 @@ mod tests {
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    max_file_size: None,
+                    extend_include: None,
                     namespace_packages: None,
                     per_file_ignores: None,
+                    overrides: None,
                     required_version: None,
                     respect_gitignore: None,
                     select: None,
@@ -197,11 +202,16 @@ mod tests {
                     flake8_annotations: None,
                     flake8_bandit: None,
                     flake8_bugbear: None,
+                    flake8_datetimez: None,
+                    flake8_debugger: None,
                     flake8_errmsg: None,
                     flake8_pytest_style: None,
                     flake8_quotes: None,
                     flake8_tidy_imports: None,
                     flake8_import_conventions: None,
+                    flake8_no_pep420: None,
+                    flake8_print: None,
+                    flake8_todos: None,
                     flake8_unused_arguments: None,
                     isort: None,
                     mccabe: None,
@@ -210,6 +220,8 @@ mod tests {
                     pydocstyle: None,
                     pylint: None,
                     pyupgrade: None,
+                    ruff: None,
+                    pygrep_hooks: None,
                 })
             })
         );
@@ -226,6 +238,8 @@ line-length = 79
             Some(Tools {
                 ruff: Some(Options {
                     allowed_confusables: None,
+                    allowed_locales: None,
+                    max_confusables_per_token: None,
                     builtins: None,
                     dummy_variable_rgx: None,
                     exclude: None,
@@ -242,8 +256,11 @@ line-length = 79
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: Some(79),
+                    max_file_size: None,
+                    extend_include: None,
                     namespace_packages: None,
                     per_file_ignores: None,
+                    overrides: None,
                     respect_gitignore: None,
                     required_version: None,
                     select: None,
@@ -258,11 +275,16 @@ line-length = 79
                     flake8_annotations: None,
                     flake8_bandit: None,
                     flake8_bugbear: None,
+                    flake8_datetimez: None,
+                    flake8_debugger: None,
                     flake8_errmsg: None,
                     flake8_pytest_style: None,
                     flake8_quotes: None,
                     flake8_tidy_imports: None,
                     flake8_import_conventions: None,
+                    flake8_no_pep420: None,
+                    flake8_print: None,
+                    flake8_todos: None,
                     flake8_unused_arguments: None,
                     isort: None,
                     mccabe: None,
@@ -271,6 +293,8 @@ line-length = 79
                     pydocstyle: None,
                     pylint: None,
                     pyupgrade: None,
+                    ruff: None,
+                    pygrep_hooks: None,
                 })
             })
         );
@@ -287,6 +311,8 @@ exclude = ["foo.py"]
             Some(Tools {
                 ruff: Some(Options {
                     allowed_confusables: None,
+                    allowed_locales: None,
+                    max_confusables_per_token: None,
                     builtins: None,
                     cache_dir: None,
                     dummy_variable_rgx: None,
@@ -304,8 +330,11 @@ exclude = ["foo.py"]
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    max_file_size: None,
+                    extend_include: None,
                     namespace_packages: None,
                     per_file_ignores: None,
+                    overrides: None,
                     required_version: None,
                     respect_gitignore: None,
                     select: None,
@@ -319,11 +348,16 @@ exclude = ["foo.py"]
                     flake8_annotations: None,
                     flake8_bandit: None,
                     flake8_bugbear: None,
+                    flake8_datetimez: None,
+                    flake8_debugger: None,
                     flake8_errmsg: None,
                     flake8_pytest_style: None,
                     flake8_quotes: None,
                     flake8_tidy_imports: None,
                     flake8_import_conventions: None,
+                    flake8_no_pep420: None,
+                    flake8_print: None,
+                    flake8_todos: None,
                     flake8_unused_arguments: None,
                     isort: None,
                     mccabe: None,
@@ -332,6 +366,8 @@ exclude = ["foo.py"]
                     pydocstyle: None,
                     pylint: None,
                     pyupgrade: None,
+                    ruff: None,
+                    pygrep_hooks: None,
                 })
             })
         );
@@ -348,6 +384,8 @@ select = ["E501"]
             Some(Tools {
                 ruff: Some(Options {
                     allowed_confusables: None,
+                    allowed_locales: None,
+                    max_confusables_per_token: None,
                     builtins: None,
                     cache_dir: None,
                     dummy_variable_rgx: None,
@@ -365,8 +403,11 @@ select = ["E501"]
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    max_file_size: None,
+                    extend_include: None,
                     namespace_packages: None,
                     per_file_ignores: None,
+                    overrides: None,
                     required_version: None,
                     respect_gitignore: None,
                     select: Some(vec![RuleCodePrefix::E501]),
@@ -380,11 +421,16 @@ select = ["E501"]
                     flake8_annotations: None,
                     flake8_bandit: None,
                     flake8_bugbear: None,
+                    flake8_datetimez: None,
+                    flake8_debugger: None,
                     flake8_errmsg: None,
                     flake8_pytest_style: None,
                     flake8_quotes: None,
                     flake8_tidy_imports: None,
                     flake8_import_conventions: None,
+                    flake8_no_pep420: None,
+                    flake8_print: None,
+                    flake8_todos: None,
                     flake8_unused_arguments: None,
                     isort: None,
                     mccabe: None,
@@ -393,6 +439,8 @@ select = ["E501"]
                     pydocstyle: None,
                     pylint: None,
                     pyupgrade: None,
+                    ruff: None,
+                    pygrep_hooks: None,
                 })
             })
         );
@@ -410,6 +458,8 @@ ignore = ["E501"]
             Some(Tools {
                 ruff: Some(Options {
                     allowed_confusables: None,
+                    allowed_locales: None,
+                    max_confusables_per_token: None,
                     builtins: None,
                     cache_dir: None,
                     dummy_variable_rgx: None,
@@ -427,8 +477,11 @@ ignore = ["E501"]
                     ignore: Some(vec![RuleCodePrefix::E501]),
                     ignore_init_module_imports: None,
                     line_length: None,
+                    max_file_size: None,
+                    extend_include: None,
                     namespace_packages: None,
                     per_file_ignores: None,
+                    overrides: None,
                     required_version: None,
                     respect_gitignore: None,
                     select: None,
@@ -442,11 +495,16 @@ ignore = ["E501"]
                     flake8_annotations: None,
                     flake8_bandit: None,
                     flake8_bugbear: None,
+                    flake8_datetimez: None,
+                    flake8_debugger: None,
                     flake8_errmsg: None,
                     flake8_pytest_style: None,
                     flake8_quotes: None,
                     flake8_tidy_imports: None,
                     flake8_import_conventions: None,
+                    flake8_no_pep420: None,
+                    flake8_print: None,
+                    flake8_todos: None,
                     flake8_unused_arguments: None,
                     isort: None,
                     mccabe: None,
@@ -455,6 +513,8 @@ ignore = ["E501"]
                     pydocstyle: None,
                     pylint: None,
                     pyupgrade: None,
+                    ruff: None,
+                    pygrep_hooks: None,
                 })
             })
         );
@@ -506,6 +566,8 @@ other-attribute = 1
             config,
             Options {
                 allowed_confusables: Some(vec!['−', 'ρ', '∗']),
+                allowed_locales: None,
+                max_confusables_per_token: None,
                 builtins: None,
                 line_length: Some(88),
                 fix: None,
@@ -526,6 +588,8 @@ other-attribute = 1
                 fixable: None,
                 format: None,
                 force_exclude: None,
+                max_file_size: None,
+                extend_include: None,
                 namespace_packages: None,
                 unfixable: None,
                 typing_modules: None,
@@ -536,6 +600,7 @@ other-attribute = 1
                     "__init__.py".to_string(),
                     vec![RuleCodePrefix::F401]
                 )])),
+                overrides: None,
                 dummy_variable_rgx: None,
                 respect_gitignore: None,
                 required_version: None,
@@ -550,6 +615,10 @@ other-attribute = 1
                         "fastapi.Query".to_string(),
                     ]),
                 }),
+                flake8_datetimez: Some(flake8_datetimez::settings::Options {
+                    exempt_time_freezing_calls: Some(vec!["freezegun.freeze_time".to_string()]),
+                }),
+                flake8_debugger: None,
                 flake8_errmsg: Some(flake8_errmsg::settings::Options {
                     max_string_length: Some(20),
                 }),
@@ -586,13 +655,15 @@ other-attribute = 1
                         (
                             "cgi".to_string(),
                             ApiBan {
-                                msg: "The cgi module is deprecated.".to_string()
+                                msg: "The cgi module is deprecated.".to_string(),
+                                replacement: None,
                             }
                         ),
                         (
                             "typing.TypedDict".to_string(),
                             ApiBan {
-                                msg: "Use typing_extensions.TypedDict instead.".to_string()
+                                msg: "Use typing_extensions.TypedDict instead.".to_string(),
+                                replacement: None,
                             }
                         )
                     ]))
@@ -606,7 +677,12 @@ other-attribute = 1
                         "dask.dataframe".to_string(),
                         "dd".to_string(),
                     )])),
+                    banned_aliases: None,
+                    banned_from: None,
                 }),
+                flake8_no_pep420: None,
+                flake8_print: None,
+                flake8_todos: None,
                 flake8_unused_arguments: None,
                 isort: None,
                 mccabe: Some(mccabe::settings::Options {
@@ -637,6 +713,8 @@ other-attribute = 1
                 pydocstyle: None,
                 pylint: None,
                 pyupgrade: None,
+                ruff: None,
+                pygrep_hooks: None,
             }
         );
 
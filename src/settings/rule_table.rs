@@ -1,4 +1,5 @@
 use std::collections::hash_map;
+use std::fmt;
 
 use rustc_hash::FxHashMap;
 
@@ -7,12 +8,23 @@ use crate::registry::Rule;
 
 /// A table to keep track of which rules are enabled
 /// and whether or not they should be autofixed.
-#[derive(Debug, Hash)]
+#[derive(Hash)]
 pub struct RuleTable {
     /// Maps rule codes to a boolean indicating if the rule should be autofixed.
     enabled: HashableHashMap<Rule, bool>,
 }
 
+impl fmt::Debug for RuleTable {
+    /// Print the enabled rule codes in sorted order, rather than as a raw hash
+    /// map, so that `--show-settings` output is diffable and makes it obvious
+    /// (e.g.) which `D` codes a `pydocstyle.convention` has disabled.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut codes: Vec<_> = self.enabled.keys().map(Rule::code).collect();
+        codes.sort_unstable();
+        f.debug_struct("RuleTable").field("enabled", &codes).finish()
+    }
+}
+
 impl RuleTable {
     /// Creates a new empty rule table.
     pub fn empty() -> Self {
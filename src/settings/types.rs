@@ -156,6 +156,7 @@ pub enum SerializationFormat {
     Grouped,
     Github,
     Gitlab,
+    Rdjson,
 }
 
 impl Default for SerializationFormat {
@@ -3,45 +3,109 @@
 use std::borrow::Cow;
 
 use once_cell::unsync::OnceCell;
-use ropey::Rope;
 use rustpython_ast::Location;
+use rustpython_parser::lexer;
+use rustpython_parser::lexer::Tok;
 
 use crate::ast::types::Range;
 
 pub struct Locator<'a> {
     contents: &'a str,
-    rope: OnceCell<Rope>,
+    /// Byte offset of the start of each physical line in `contents`, indexed by
+    /// `row - 1`. Lazily computed once, from a single pass over `contents`, and
+    /// reused for every subsequent `Location` lookup.
+    ///
+    /// Earlier versions of `Locator` built a full `ropey::Rope` copy of `contents`
+    /// on first use, so that (row, column) pairs could be translated to an offset
+    /// and sliced. That doubled peak memory for large files: the whole source was
+    /// held once as the caller's `String`/`&str` and a second time inside the
+    /// rope. This line-start index is `O(number of lines)`, not `O(file size)`,
+    /// and every slice below is a zero-copy borrow of `contents` itself.
+    line_starts: OnceCell<Vec<usize>>,
 }
 
 impl<'a> Locator<'a> {
     pub fn new(contents: &'a str) -> Self {
         Locator {
             contents,
-            rope: OnceCell::default(),
+            line_starts: OnceCell::default(),
         }
     }
 
-    fn get_or_init_rope(&self) -> &Rope {
-        self.rope.get_or_init(|| Rope::from_str(self.contents))
+    fn get_or_init_line_starts(&self) -> &[usize] {
+        self.line_starts.get_or_init(|| {
+            let mut line_starts = Vec::with_capacity(self.contents.len() / 48 + 1);
+            line_starts.push(0);
+            line_starts.extend(
+                self.contents
+                    .match_indices('\n')
+                    .map(|(index, _)| index + 1),
+            );
+            line_starts
+        })
+    }
+
+    /// Convert a `Location` to a byte offset into `contents`.
+    ///
+    /// `Location::column()` is a character offset within its row, so this is the one place
+    /// that offset should ever be combined with a row's starting byte offset: it walks the
+    /// row's characters one at a time to find the byte length of the `column` characters that
+    /// precede the target, since source lines containing multi-byte UTF-8 characters would
+    /// otherwise produce a misaligned or panicking slice.
+    fn to_byte_offset(&self, location: Location) -> usize {
+        let line_starts = self.get_or_init_line_starts();
+        let line_start = line_starts
+            .get(location.row() - 1)
+            .copied()
+            .unwrap_or(self.contents.len());
+        if location.column() == 0 {
+            return line_start;
+        }
+        self.contents[line_start..]
+            .char_indices()
+            .nth(location.column())
+            .map_or(self.contents.len(), |(offset, _)| line_start + offset)
     }
 
     pub fn slice_source_code_at(&self, location: Location) -> Cow<'_, str> {
-        let rope = self.get_or_init_rope();
-        let offset = rope.line_to_char(location.row() - 1) + location.column();
-        Cow::from(rope.slice(offset..))
+        let offset = self.to_byte_offset(location);
+        Cow::Borrowed(&self.contents[offset..])
     }
 
     pub fn slice_source_code_until(&self, location: Location) -> Cow<'_, str> {
-        let rope = self.get_or_init_rope();
-        let offset = rope.line_to_char(location.row() - 1) + location.column();
-        Cow::from(rope.slice(..offset))
+        let offset = self.to_byte_offset(location);
+        Cow::Borrowed(&self.contents[..offset])
     }
 
     pub fn slice_source_code_range(&self, range: &Range) -> Cow<'_, str> {
-        let rope = self.get_or_init_rope();
-        let start = rope.line_to_char(range.location.row() - 1) + range.location.column();
-        let end = rope.line_to_char(range.end_location.row() - 1) + range.end_location.column();
-        Cow::from(rope.slice(start..end))
+        let start = self.to_byte_offset(range.location);
+        let end = self.to_byte_offset(range.end_location);
+        Cow::Borrowed(&self.contents[start..end])
+    }
+
+    /// Returns `true` if the given `Range` contains at least one comment.
+    ///
+    /// Fix authors should check this before collapsing or rewriting a range
+    /// of source code, to avoid silently discarding a comment that appeared
+    /// within it.
+    pub fn contains_comments(&self, range: &Range) -> bool {
+        lexer::make_tokenizer(&self.slice_source_code_range(range))
+            .any(|result| result.map_or(false, |(_, tok, _)| matches!(tok, Tok::Comment(..))))
+    }
+
+    /// Returns `true` if the given `Range` contains at least one string literal that spans
+    /// multiple lines (e.g. a triple-quoted string).
+    ///
+    /// Fix authors should check this before re-indenting a range of source code with a purely
+    /// textual dedent/indent: doing so would strip or add whitespace from the *contents* of a
+    /// multi-line string literal, silently changing the value it evaluates to, not just its
+    /// on-disk formatting.
+    pub fn contains_multiline_string(&self, range: &Range) -> bool {
+        lexer::make_tokenizer(&self.slice_source_code_range(range)).any(|result| {
+            result.map_or(false, |(start, tok, end)| {
+                matches!(tok, Tok::String { .. }) && end.row() > start.row()
+            })
+        })
     }
 
     pub fn partition_source_code_at(
@@ -49,17 +113,14 @@ impl<'a> Locator<'a> {
         outer: &Range,
         inner: &Range,
     ) -> (Cow<'_, str>, Cow<'_, str>, Cow<'_, str>) {
-        let rope = self.get_or_init_rope();
-        let outer_start = rope.line_to_char(outer.location.row() - 1) + outer.location.column();
-        let outer_end =
-            rope.line_to_char(outer.end_location.row() - 1) + outer.end_location.column();
-        let inner_start = rope.line_to_char(inner.location.row() - 1) + inner.location.column();
-        let inner_end =
-            rope.line_to_char(inner.end_location.row() - 1) + inner.end_location.column();
+        let outer_start = self.to_byte_offset(outer.location);
+        let outer_end = self.to_byte_offset(outer.end_location);
+        let inner_start = self.to_byte_offset(inner.location);
+        let inner_end = self.to_byte_offset(inner.end_location);
         (
-            Cow::from(rope.slice(outer_start..inner_start)),
-            Cow::from(rope.slice(inner_start..inner_end)),
-            Cow::from(rope.slice(inner_end..outer_end)),
+            Cow::Borrowed(&self.contents[outer_start..inner_start]),
+            Cow::Borrowed(&self.contents[inner_start..inner_end]),
+            Cow::Borrowed(&self.contents[inner_end..outer_end]),
         )
     }
 }
@@ -0,0 +1,108 @@
+//! Helpers for working with the raw source text of Python string literals: parsing
+//! their prefix and quote style, and mapping offsets within their body back to a
+//! `Location` in the original file. Shared by rules that need to point a diagnostic
+//! at a specific spot inside a string, such as an invalid escape sequence (W605), or
+//! (in the future) a name inside a quoted forward-reference annotation or a doctest
+//! embedded in a docstring.
+
+use rustpython_parser::ast::Location;
+
+/// The parsed prefix, quote style, and body of a Python string literal, along with
+/// the `Location` at which `body` begins in the source file.
+pub struct StrLiteral<'a> {
+    prefix: &'a str,
+    pub body: &'a str,
+    body_start: Location,
+}
+
+impl<'a> StrLiteral<'a> {
+    /// Parse the raw source text of a string literal (e.g., as returned by
+    /// `Locator::slice_source_code_range`), given the `Location` at which `text`
+    /// itself starts.
+    pub fn new(text: &'a str, start: Location) -> Self {
+        let quote = Self::extract_quote(text);
+        let quote_pos = text.find(quote).unwrap();
+        let prefix = &text[..quote_pos];
+        let body = &text[(quote_pos + quote.len())..(text.len() - quote.len())];
+        let body_start = Location::new(start.row(), start.column() + prefix.len() + quote.len());
+        Self {
+            prefix,
+            body,
+            body_start,
+        }
+    }
+
+    /// Return the quotation marker used for a string token (e.g., `'`, `"`, `'''`,
+    /// or `"""`).
+    fn extract_quote(text: &str) -> &str {
+        for quote in ["'''", "\"\"\"", "'", "\""] {
+            if text.ends_with(quote) {
+                return quote;
+            }
+        }
+
+        panic!("Unable to find quotation mark for String token")
+    }
+
+    /// Return `true` if the literal is a raw string (e.g., `r"..."`), which can't
+    /// contain escape sequences.
+    pub fn is_raw(&self) -> bool {
+        self.prefix.to_lowercase().contains('r')
+    }
+
+    /// Return the length (in characters) of the literal's prefix (e.g., `2` for
+    /// `rb"..."`, `0` for `"..."`).
+    pub fn prefix_len(&self) -> usize {
+        self.prefix.len()
+    }
+
+    /// Map a zero-indexed `(row, column)` offset within `body` back to its
+    /// `Location` in the original file. `row` is relative to the first line of
+    /// `body` (i.e., `0` for the first line).
+    pub fn location_at(&self, row: usize, column: usize) -> Location {
+        if row == 0 {
+            Location::new(self.body_start.row(), self.body_start.column() + column)
+        } else {
+            Location::new(self.body_start.row() + row, column)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustpython_parser::ast::Location;
+
+    use crate::str::StrLiteral;
+
+    #[test]
+    fn single_quoted() {
+        let literal = StrLiteral::new(r#""foo""#, Location::new(1, 4));
+        assert_eq!(literal.body, "foo");
+        assert!(!literal.is_raw());
+        assert_eq!(literal.prefix_len(), 0);
+        assert_eq!(literal.location_at(0, 0), Location::new(1, 5));
+        assert_eq!(literal.location_at(0, 2), Location::new(1, 7));
+    }
+
+    #[test]
+    fn prefixed_and_raw() {
+        let literal = StrLiteral::new(r#"rb"foo""#, Location::new(1, 4));
+        assert_eq!(literal.body, "foo");
+        assert!(literal.is_raw());
+        assert_eq!(literal.prefix_len(), 2);
+        assert_eq!(literal.location_at(0, 0), Location::new(1, 7));
+    }
+
+    #[test]
+    fn triple_quoted_multiline() {
+        let literal = StrLiteral::new("'''\nfoo\nbar'''", Location::new(1, 4));
+        assert_eq!(literal.body, "\nfoo\nbar");
+        // The first line of `body` is empty (it's just the newline after the opening
+        // `'''`), so an offset on row 0 still resolves relative to the opening quote.
+        assert_eq!(literal.location_at(0, 0), Location::new(1, 7));
+        // An offset on a later row ignores the starting column entirely, since that
+        // line began at the start of the file's line, not partway through it.
+        assert_eq!(literal.location_at(1, 0), Location::new(2, 0));
+        assert_eq!(literal.location_at(2, 1), Location::new(3, 1));
+    }
+}
@@ -16,6 +16,17 @@ pub trait Violation: Debug + PartialEq + Eq + Serialize + DeserializeOwned {
 
     /// A placeholder instance of the violation.
     fn placeholder() -> Self;
+
+    /// A minimal snippet of Python that triggers this violation, used to
+    /// render a before/after example via `ruff --explain`. The "after" side
+    /// is never stored: it's produced by running the snippet through the
+    /// real autofixer, so the example can't drift from actual behavior.
+    /// Rules for which such a snippet wouldn't be self-contained or
+    /// meaningful (e.g., rules that depend on multi-file context) can leave
+    /// this unset.
+    fn example() -> Option<&'static str> {
+        None
+    }
 }
 
 /// This trait exists just to make implementing the [`Violation`] trait more
@@ -31,6 +42,11 @@ pub trait AlwaysAutofixableViolation:
 
     /// A placeholder instance of the violation.
     fn placeholder() -> Self;
+
+    /// See [`Violation::example`].
+    fn example() -> Option<&'static str> {
+        None
+    }
 }
 
 /// A blanket implementation.
@@ -46,6 +62,10 @@ impl<VA: AlwaysAutofixableViolation> Violation for VA {
     fn placeholder() -> Self {
         <Self as AlwaysAutofixableViolation>::placeholder()
     }
+
+    fn example() -> Option<&'static str> {
+        <Self as AlwaysAutofixableViolation>::example()
+    }
 }
 
 /// This macro just exists so that you don't have to add the `#[derive]`
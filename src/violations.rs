@@ -18,11 +18,15 @@ use crate::violation::{AlwaysAutofixableViolation, Violation};
 define_violation!(
     pub struct MultipleImportsOnOneLine;
 );
-impl Violation for MultipleImportsOnOneLine {
+impl AlwaysAutofixableViolation for MultipleImportsOnOneLine {
     fn message(&self) -> String {
         "Multiple imports on one line".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Split imports onto separate lines".to_string()
+    }
+
     fn placeholder() -> Self {
         MultipleImportsOnOneLine
     }
@@ -55,6 +59,83 @@ impl Violation for LineTooLong {
     }
 }
 
+define_violation!(
+    pub struct RedundantBackslash;
+);
+impl AlwaysAutofixableViolation for RedundantBackslash {
+    fn message(&self) -> String {
+        "Backslash is redundant between brackets".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove redundant backslash".to_string()
+    }
+
+    fn placeholder() -> Self {
+        RedundantBackslash
+    }
+}
+
+define_violation!(
+    pub struct MultipleStatementsOnOneLineColon;
+);
+impl Violation for MultipleStatementsOnOneLineColon {
+    fn message(&self) -> String {
+        "Multiple statements on one line (colon)".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MultipleStatementsOnOneLineColon
+    }
+}
+
+define_violation!(
+    pub struct MultipleStatementsOnOneLineSemicolon;
+);
+impl AlwaysAutofixableViolation for MultipleStatementsOnOneLineSemicolon {
+    fn message(&self) -> String {
+        "Multiple statements on one line (semicolon)".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Replace semicolon with a newline".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MultipleStatementsOnOneLineSemicolon
+    }
+}
+
+define_violation!(
+    pub struct UselessSemicolon;
+);
+impl AlwaysAutofixableViolation for UselessSemicolon {
+    fn message(&self) -> String {
+        "Statement ends with a semicolon".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove semicolon".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UselessSemicolon
+    }
+}
+
+define_violation!(
+    pub struct StatementOnOneLineDef;
+);
+impl Violation for StatementOnOneLineDef {
+    fn message(&self) -> String {
+        "Statement on one line (def)".to_string()
+    }
+
+    fn placeholder() -> Self {
+        StatementOnOneLineDef
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EqCmpop {
     Eq,
@@ -323,11 +404,41 @@ impl Violation for DocLineTooLong {
         format!("Doc line too long ({length} > {limit} characters)")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Wrap comment to fit within the line-length limit".to_string())
+    }
+
     fn placeholder() -> Self {
         DocLineTooLong(89, 88)
     }
 }
 
+define_violation!(
+    pub struct LineBreakBeforeBinaryOperator;
+);
+impl Violation for LineBreakBeforeBinaryOperator {
+    fn message(&self) -> String {
+        "Line break occurred before a binary operator".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineBreakBeforeBinaryOperator
+    }
+}
+
+define_violation!(
+    pub struct LineBreakAfterBinaryOperator;
+);
+impl Violation for LineBreakAfterBinaryOperator {
+    fn message(&self) -> String {
+        "Line break occurred after a binary operator".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineBreakAfterBinaryOperator
+    }
+}
+
 // pyflakes
 
 define_violation!(
@@ -755,15 +866,24 @@ impl Violation for TwoStarredExpressions {
 }
 
 define_violation!(
-    pub struct AssertTuple;
+    pub struct AssertTuple(pub bool);
 );
 impl Violation for AssertTuple {
     fn message(&self) -> String {
         "Assert test is a non-empty tuple, which is always `True`".to_string()
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let AssertTuple(fixable) = self;
+        if *fixable {
+            Some(|_| "Remove parentheses, moving the second element to the message".to_string())
+        } else {
+            None
+        }
+    }
+
     fn placeholder() -> Self {
-        AssertTuple
+        AssertTuple(true)
     }
 }
 
@@ -940,6 +1060,10 @@ impl Violation for RedefinedWhileUnused {
         format!("Redefinition of unused `{name}` from line {line}")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|RedefinedWhileUnused(name, _)| format!("Remove unused import: `{name}`"))
+    }
+
     fn placeholder() -> Self {
         RedefinedWhileUnused("...".to_string(), 1)
     }
@@ -1804,6 +1928,33 @@ impl Violation for BlindExcept {
     }
 }
 
+define_violation!(
+    pub struct BlindExceptSwallow;
+);
+impl Violation for BlindExceptSwallow {
+    fn message(&self) -> String {
+        "Do not silently swallow a bare `except:`; re-raise or narrow the exception type"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        BlindExceptSwallow
+    }
+}
+
+define_violation!(
+    pub struct BlindExceptWithoutLogging;
+);
+impl Violation for BlindExceptWithoutLogging {
+    fn message(&self) -> String {
+        "Exception is swallowed without being logged".to_string()
+    }
+
+    fn placeholder() -> Self {
+        BlindExceptWithoutLogging
+    }
+}
+
 // flake8-comprehensions
 
 define_violation!(
@@ -2118,6 +2269,42 @@ impl Violation for UnnecessaryMap {
     }
 }
 
+define_violation!(
+    pub struct UnnecessaryDictCall(pub String);
+);
+impl AlwaysAutofixableViolation for UnnecessaryDictCall {
+    fn message(&self) -> String {
+        let UnnecessaryDictCall(kind) = self;
+        format!("Unnecessary `{kind}` passed to `dict()` (remove the outer call to `dict()`)")
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove outer `dict` call".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryDictCall("(dict|dict comprehension)".to_string())
+    }
+}
+
+define_violation!(
+    pub struct UnnecessaryDictComprehensionForIterable;
+);
+impl AlwaysAutofixableViolation for UnnecessaryDictComprehensionForIterable {
+    fn message(&self) -> String {
+        "Unnecessary `dict` comprehension for iterable (rewrite using `dict.fromkeys()`)"
+            .to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Rewrite using `dict.fromkeys()`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryDictComprehensionForIterable
+    }
+}
+
 // flake8-debugger
 
 define_violation!(
@@ -2140,16 +2327,16 @@ impl Violation for Debugger {
 // mccabe
 
 define_violation!(
-    pub struct FunctionIsTooComplex(pub String, pub usize);
+    pub struct FunctionIsTooComplex(pub String, pub usize, pub usize);
 );
 impl Violation for FunctionIsTooComplex {
     fn message(&self) -> String {
-        let FunctionIsTooComplex(name, complexity) = self;
-        format!("`{name}` is too complex ({complexity})")
+        let FunctionIsTooComplex(name, complexity, max_complexity) = self;
+        format!("`{name}` is too complex ({complexity} > {max_complexity})")
     }
 
     fn placeholder() -> Self {
-        FunctionIsTooComplex("...".to_string(), 10)
+        FunctionIsTooComplex("...".to_string(), 10, 10)
     }
 }
 
@@ -2245,6 +2432,14 @@ impl Violation for SuperfluousElseReturn {
         format!("Unnecessary `{branch}` after `return` statement")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let SuperfluousElseReturn(branch) = self;
+        Some(match branch {
+            Branch::Elif => |_| "Remove unnecessary `elif`".to_string(),
+            Branch::Else => |_| "Remove unnecessary `else`".to_string(),
+        })
+    }
+
     fn placeholder() -> Self {
         SuperfluousElseReturn(Branch::Else)
     }
@@ -2259,6 +2454,14 @@ impl Violation for SuperfluousElseRaise {
         format!("Unnecessary `{branch}` after `raise` statement")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let SuperfluousElseRaise(branch) = self;
+        Some(match branch {
+            Branch::Elif => |_| "Remove unnecessary `elif`".to_string(),
+            Branch::Else => |_| "Remove unnecessary `else`".to_string(),
+        })
+    }
+
     fn placeholder() -> Self {
         SuperfluousElseRaise(Branch::Else)
     }
@@ -2273,6 +2476,14 @@ impl Violation for SuperfluousElseContinue {
         format!("Unnecessary `{branch}` after `continue` statement")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let SuperfluousElseContinue(branch) = self;
+        Some(match branch {
+            Branch::Elif => |_| "Remove unnecessary `elif`".to_string(),
+            Branch::Else => |_| "Remove unnecessary `else`".to_string(),
+        })
+    }
+
     fn placeholder() -> Self {
         SuperfluousElseContinue(Branch::Else)
     }
@@ -2287,6 +2498,14 @@ impl Violation for SuperfluousElseBreak {
         format!("Unnecessary `{branch}` after `break` statement")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        let SuperfluousElseBreak(branch) = self;
+        Some(match branch {
+            Branch::Elif => |_| "Remove unnecessary `elif`".to_string(),
+            Branch::Else => |_| "Remove unnecessary `else`".to_string(),
+        })
+    }
+
     fn placeholder() -> Self {
         SuperfluousElseBreak(Branch::Else)
     }
@@ -2544,6 +2763,10 @@ impl Violation for MissingReturnTypePublicFunction {
         format!("Missing return type annotation for public function `{name}`")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Add `None` return type".to_string())
+    }
+
     fn placeholder() -> Self {
         MissingReturnTypePublicFunction("...".to_string())
     }
@@ -2558,6 +2781,10 @@ impl Violation for MissingReturnTypePrivateFunction {
         format!("Missing return type annotation for private function `{name}`")
     }
 
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Add `None` return type".to_string())
+    }
+
     fn placeholder() -> Self {
         MissingReturnTypePrivateFunction("...".to_string())
     }
@@ -3273,6 +3500,10 @@ impl AlwaysAutofixableViolation for UselessObjectInheritance {
     fn placeholder() -> Self {
         UselessObjectInheritance("...".to_string())
     }
+
+    fn example() -> Option<&'static str> {
+        Some("class Foo(object):\n    pass\n")
+    }
 }
 
 define_violation!(
@@ -3368,6 +3599,57 @@ impl AlwaysAutofixableViolation for PEP3120UnnecessaryCodingComment {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Encoding {
+    Utf8Bom,
+    NonUtf8(String),
+}
+
+define_violation!(
+    pub struct InvalidEncodingDeclaration(pub Encoding);
+);
+impl AlwaysAutofixableViolation for InvalidEncodingDeclaration {
+    fn message(&self) -> String {
+        match &self.0 {
+            Encoding::Utf8Bom => {
+                "UTF-8 byte-order mark (BOM) is unnecessary, as UTF-8 is always assumed"
+                    .to_string()
+            }
+            Encoding::NonUtf8(encoding) => {
+                format!("File declares encoding `{encoding}`, but Python source is always read as UTF-8")
+            }
+        }
+    }
+
+    fn autofix_title(&self) -> String {
+        match &self.0 {
+            Encoding::Utf8Bom => "Remove UTF-8 byte-order mark".to_string(),
+            Encoding::NonUtf8(..) => "Remove invalid coding comment".to_string(),
+        }
+    }
+
+    fn placeholder() -> Self {
+        InvalidEncodingDeclaration(Encoding::Utf8Bom)
+    }
+}
+
+define_violation!(
+    pub struct OutdatedVersionBlock;
+);
+impl Violation for OutdatedVersionBlock {
+    fn message(&self) -> String {
+        "Version block is outdated for minimum Python version".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Remove outdated version block".to_string())
+    }
+
+    fn placeholder() -> Self {
+        OutdatedVersionBlock
+    }
+}
+
 define_violation!(
     pub struct UnnecessaryFutureImport(pub Vec<String>);
 );
@@ -3833,11 +4115,15 @@ impl AlwaysAutofixableViolation for FunctoolsCache {
 define_violation!(
     pub struct PublicModule;
 );
-impl Violation for PublicModule {
+impl AlwaysAutofixableViolation for PublicModule {
     fn message(&self) -> String {
         "Missing docstring in public module".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Insert placeholder docstring".to_string()
+    }
+
     fn placeholder() -> Self {
         PublicModule
     }
@@ -3885,11 +4171,15 @@ impl Violation for PublicFunction {
 define_violation!(
     pub struct PublicPackage;
 );
-impl Violation for PublicPackage {
+impl AlwaysAutofixableViolation for PublicPackage {
     fn message(&self) -> String {
         "Missing docstring in public package".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Insert placeholder docstring".to_string()
+    }
+
     fn placeholder() -> Self {
         PublicPackage
     }
@@ -4767,6 +5057,20 @@ impl Violation for ErrorSuffixOnExceptionName {
     }
 }
 
+define_violation!(
+    pub struct InvalidModuleName(pub String);
+);
+impl Violation for InvalidModuleName {
+    fn message(&self) -> String {
+        let InvalidModuleName(name) = self;
+        format!("Invalid module name: '{name}'")
+    }
+
+    fn placeholder() -> Self {
+        InvalidModuleName("...".to_string())
+    }
+}
+
 // isort
 
 define_violation!(
@@ -4960,6 +5264,32 @@ impl Violation for HardcodedTempFile {
     }
 }
 
+define_violation!(
+    pub struct TryExceptPass;
+);
+impl Violation for TryExceptPass {
+    fn message(&self) -> String {
+        "Try-Except-Pass detected, consider logging the exception".to_string()
+    }
+
+    fn placeholder() -> Self {
+        TryExceptPass
+    }
+}
+
+define_violation!(
+    pub struct TryExceptContinue;
+);
+impl Violation for TryExceptContinue {
+    fn message(&self) -> String {
+        "Try-Except-Continue detected, consider logging the exception".to_string()
+    }
+
+    fn placeholder() -> Self {
+        TryExceptContinue
+    }
+}
+
 define_violation!(
     pub struct RequestWithoutTimeout(pub Option<String>);
 );
@@ -5063,95 +5393,182 @@ impl Violation for SnmpWeakCryptography {
     }
 }
 
-// flake8-boolean-trap
-
 define_violation!(
-    pub struct BooleanPositionalArgInFunctionDefinition;
+    pub struct SubprocessPopenWithShellEqualsTrue;
 );
-impl Violation for BooleanPositionalArgInFunctionDefinition {
+impl Violation for SubprocessPopenWithShellEqualsTrue {
     fn message(&self) -> String {
-        "Boolean positional arg in function definition".to_string()
+        "`subprocess` call with `shell=True` identified, security issue".to_string()
     }
 
     fn placeholder() -> Self {
-        BooleanPositionalArgInFunctionDefinition
+        SubprocessPopenWithShellEqualsTrue
     }
 }
 
 define_violation!(
-    pub struct BooleanDefaultValueInFunctionDefinition;
+    pub struct SubprocessWithoutShellEqualsTrue;
 );
-impl Violation for BooleanDefaultValueInFunctionDefinition {
+impl Violation for SubprocessWithoutShellEqualsTrue {
     fn message(&self) -> String {
-        "Boolean default value in function definition".to_string()
+        "`subprocess` call: check for execution of untrusted input".to_string()
     }
 
     fn placeholder() -> Self {
-        BooleanDefaultValueInFunctionDefinition
+        SubprocessWithoutShellEqualsTrue
     }
 }
 
 define_violation!(
-    pub struct BooleanPositionalValueInFunctionCall;
+    pub struct CallWithShellEqualsTrue;
 );
-impl Violation for BooleanPositionalValueInFunctionCall {
+impl Violation for CallWithShellEqualsTrue {
     fn message(&self) -> String {
-        "Boolean positional value in function call".to_string()
+        "Function call with `shell=True` parameter identified, security issue".to_string()
     }
 
     fn placeholder() -> Self {
-        BooleanPositionalValueInFunctionCall
+        CallWithShellEqualsTrue
     }
 }
 
-// flake8-unused-arguments
-
 define_violation!(
-    pub struct UnusedFunctionArgument(pub String);
+    pub struct StartProcessWithAShell;
 );
-impl Violation for UnusedFunctionArgument {
+impl Violation for StartProcessWithAShell {
     fn message(&self) -> String {
-        let UnusedFunctionArgument(name) = self;
-        format!("Unused function argument: `{name}`")
+        "Starting a process with a shell, possible injection detected".to_string()
     }
 
     fn placeholder() -> Self {
-        UnusedFunctionArgument("...".to_string())
+        StartProcessWithAShell
     }
 }
 
 define_violation!(
-    pub struct UnusedMethodArgument(pub String);
+    pub struct StartProcessWithNoShell;
 );
-impl Violation for UnusedMethodArgument {
+impl Violation for StartProcessWithNoShell {
     fn message(&self) -> String {
-        let UnusedMethodArgument(name) = self;
-        format!("Unused method argument: `{name}`")
+        "Starting a process without a shell".to_string()
     }
 
     fn placeholder() -> Self {
-        UnusedMethodArgument("...".to_string())
+        StartProcessWithNoShell
     }
 }
 
 define_violation!(
-    pub struct UnusedClassMethodArgument(pub String);
+    pub struct StartProcessWithPartialPath(pub String);
 );
-impl Violation for UnusedClassMethodArgument {
+impl Violation for StartProcessWithPartialPath {
     fn message(&self) -> String {
-        let UnusedClassMethodArgument(name) = self;
-        format!("Unused class method argument: `{name}`")
+        let StartProcessWithPartialPath(executable) = self;
+        format!("Starting a process with a partial executable path: `{executable}`")
     }
 
     fn placeholder() -> Self {
-        UnusedClassMethodArgument("...".to_string())
+        StartProcessWithPartialPath("...".to_string())
     }
 }
 
+// flake8-boolean-trap
+
 define_violation!(
-    pub struct UnusedStaticMethodArgument(pub String);
+    pub struct BooleanPositionalArgInFunctionDefinition;
 );
-impl Violation for UnusedStaticMethodArgument {
+impl Violation for BooleanPositionalArgInFunctionDefinition {
+    fn message(&self) -> String {
+        "Boolean positional arg in function definition".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Make the parameter keyword-only".to_string())
+    }
+
+    fn placeholder() -> Self {
+        BooleanPositionalArgInFunctionDefinition
+    }
+}
+
+define_violation!(
+    pub struct BooleanDefaultValueInFunctionDefinition;
+);
+impl Violation for BooleanDefaultValueInFunctionDefinition {
+    fn message(&self) -> String {
+        "Boolean default value in function definition".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Make the parameter keyword-only".to_string())
+    }
+
+    fn placeholder() -> Self {
+        BooleanDefaultValueInFunctionDefinition
+    }
+}
+
+define_violation!(
+    pub struct BooleanPositionalValueInFunctionCall;
+);
+impl Violation for BooleanPositionalValueInFunctionCall {
+    fn message(&self) -> String {
+        "Boolean positional value in function call".to_string()
+    }
+
+    fn placeholder() -> Self {
+        BooleanPositionalValueInFunctionCall
+    }
+}
+
+// flake8-unused-arguments
+
+define_violation!(
+    pub struct UnusedFunctionArgument(pub String);
+);
+impl Violation for UnusedFunctionArgument {
+    fn message(&self) -> String {
+        let UnusedFunctionArgument(name) = self;
+        format!("Unused function argument: `{name}`")
+    }
+
+    fn placeholder() -> Self {
+        UnusedFunctionArgument("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct UnusedMethodArgument(pub String);
+);
+impl Violation for UnusedMethodArgument {
+    fn message(&self) -> String {
+        let UnusedMethodArgument(name) = self;
+        format!("Unused method argument: `{name}`")
+    }
+
+    fn placeholder() -> Self {
+        UnusedMethodArgument("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct UnusedClassMethodArgument(pub String);
+);
+impl Violation for UnusedClassMethodArgument {
+    fn message(&self) -> String {
+        let UnusedClassMethodArgument(name) = self;
+        format!("Unused class method argument: `{name}`")
+    }
+
+    fn placeholder() -> Self {
+        UnusedClassMethodArgument("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct UnusedStaticMethodArgument(pub String);
+);
+impl Violation for UnusedStaticMethodArgument {
     fn message(&self) -> String {
         let UnusedStaticMethodArgument(name) = self;
         format!("Unused static method argument: `{name}`")
@@ -5192,6 +5609,34 @@ impl Violation for ImportAliasIsNotConventional {
     }
 }
 
+define_violation!(
+    pub struct BannedImportAlias(pub String, pub String);
+);
+impl Violation for BannedImportAlias {
+    fn message(&self) -> String {
+        let BannedImportAlias(name, asname) = self;
+        format!("`{name}` should not be imported as `{asname}`")
+    }
+
+    fn placeholder() -> Self {
+        BannedImportAlias("...".to_string(), "...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct BannedImportFrom(pub String);
+);
+impl Violation for BannedImportFrom {
+    fn message(&self) -> String {
+        let BannedImportFrom(name) = self;
+        format!("Members of `{name}` should not be imported explicitly")
+    }
+
+    fn placeholder() -> Self {
+        BannedImportFrom("...".to_string())
+    }
+}
+
 // flake8-datetimez
 
 define_violation!(
@@ -5378,6 +5823,25 @@ impl Violation for BlanketNOQA {
     }
 }
 
+define_violation!(
+    pub struct TypeIgnoreMissingCode;
+);
+impl Violation for TypeIgnoreMissingCode {
+    fn message(&self) -> String {
+        "`# type: ignore` should include an error code, e.g. `# type: ignore[code]`".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        // Only offered when `pygrep-hooks.default-type-ignore-code` is configured; see
+        // `pygrep_hooks::rules::type_ignore_missing_code`.
+        Some(|_| "Insert the configured default error code".to_string())
+    }
+
+    fn placeholder() -> Self {
+        TypeIgnoreMissingCode
+    }
+}
+
 // pandas-vet
 
 define_violation!(
@@ -5538,6 +6002,21 @@ impl Violation for DfIsABadVariableName {
     }
 }
 
+define_violation!(
+    pub struct UseOfDotNunique;
+);
+impl Violation for UseOfDotNunique {
+    fn message(&self) -> String {
+        "Use `.nunique()` with caution for boolean checks; consider `.isin([0, 1]).all()` for \
+         clarity"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        UseOfDotNunique
+    }
+}
+
 // flake8-errmsg
 
 define_violation!(
@@ -5963,6 +6442,25 @@ impl AlwaysAutofixableViolation for UseFixturesWithoutParameters {
     }
 }
 
+define_violation!(
+    pub struct UnittestRaisesAssertion(pub String);
+);
+impl AlwaysAutofixableViolation for UnittestRaisesAssertion {
+    fn message(&self) -> String {
+        let UnittestRaisesAssertion(assertion) = self;
+        format!("Use `pytest.raises` instead of unittest-style `{assertion}`")
+    }
+
+    fn autofix_title(&self) -> String {
+        let UnittestRaisesAssertion(assertion) = self;
+        format!("Replace `{assertion}` with `pytest.raises`")
+    }
+
+    fn placeholder() -> Self {
+        UnittestRaisesAssertion("assertRaises".to_string())
+    }
+}
+
 // flake8-pie
 
 define_violation!(
@@ -6036,6 +6534,76 @@ impl AlwaysAutofixableViolation for PreferListBuiltin {
     }
 }
 
+define_violation!(
+    pub struct UnnecessarySpread;
+);
+impl Violation for UnnecessarySpread {
+    fn message(&self) -> String {
+        "Unnecessary spread `**`".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Remove unnecessary dict".to_string())
+    }
+
+    fn placeholder() -> Self {
+        UnnecessarySpread
+    }
+}
+
+define_violation!(
+    pub struct UnnecessaryDictKwargs;
+);
+impl Violation for UnnecessaryDictKwargs {
+    fn message(&self) -> String {
+        "Unnecessary `dict` kwargs".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Remove unnecessary `dict` kwargs".to_string())
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryDictKwargs
+    }
+}
+
+define_violation!(
+    pub struct UnnecessaryRangeStart;
+);
+impl AlwaysAutofixableViolation for UnnecessaryRangeStart {
+    fn message(&self) -> String {
+        "`range` does not require a `start` argument of `0`".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove `start` argument".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryRangeStart
+    }
+}
+
+define_violation!(
+    pub struct MultipleStartsEndsWith(pub String);
+);
+impl AlwaysAutofixableViolation for MultipleStartsEndsWith {
+    fn message(&self) -> String {
+        let MultipleStartsEndsWith(attr) = self;
+        format!("Multiple `{attr}` calls, merge into a single call")
+    }
+
+    fn autofix_title(&self) -> String {
+        let MultipleStartsEndsWith(attr) = self;
+        format!("Merge `{attr}` calls using a `tuple`")
+    }
+
+    fn placeholder() -> Self {
+        MultipleStartsEndsWith("...".to_string())
+    }
+}
+
 // flake8-commas
 
 define_violation!(
@@ -6101,6 +6669,118 @@ impl Violation for ImplicitNamespacePackage {
     }
 }
 
+define_violation!(
+    pub struct ImplicitNamespacePackageInScriptDirectory(pub String);
+);
+impl Violation for ImplicitNamespacePackageInScriptDirectory {
+    fn message(&self) -> String {
+        let ImplicitNamespacePackageInScriptDirectory(filename) = self;
+        format!("File `{filename}` is part of an implicit namespace package in a configured script directory. Add an `__init__.py` if it's meant to be importable.")
+    }
+
+    fn placeholder() -> Self {
+        ImplicitNamespacePackageInScriptDirectory("...".to_string())
+    }
+}
+
+// flake8-pyi
+
+define_violation!(
+    pub struct DocstringInStub;
+);
+impl Violation for DocstringInStub {
+    fn message(&self) -> String {
+        "Docstrings should not be included in stubs".to_string()
+    }
+
+    fn placeholder() -> Self {
+        DocstringInStub
+    }
+}
+
+// flake8-todos
+
+define_violation!(
+    pub struct InvalidTodoTag(pub String);
+);
+impl Violation for InvalidTodoTag {
+    fn message(&self) -> String {
+        let InvalidTodoTag(tag) = self;
+        format!("Invalid TODO tag: `{tag}`")
+    }
+
+    fn placeholder() -> Self {
+        InvalidTodoTag("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct MissingTodoAuthor;
+);
+impl Violation for MissingTodoAuthor {
+    fn message(&self) -> String {
+        "Missing author in TODO; try: `# TODO(<author_name>): ...`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MissingTodoAuthor
+    }
+}
+
+// flake8-fixme
+
+define_violation!(
+    pub struct LineContainsFixme;
+);
+impl Violation for LineContainsFixme {
+    fn message(&self) -> String {
+        "Line contains FIXME, consider resolving the issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineContainsFixme
+    }
+}
+
+define_violation!(
+    pub struct LineContainsTodo;
+);
+impl Violation for LineContainsTodo {
+    fn message(&self) -> String {
+        "Line contains TODO, consider resolving the issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineContainsTodo
+    }
+}
+
+define_violation!(
+    pub struct LineContainsXxx;
+);
+impl Violation for LineContainsXxx {
+    fn message(&self) -> String {
+        "Line contains XXX, consider resolving the issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineContainsXxx
+    }
+}
+
+define_violation!(
+    pub struct LineContainsHack;
+);
+impl Violation for LineContainsHack {
+    fn message(&self) -> String {
+        "Line contains HACK, consider resolving the issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineContainsHack
+    }
+}
+
 // Ruff
 
 define_violation!(
@@ -6219,6 +6899,245 @@ impl Violation for KeywordArgumentBeforeStarArgument {
     }
 }
 
+define_violation!(
+    pub struct MissingCopyrightNotice;
+);
+impl AlwaysAutofixableViolation for MissingCopyrightNotice {
+    fn message(&self) -> String {
+        "Missing copyright notice at top of file".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Insert copyright notice".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MissingCopyrightNotice
+    }
+
+    fn example() -> Option<&'static str> {
+        Some("import os\n")
+    }
+}
+
+define_violation!(
+    pub struct CollectionLiteralConcatenation(pub String);
+);
+impl AlwaysAutofixableViolation for CollectionLiteralConcatenation {
+    fn message(&self) -> String {
+        "Consider iterable unpacking instead of concatenation".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        let CollectionLiteralConcatenation(expr) = self;
+        format!("Replace with `{expr}`")
+    }
+
+    fn placeholder() -> Self {
+        CollectionLiteralConcatenation("[*a, *b]".to_string())
+    }
+}
+
+define_violation!(
+    pub struct AsyncioDanglingTask;
+);
+impl Violation for AsyncioDanglingTask {
+    fn message(&self) -> String {
+        "Store a reference to the return value of `asyncio.create_task`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        AsyncioDanglingTask
+    }
+}
+
+define_violation!(
+    pub struct QuotedAnnotation(pub String);
+);
+impl Violation for QuotedAnnotation {
+    fn message(&self) -> String {
+        let QuotedAnnotation(annotation) = self;
+        format!(
+            "Quotes on annotation `{annotation}` are redundant with `from __future__ import \
+             annotations`"
+        )
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Remove redundant quotes".to_string())
+    }
+
+    fn placeholder() -> Self {
+        QuotedAnnotation("int".to_string())
+    }
+}
+
+define_violation!(
+    pub struct SyntaxErrorInDoctest(pub String);
+);
+impl Violation for SyntaxErrorInDoctest {
+    fn message(&self) -> String {
+        let SyntaxErrorInDoctest(message) = self;
+        format!("Syntax error in doctest: {message}")
+    }
+
+    fn placeholder() -> Self {
+        SyntaxErrorInDoctest("EOF in multi-line statement".to_string())
+    }
+}
+
+define_violation!(
+    pub struct MutableClassDefault(pub bool);
+);
+impl Violation for MutableClassDefault {
+    fn message(&self) -> String {
+        let MutableClassDefault(is_dataclass) = self;
+        if *is_dataclass {
+            "Do not use mutable data structures for dataclass field defaults; use \
+             `field(default_factory=...)` instead"
+                .to_string()
+        } else {
+            "Do not use mutable data structures for class attribute defaults; they are shared \
+             across all instances"
+                .to_string()
+        }
+    }
+
+    fn placeholder() -> Self {
+        MutableClassDefault(true)
+    }
+}
+
+define_violation!(
+    pub struct FStringStrCall;
+);
+impl AlwaysAutofixableViolation for FStringStrCall {
+    fn message(&self) -> String {
+        "Use conversion in f-string, instead of calling `str`".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Replace `str()` call with conversion".to_string()
+    }
+
+    fn placeholder() -> Self {
+        FStringStrCall
+    }
+}
+
+define_violation!(
+    pub struct ImplicitOptional;
+);
+impl Violation for ImplicitOptional {
+    fn message(&self) -> String {
+        "PEP 484 prohibits implicit `Optional`".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Make `Optional` explicit".to_string())
+    }
+
+    fn placeholder() -> Self {
+        ImplicitOptional
+    }
+}
+
+define_violation!(
+    pub struct UndocumentedException(pub Vec<String>);
+);
+impl Violation for UndocumentedException {
+    fn message(&self) -> String {
+        let UndocumentedException(names) = self;
+        if names.len() == 1 {
+            let name = &names[0];
+            format!("Raised exception `{name}` missing from docstring's `Raises` section")
+        } else {
+            let names = names.iter().map(|name| format!("`{name}`")).join(", ");
+            format!("Raised exceptions {names} missing from docstring's `Raises` section")
+        }
+    }
+
+    fn placeholder() -> Self {
+        UndocumentedException(vec!["ValueError".to_string()])
+    }
+}
+
+define_violation!(
+    pub struct SingleStringSlots;
+);
+impl Violation for SingleStringSlots {
+    fn message(&self) -> String {
+        "Class `__slots__` should be a non-string iterable".to_string()
+    }
+
+    fn placeholder() -> Self {
+        SingleStringSlots
+    }
+}
+
+define_violation!(
+    pub struct UnexpectedSpecialMethodSignature(pub String, pub usize, pub usize);
+);
+impl Violation for UnexpectedSpecialMethodSignature {
+    fn message(&self) -> String {
+        let UnexpectedSpecialMethodSignature(name, expected, actual) = self;
+        let expected_params = if *expected == 1 { "parameter" } else { "parameters" };
+        format!(
+            "The special method `{name}` expects {expected} {expected_params}, got {actual}"
+        )
+    }
+
+    fn placeholder() -> Self {
+        UnexpectedSpecialMethodSignature("__eq__".to_string(), 1, 0)
+    }
+}
+
+define_violation!(
+    pub struct TooManyPublicMethods(pub usize, pub usize);
+);
+impl Violation for TooManyPublicMethods {
+    fn message(&self) -> String {
+        let TooManyPublicMethods(methods, max_methods) = self;
+        format!("Too many public methods ({methods} > {max_methods})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyPublicMethods(25, 20)
+    }
+}
+
+define_violation!(
+    pub struct AssertOnStringLiteral(pub bool);
+);
+impl Violation for AssertOnStringLiteral {
+    fn message(&self) -> String {
+        let AssertOnStringLiteral(is_empty) = self;
+        if *is_empty {
+            "Assert statement on an empty string literal, which will always fail".to_string()
+        } else {
+            "Assert statement on a non-empty string literal, which will always pass".to_string()
+        }
+    }
+
+    fn placeholder() -> Self {
+        AssertOnStringLiteral(false)
+    }
+}
+
+define_violation!(
+    pub struct SelfAssigningVariable(pub String);
+);
+impl Violation for SelfAssigningVariable {
+    fn message(&self) -> String {
+        let SelfAssigningVariable(name) = self;
+        format!("Self-assignment of variable `{name}`")
+    }
+
+    fn placeholder() -> Self {
+        SelfAssigningVariable("...".to_string())
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UnusedCodes {
     pub unknown: Vec<String>,
@@ -6283,3 +7202,17 @@ impl AlwaysAutofixableViolation for UnusedNOQA {
         UnusedNOQA(None)
     }
 }
+
+define_violation!(
+    pub struct UnusedPrivateModuleFunction(pub String);
+);
+impl Violation for UnusedPrivateModuleFunction {
+    fn message(&self) -> String {
+        let UnusedPrivateModuleFunction(name) = self;
+        format!("Function `{name}` is never used")
+    }
+
+    fn placeholder() -> Self {
+        UnusedPrivateModuleFunction("_unused".to_string())
+    }
+}
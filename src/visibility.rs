@@ -3,9 +3,9 @@
 
 use std::path::Path;
 
-use rustpython_ast::{Expr, Stmt, StmtKind};
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
 
-use crate::ast::helpers::collect_call_path;
+use crate::ast::helpers::{collect_call_path, to_call_path};
 use crate::checkers::ast::Checker;
 use crate::docstrings::definition::Documentable;
 
@@ -29,19 +29,37 @@ pub struct VisibleScope {
 }
 
 /// Returns `true` if a function is a "static method".
+///
+/// Consults the `pep8-naming.staticmethod-decorators` setting, so that
+/// third-party decorators (e.g. `attrs.define`-style helpers) are treated
+/// consistently everywhere a rule needs to know whether a method is static,
+/// rather than each rule hard-coding its own `@staticmethod` check.
 pub fn is_staticmethod(checker: &Checker, decorator_list: &[Expr]) -> bool {
     decorator_list.iter().any(|expr| {
         checker.resolve_call_path(expr).map_or(false, |call_path| {
-            call_path.as_slice() == ["", "staticmethod"]
+            checker
+                .settings
+                .pep8_naming
+                .staticmethod_decorators
+                .iter()
+                .any(|decorator| call_path == to_call_path(decorator))
         })
     })
 }
 
 /// Returns `true` if a function is a "class method".
+///
+/// Consults the `pep8-naming.classmethod-decorators` setting; see
+/// [`is_staticmethod`].
 pub fn is_classmethod(checker: &Checker, decorator_list: &[Expr]) -> bool {
     decorator_list.iter().any(|expr| {
         checker.resolve_call_path(expr).map_or(false, |call_path| {
-            call_path.as_slice() == ["", "classmethod"]
+            checker
+                .settings
+                .pep8_naming
+                .classmethod_decorators
+                .iter()
+                .any(|decorator| call_path == to_call_path(decorator))
         })
     })
 }
@@ -70,6 +88,24 @@ pub fn is_abstract(checker: &Checker, decorator_list: &[Expr]) -> bool {
     })
 }
 
+/// Returns `true` if a function or class body is a stub: made up only of `pass`
+/// statements, `...` (`Ellipsis`) expressions, and string literals such as a docstring. Shared
+/// by rules that need to distinguish "real" implementations from
+/// protocol/abstract stubs (e.g. `B027`, and `pydocstyle`'s
+/// `ignore-stub-functions` setting).
+pub fn is_stub_body(body: &[Stmt]) -> bool {
+    body.iter().all(|stmt| match &stmt.node {
+        StmtKind::Pass => true,
+        StmtKind::Expr { value } => match &value.node {
+            ExprKind::Constant { value, .. } => {
+                matches!(value, Constant::Str(..) | Constant::Ellipsis)
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
 /// Returns `true` if a function is a "magic method".
 pub fn is_magic(name: &str) -> bool {
     name.starts_with("__") && name.ends_with("__")
@@ -148,7 +184,7 @@ fn function_visibility(stmt: &Stmt) -> Visibility {
     }
 }
 
-fn method_visibility(stmt: &Stmt) -> Visibility {
+pub(crate) fn method_visibility(stmt: &Stmt) -> Visibility {
     match &stmt.node {
         StmtKind::FunctionDef {
             name,